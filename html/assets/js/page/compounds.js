@@ -0,0 +1,94 @@
+/**
+ * This JS-File loads the compounds of a word or kanji (and the words a compound consists of) into their modal
+ */
+
+// Amount of compounds to show per position
+const compoundPageSize = 20;
+
+// Load the data the first time a compound modal gets opened
+$(document).on("show.bs.modal", ".compounds-modal", function() {
+    let modal = $(this);
+    if (modal.data("loaded")) {
+        return;
+    }
+    modal.data("loaded", true);
+
+    let term = modal.data("term").toString();
+    loadCompounds(modal, term);
+
+    if (modal.data("split")) {
+        loadCompoundSplit(modal, term);
+    }
+});
+
+// Requests all compounds containing `term` and renders them grouped by position
+function loadCompounds(modal, term) {
+    postCompoundApi("/api/compounds", { "term": term, "page_size": compoundPageSize }, (result) => {
+        let found = false;
+
+        for (let position of ["prefix", "infix", "suffix"]) {
+            let group = result[position];
+            if (group === undefined || group.words.length === 0) {
+                continue;
+            }
+            found = true;
+
+            let groupElement = modal.find(".compound-group[data-position='" + position + "']");
+            groupElement.find(".compound-total").text(group.total);
+            groupElement.find(".compound-words").append(group.words.map(compoundWordLink));
+            groupElement.removeClass("hidden");
+        }
+
+        if (!found) {
+            modal.find(".compound-empty").removeClass("hidden");
+        }
+    }, () => modal.find(".compound-empty").removeClass("hidden"));
+}
+
+// Requests the dictionary words `compound` consists of. Nothing is shown if it can't be split
+function loadCompoundSplit(modal, compound) {
+    postCompoundApi("/api/compounds/split", { "compound": compound }, (result) => {
+        let segments = result.segments.map((segment) => {
+            if (segment.word === undefined) {
+                return $("<span>").text(segment.text);
+            }
+            return compoundWordLink(segment.word);
+        });
+
+        let parent = modal.find(".compound-segments");
+        segments.forEach((segment, pos) => {
+            if (pos > 0) {
+                parent.append(" + ");
+            }
+            parent.append(segment);
+        });
+
+        modal.find(".compound-split").removeClass("hidden");
+    }, () => {});
+}
+
+// Creates a link to the direct reference of a word returned by the compound API
+function compoundWordLink(word) {
+    let link = $("<a>")
+        .addClass("clickable no-align green right-padding-10")
+        .attr("href", "/direct/0/" + word.sequence)
+        .text(word.reading);
+
+    if (word.reading !== word.kana) {
+        link.attr("title", word.kana);
+    }
+    return link;
+}
+
+function postCompoundApi(url, payload, successFn, errorFn) {
+    $.ajax({
+        type: "POST",
+        url: url,
+        data: JSON.stringify(payload),
+        headers: {
+            'Content-Type': 'application/json'
+        },
+        success: successFn,
+        error: errorFn,
+    });
+}
@@ -0,0 +1,88 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use search::{
+    query::{parser::QueryParser, Query, UserSettings},
+    SearchExecutor,
+};
+use types::jotoba::{languages::Language, search::SearchTarget};
+
+#[global_allocator]
+static ALLOC: snmalloc_rs::SnMalloc = snmalloc_rs::SnMalloc;
+
+/// Queries for which several independent producers run
+const QUERIES: &[(&str, SearchTarget)] = &[
+    ("taberu", SearchTarget::Words),
+    ("kaimono", SearchTarget::Words),
+    ("house", SearchTarget::Words),
+    ("suzuki", SearchTarget::Names),
+    ("haus", SearchTarget::Sentences),
+];
+
+fn get_query(inp: &str, query_type: SearchTarget) -> Query {
+    let mut settings = UserSettings::default();
+    settings.user_lang = Language::German;
+    settings.show_english = true;
+    QueryParser::new(inp.to_string(), query_type, settings)
+        .parse()
+        .unwrap()
+}
+
+fn load() {
+    rayon::scope(move |s| {
+        s.spawn(move |_| {
+            resources::load("../resources/storage_data").unwrap();
+        });
+        s.spawn(move |_| {
+            indexes::storage::load("../resources/indexes").unwrap();
+        });
+        s.spawn(|_| {
+            sentence_reader::load_parser("../resources/unidic-mecab");
+        });
+    });
+}
+
+/// Runs the search without the result cache so every iteration runs all producers
+fn search(query: &Query, parallel: bool) {
+    match query.target {
+        SearchTarget::Words => {
+            let search = search::word::Search::new(query);
+            SearchExecutor::new(search)
+                .with_parallel(parallel)
+                .run_uncached();
+        }
+        SearchTarget::Names => {
+            let search = search::name::Search::new(query);
+            SearchExecutor::new(search)
+                .with_parallel(parallel)
+                .run_uncached();
+        }
+        SearchTarget::Sentences => {
+            let search = search::sentence::Search::new(query);
+            SearchExecutor::new(search)
+                .with_parallel(parallel)
+                .run_uncached();
+        }
+        SearchTarget::Kanji => (),
+    }
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    load();
+
+    let mut group = c.benchmark_group("producers");
+    for (inp, target) in QUERIES {
+        let query = get_query(inp, *target);
+        let id = format!("{target:?} {inp}");
+
+        group.bench_with_input(BenchmarkId::new("sequential", &id), &query, |b, query| {
+            b.iter(|| search(query, false))
+        });
+
+        group.bench_with_input(BenchmarkId::new("parallel", &id), &query, |b, query| {
+            b.iter(|| search(query, true))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -17,18 +17,19 @@ fn criterion_benchmark(c: &mut Criterion) {
         })
     });
 
+    let retrieve = resources::get().kanji();
     let tests: Vec<&'static [char]> = vec![&['囗'], &['一'], &['囗', '一'], &['口'], &['口', '一']];
     c.bench_function("Find by radicals", |b| {
         b.iter(|| {
             for i in &tests {
-                api::radical::kanji::find_kanji(black_box(i));
+                api::radical::kanji::find_kanji(&retrieve, black_box(i));
             }
         })
     });
 
     c.bench_function("Find by radicals light", |b| {
         b.iter(|| {
-            api::radical::kanji::find_kanji(black_box(&['首']));
+            api::radical::kanji::find_kanji(&retrieve, black_box(&['首']));
         })
     });
 }
@@ -1,6 +1,6 @@
 use std::process::exit;
 
-use argparse::{ArgumentParser, Print, StoreTrue};
+use argparse::{ArgumentParser, List, Print, Store, StoreTrue};
 
 /// Command line arguments
 #[derive(Default)]
@@ -9,6 +9,10 @@ pub struct Options {
     pub start: bool,
     pub debug: bool,
     pub check_resources: bool,
+    /// Subcommand to run instead of the server
+    pub command: String,
+    /// Arguments passed to the subcommand
+    pub command_args: Vec<String>,
 }
 
 // Parse CLI args
@@ -36,6 +40,19 @@ pub fn parse() -> Options {
             "Check resources",
         );
 
+        ap.refer(&mut options.command).add_argument(
+            "command",
+            Store,
+            "Command to run instead of starting the server (difficulty, query, reader, query-log, sentence-jlpt)",
+        );
+
+        ap.refer(&mut options.command_args).add_argument(
+            "arguments",
+            List,
+            "Arguments for the command",
+        );
+
+        ap.stop_on_first_argument(true);
         ap.parse_args_or_exit();
     }
 
@@ -44,5 +61,10 @@ pub fn parse() -> Options {
         exit(1);
     }
 
+    if !options.command.is_empty() && (options.start || options.check_resources) {
+        println!("Can't run a command along with start or check_resources");
+        exit(1);
+    }
+
     options
 }
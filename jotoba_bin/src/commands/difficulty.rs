@@ -0,0 +1,110 @@
+use argparse::{ArgumentParser, Store, StoreOption, StoreTrue};
+use indexes::storage::IndexPart;
+use search::reader::difficulty::Report;
+use std::{fs, process::exit};
+use types::jotoba::words::Word;
+
+/// Prints a difficulty report of a japanese text
+pub(super) fn run(args: Vec<String>) {
+    let mut text = String::new();
+    let mut file: Option<String> = None;
+    let mut top_n: usize = 20;
+    let mut json = false;
+
+    {
+        let mut ap = ArgumentParser::new();
+        ap.set_description("Estimate the difficulty of a japanese text");
+
+        ap.refer(&mut file)
+            .add_option(&["--file", "-f"], StoreOption, "Read the text from a file");
+
+        ap.refer(&mut top_n)
+            .add_option(&["--top", "-n"], Store, "Amount of words to pre-learn");
+
+        ap.refer(&mut json)
+            .add_option(&["--json"], StoreTrue, "Print the report as JSON");
+
+        ap.refer(&mut text)
+            .add_argument("text", Store, "The text to analyze");
+
+        super::parse_args(ap, args);
+    }
+
+    if let Some(file) = file {
+        text = fs::read_to_string(&file).unwrap_or_else(|err| {
+            println!("Failed to read {file}: {err}");
+            exit(1);
+        });
+    }
+
+    if text.trim().is_empty() {
+        println!("No text provided");
+        exit(1);
+    }
+
+    super::load_data(&[IndexPart::Word, IndexPart::Kanji], true);
+
+    let report = search::reader::difficulty::analyze(&text, top_n);
+
+    if json {
+        let response = api::reader::difficulty::conv_report(&report);
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+        return;
+    }
+
+    print_report(&report);
+}
+
+fn print_report(report: &Report) {
+    match report.estimated_jlpt {
+        Some(lvl) => println!("Estimated JLPT level: N{lvl}"),
+        None => println!("Estimated JLPT level: -"),
+    }
+    println!("Tokens: {}", report.token_count);
+
+    println!("\nJLPT levels:");
+    for lvl in (0..=5).rev() {
+        let name = if lvl > 0 {
+            format!("N{lvl}")
+        } else {
+            "none".to_string()
+        };
+        let count = report.jlpt_tokens[lvl as usize];
+        println!("  {name:<5}{count:>6} ({:.1}%)", report.jlpt_share(lvl));
+    }
+
+    println!("\nKanji ({}):", report.kanji.len());
+    for (kanji, count) in &report.kanji {
+        let grade = kanji.grade.map(|i| i.to_string()).unwrap_or_default();
+        let jlpt = kanji.jlpt.map(|i| format!("N{i}")).unwrap_or_default();
+        println!("  {}  grade {grade:<3} {jlpt:<3} x{count}", kanji.literal);
+    }
+
+    println!("\nWords to learn:");
+    for (word, count) in &report.vocabulary {
+        print_word(word, *count);
+    }
+
+    if !report.rare.is_empty() {
+        println!("\nRare words:");
+        for (word, count) in &report.rare {
+            print_word(word, *count);
+        }
+    }
+
+    if !report.unknown.is_empty() {
+        println!("\nUnknown: {}", report.unknown.join(", "));
+    }
+}
+
+fn print_word(word: &Word, count: usize) {
+    let jlpt = word
+        .get_jlpt_lvl()
+        .map(|i| format!("N{i}"))
+        .unwrap_or_default();
+    println!(
+        "  {} ({}) {jlpt:<3} x{count}",
+        word.get_reading().reading,
+        word.get_kana()
+    );
+}
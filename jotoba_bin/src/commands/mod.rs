@@ -0,0 +1,59 @@
+mod difficulty;
+mod query;
+mod query_log;
+mod reader;
+mod sentence_jlpt;
+
+use crate::webserver::{load_resources, load_tokenizer};
+use argparse::ArgumentParser;
+use config::Config;
+use indexes::storage::IndexPart;
+use std::{
+    io::{stderr, stdout},
+    process::exit,
+};
+
+/// Runs the command `name` with its arguments
+pub fn run(name: &str, mut args: Vec<String>) {
+    args.insert(0, format!("jotoba {name}"));
+
+    match name {
+        "difficulty" => difficulty::run(args),
+        "query" => query::run(args),
+        "reader" => reader::run(args),
+        "sentence-jlpt" => sentence_jlpt::run(args),
+        "query-log" => query_log::run(args),
+        _ => {
+            eprintln!("Unknown command: {name}");
+            exit(1);
+        }
+    }
+}
+
+/// Parses the arguments of a command. Exits the program if parsing failed or help was requested
+fn parse_args(ap: ArgumentParser, args: Vec<String>) {
+    if let Err(code) = ap.parse(args, &mut stdout(), &mut stderr()) {
+        exit(code);
+    }
+}
+
+/// Loads the resources and the given parts of the indexes in parallel. The tokenizer only gets
+/// loaded if `tokenizer` is set
+fn load_data(index_parts: &[IndexPart], tokenizer: bool) -> Config {
+    let config = Config::new(None).expect("config failed");
+
+    rayon::scope(|s| {
+        s.spawn(|_| load_resources(&config.get_storage_data_path()));
+        if !index_parts.is_empty() {
+            s.spawn(|_| {
+                indexes::storage::load_parts(config.get_indexes_source(), index_parts)
+                    .expect("Failed to load index files");
+            });
+        }
+        if tokenizer {
+            s.spawn(|_| load_tokenizer(&config));
+        }
+    });
+
+    config
+}
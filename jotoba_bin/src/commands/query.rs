@@ -0,0 +1,255 @@
+use argparse::{ArgumentParser, Collect, Store, StoreOption, StoreTrue};
+use indexes::storage::IndexPart;
+use search::{
+    query::{parser::QueryParser, Query, UserSettings},
+    SearchExecutor,
+};
+use std::{process::exit, str::FromStr};
+use types::jotoba::{languages::Language, search::SearchTarget};
+
+/// Searches the dictionary and prints the results
+pub(super) fn run(args: Vec<String>) {
+    let mut target = String::new();
+    let mut query_str = String::new();
+    let mut lang = String::from("eng");
+    let mut no_english = false;
+    let mut tags: Vec<String> = vec![];
+    let mut page: usize = 1;
+    let mut limit: Option<u32> = None;
+    let mut romaji = false;
+    let mut json = false;
+
+    {
+        let mut ap = ArgumentParser::new();
+        ap.set_description("Search for words, kanji, names or sentences");
+
+        ap.refer(&mut lang).add_option(
+            &["--lang", "-l"],
+            Store,
+            "Language to search in, eg. ger or eng",
+        );
+
+        ap.refer(&mut no_english).add_option(
+            &["--no-english"],
+            StoreTrue,
+            "Don't show english results along with the ones of the language",
+        );
+
+        ap.refer(&mut tags).add_option(
+            &["--tag", "-t"],
+            Collect,
+            "Tag to filter by, eg. formal or n3. Can be used multiple times",
+        );
+
+        ap.refer(&mut page)
+            .add_option(&["--page", "-p"], Store, "Page of the results to show");
+
+        ap.refer(&mut limit).add_option(
+            &["--limit", "-n"],
+            StoreOption,
+            "Amount of results per page",
+        );
+
+        ap.refer(&mut romaji)
+            .add_option(&["--romaji"], StoreTrue, "Add romaji to words and names");
+
+        ap.refer(&mut json).add_option(
+            &["--json"],
+            StoreTrue,
+            "Print the results like the search API does",
+        );
+
+        ap.refer(&mut target)
+            .add_argument("target", Store, "words, kanji, names or sentences")
+            .required();
+
+        ap.refer(&mut query_str)
+            .add_argument("query", Store, "The query to search for");
+
+        super::parse_args(ap, args);
+    }
+
+    let target = parse_target(&target).unwrap_or_else(|| {
+        eprintln!("Unknown search target: {target}");
+        exit(1);
+    });
+
+    let language = Language::from_str(&lang).unwrap_or_else(|_| {
+        eprintln!("Unknown language: {lang}");
+        exit(1);
+    });
+
+    for tag in tags {
+        query_str.push_str(&format!(" #{}", tag.trim_start_matches('#')));
+    }
+
+    if query_str.trim().is_empty() {
+        eprintln!("No query provided");
+        exit(1);
+    }
+
+    let mut settings = UserSettings {
+        user_lang: language,
+        show_english: !no_english,
+        ..UserSettings::default()
+    };
+    if let Some(limit) = limit {
+        settings.page_size = limit;
+        settings.kanji_page_size = limit;
+    }
+
+    let query = QueryParser::new(query_str.clone(), target, settings)
+        .with_page(page)
+        .parse()
+        .unwrap_or_else(|| {
+            eprintln!("Invalid query: {query_str}");
+            exit(1);
+        });
+
+    // Kanji searches don't tokenize the query
+    super::load_data(index_parts(target), target != SearchTarget::Kanji);
+
+    match target {
+        SearchTarget::Words => words(&query, romaji, json),
+        SearchTarget::Kanji => kanji(&query, json),
+        SearchTarget::Names => names(&query, romaji, json),
+        SearchTarget::Sentences => sentences(&query, json),
+    }
+}
+
+/// Parses the name of a search target, allowing singular names as well
+fn parse_target(name: &str) -> Option<SearchTarget> {
+    let name = name.to_lowercase();
+    SearchTarget::iterate().find(|i| {
+        let plural = i.as_str();
+        name == plural || Some(name.as_str()) == plural.strip_suffix('s')
+    })
+}
+
+/// Returns the parts of the indexes a search for `target` needs
+fn index_parts(target: SearchTarget) -> &'static [IndexPart] {
+    match target {
+        // Words get ordered by the kanji reading frequencies and kanji compounds are words
+        SearchTarget::Words | SearchTarget::Kanji => &[IndexPart::Word, IndexPart::Kanji],
+        SearchTarget::Names => &[IndexPart::Name],
+        SearchTarget::Sentences => &[IndexPart::Sentence],
+    }
+}
+
+fn words(query: &Query, romaji: bool, json: bool) {
+    let search = search::word::Search::new(query);
+    let result = SearchExecutor::new(search).run_uncached();
+
+    if json {
+        let response = api::search::word::conv_result(&result, romaji);
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+        return;
+    }
+
+    print_total(result.total);
+    for word in result.iter() {
+        let reading = &word.get_reading().reading;
+        let kana = word.get_kana();
+        let reading = if reading == kana {
+            reading.to_string()
+        } else {
+            format!("{reading} ({kana})")
+        };
+
+        let mut info = vec![];
+        if word.is_common() {
+            info.push("common".to_string());
+        }
+        if let Some(jlpt) = word.get_jlpt_lvl() {
+            info.push(format!("N{jlpt}"));
+        }
+        if romaji {
+            info.push(api::romaji::romanize_word(word));
+        }
+
+        println!("{reading}  {}", info.join(", "));
+        println!("    {}", word.glosses_pretty());
+    }
+}
+
+fn kanji(query: &Query, json: bool) {
+    let result = search::kanji::search(query).unwrap_or_else(|err| {
+        eprintln!("Search failed: {err:?}");
+        exit(1);
+    });
+
+    if json {
+        let response = api::search::kanji::to_response(&result.items);
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+        return;
+    }
+
+    print_total(result.total_len);
+    for item in &result.items {
+        let kanji = &item.kanji;
+        let mut info = vec![format!("{} strokes", kanji.stroke_count)];
+        if let Some(grade) = kanji.grade {
+            info.push(format!("grade {grade}"));
+        }
+        if let Some(jlpt) = kanji.jlpt {
+            info.push(format!("N{jlpt}"));
+        }
+
+        println!("{}  {}", kanji.literal, info.join(", "));
+        println!("    {}", kanji.meanings.join(", "));
+        if !kanji.onyomi.is_empty() {
+            println!("    on:  {}", kanji.onyomi.join("、"));
+        }
+        if !kanji.kunyomi.is_empty() {
+            println!("    kun: {}", kanji.kunyomi.join("、"));
+        }
+    }
+}
+
+fn names(query: &Query, romaji: bool, json: bool) {
+    let search = search::name::Search::new(query);
+    let result = SearchExecutor::new(search).run_uncached();
+
+    if json {
+        let response = api::search::name::conv_result(&result, romaji);
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+        return;
+    }
+
+    print_total(result.total);
+    for name in result.iter() {
+        match &name.kanji {
+            Some(kanji) => print!("{kanji} ({})", name.kana),
+            None => print!("{}", name.kana),
+        }
+        if romaji {
+            print!("  {}", api::romaji::romanize_kana(&name.kana));
+        }
+        println!();
+        println!("    {}", name.transcription);
+    }
+}
+
+fn sentences(query: &Query, json: bool) {
+    let search = search::sentence::Search::new(query);
+    let result = SearchExecutor::new(search).run_uncached();
+
+    if json {
+        let response = api::search::sentence::conv_result(&result);
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+        return;
+    }
+
+    print_total(result.total);
+    for sentence in result.iter() {
+        println!("{}", sentence.content);
+        println!("    {}", sentence.translation);
+        if let Some(eng) = sentence.get_english() {
+            println!("    {eng}");
+        }
+    }
+}
+
+fn print_total(total: usize) {
+    println!("Results: {total}\n");
+}
@@ -0,0 +1,142 @@
+use argparse::{ArgumentParser, Store, StoreOption};
+use config::Config;
+use frontend::query_log::{self, SlowQuery, ZeroResult};
+use std::{collections::HashMap, process::exit};
+
+/// Prints a summary of the slow query and zero result logs
+pub(super) fn run(args: Vec<String>) {
+    let mut slow_log: Option<String> = None;
+    let mut zero_log: Option<String> = None;
+    let mut top_n: usize = 20;
+    let mut min_count: usize = 2;
+
+    {
+        let mut ap = ArgumentParser::new();
+        ap.set_description("Summarise the slow query and zero result logs");
+
+        ap.refer(&mut slow_log).add_option(
+            &["--slow"],
+            StoreOption,
+            "Slow query log. Defaults to the configured one",
+        );
+
+        ap.refer(&mut zero_log).add_option(
+            &["--zero"],
+            StoreOption,
+            "Zero result log. Defaults to the configured one",
+        );
+
+        ap.refer(&mut top_n)
+            .add_option(&["--top", "-n"], Store, "Amount of entries to show");
+
+        ap.refer(&mut min_count).add_option(
+            &["--min-count"],
+            Store,
+            "Only show failed queries searched at least this often",
+        );
+
+        super::parse_args(ap, args);
+    }
+
+    let config = Config::new(None).expect("config failed");
+    let slow_log = slow_log.or_else(|| config.get_slow_query_log().map(|i| i.to_string()));
+    let zero_log = zero_log.or_else(|| config.get_zero_result_log().map(|i| i.to_string()));
+
+    if slow_log.is_none() && zero_log.is_none() {
+        println!("No log configured or provided");
+        exit(1);
+    }
+
+    if let Some(path) = slow_log {
+        match query_log::read::<SlowQuery, _>(&path) {
+            Ok(entries) => print_slow(&entries, top_n),
+            Err(err) => println!("Failed to read {path}: {err}"),
+        }
+    }
+
+    if let Some(path) = zero_log {
+        match query_log::read::<ZeroResult, _>(&path) {
+            Ok(entries) => print_zero(&entries, top_n, min_count),
+            Err(err) => println!("Failed to read {path}: {err}"),
+        }
+    }
+}
+
+fn print_slow(entries: &[SlowQuery], top_n: usize) {
+    println!("Slow queries: {}", entries.len());
+    if entries.is_empty() {
+        return;
+    }
+
+    let mut durations: Vec<f64> = entries.iter().map(|i| i.duration_ms).collect();
+    durations.sort_by(|a, b| a.total_cmp(b));
+    let avg = durations.iter().sum::<f64>() / durations.len() as f64;
+    let median = durations[durations.len() / 2];
+    let max = durations[durations.len() - 1];
+    println!("  avg {avg:.0}ms, median {median:.0}ms, max {max:.0}ms");
+
+    println!("\nSlowest:");
+    let mut slowest: Vec<&SlowQuery> = entries.iter().collect();
+    slowest.sort_by(|a, b| b.duration_ms.total_cmp(&a.duration_ms));
+    for entry in slowest.into_iter().take(top_n) {
+        println!(
+            "  {:>8.0}ms  {:<9} {:<10} {:>5} results  {}",
+            entry.duration_ms,
+            entry.target,
+            entry.language.to_string(),
+            entry.results,
+            entry.query
+        );
+    }
+
+    // (runs, total duration, max duration)
+    let mut producers: HashMap<&str, (usize, f64, f64)> = HashMap::new();
+    for producer in entries.iter().flat_map(|i| i.producers.iter()) {
+        let stats = producers.entry(&producer.name).or_default();
+        stats.0 += 1;
+        stats.1 += producer.duration_ms;
+        stats.2 = stats.2.max(producer.duration_ms);
+    }
+
+    let mut producers: Vec<_> = producers.into_iter().collect();
+    producers.sort_by(|a, b| b.1 .1.total_cmp(&a.1 .1));
+
+    println!("\nProducers by total time:");
+    for (name, (runs, total, max)) in producers.into_iter().take(top_n) {
+        let avg = total / runs as f64;
+        println!("  {total:>10.0}ms  {runs:>6} runs  avg {avg:>6.0}ms  max {max:>6.0}ms  {name}");
+    }
+}
+
+fn print_zero(entries: &[ZeroResult], top_n: usize, min_count: usize) {
+    let mut counts: HashMap<(&str, &str, String), usize> = HashMap::new();
+    for entry in entries {
+        let key = (
+            entry.query.as_str(),
+            entry.target.as_str(),
+            entry.language.to_string(),
+        );
+        *counts.entry(key).or_default() += 1;
+    }
+
+    println!(
+        "\nSearches without results: {} ({} distinct)",
+        entries.len(),
+        counts.len()
+    );
+
+    let mut counts: Vec<_> = counts
+        .into_iter()
+        .filter(|(_, count)| *count >= min_count)
+        .collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    if counts.is_empty() {
+        return;
+    }
+
+    println!("\nMost searched without results (at least {min_count} times):");
+    for ((query, target, language), count) in counts.into_iter().take(top_n) {
+        println!("  {count:>6}  {target:<9} {language:<10} {query}");
+    }
+}
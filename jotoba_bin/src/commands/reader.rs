@@ -0,0 +1,86 @@
+use argparse::{ArgumentParser, Store, StoreOption, StoreTrue};
+use indexes::storage::IndexPart;
+use search::reader::Token;
+use std::{fs, process::exit};
+
+/// Splits a japanese text into words and prints them
+pub(super) fn run(args: Vec<String>) {
+    let mut text = String::new();
+    let mut file: Option<String> = None;
+    let mut json = false;
+
+    {
+        let mut ap = ArgumentParser::new();
+        ap.set_description("Split a japanese text into words");
+
+        ap.refer(&mut file)
+            .add_option(&["--file", "-f"], StoreOption, "Read the text from a file");
+
+        ap.refer(&mut json).add_option(
+            &["--json"],
+            StoreTrue,
+            "Print the words like the reader API does",
+        );
+
+        ap.refer(&mut text)
+            .add_argument("text", Store, "The text to read");
+
+        super::parse_args(ap, args);
+    }
+
+    if let Some(file) = file {
+        text = fs::read_to_string(&file).unwrap_or_else(|err| {
+            eprintln!("Failed to read {file}: {err}");
+            exit(1);
+        });
+    }
+
+    if text.trim().is_empty() {
+        eprintln!("No text provided");
+        exit(1);
+    }
+
+    super::load_data(&[IndexPart::Word, IndexPart::Kanji], true);
+
+    let sentences = search::reader::read_text(&text);
+
+    if json {
+        let response = api::reader::conv_sentences(sentences);
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+        return;
+    }
+
+    for (pos, sentence) in sentences.iter().enumerate() {
+        if pos > 0 {
+            println!();
+        }
+        for token in sentence {
+            print_token(token);
+        }
+    }
+}
+
+fn print_token(token: &Token) {
+    let part = &token.part;
+    let inflected = part.get_inflected();
+
+    let mut info = vec![];
+    if let Some(furigana) = part.furigana() {
+        info.push(furigana.to_string());
+    }
+    let normalized = part.get_normalized();
+    if normalized != inflected {
+        info.push(format!("<- {normalized}"));
+    }
+    if let Some(word_class) = part.word_class() {
+        info.push(word_class.to_string());
+    }
+    if let Some(jlpt) = token.jlpt_lvl() {
+        info.push(format!("N{jlpt}"));
+    }
+
+    println!("{inflected}  {}", info.join(", "));
+    if let Some(word) = token.words.first() {
+        println!("    {}", word.glosses_pretty());
+    }
+}
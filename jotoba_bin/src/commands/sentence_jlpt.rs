@@ -0,0 +1,52 @@
+use argparse::{ArgumentParser, Store};
+use indexes::storage::IndexPart;
+use rayon::prelude::*;
+use std::{collections::HashMap, fs::File, io::BufWriter, process::exit};
+
+/// Guesses the JLPT level of all example sentences and writes the updated resources
+pub(super) fn run(args: Vec<String>) {
+    let mut output = String::new();
+
+    {
+        let mut ap = ArgumentParser::new();
+        ap.set_description("Guess the JLPT level of all sentences using the difficulty estimator");
+
+        ap.refer(&mut output)
+            .add_argument("output", Store, "File to write the updated resources to")
+            .required();
+
+        super::parse_args(ap, args);
+    }
+
+    super::load_data(&[IndexPart::Word, IndexPart::Kanji], true);
+
+    let storage = resources::get();
+    let sentences: Vec<_> = storage.sentences().iter().collect();
+
+    let guesses: HashMap<u32, u8> = sentences
+        .par_iter()
+        .filter_map(|sentence| {
+            let guess = search::reader::difficulty::estimate_sentence_jlpt(&sentence.japanese)?;
+            Some((sentence.id, guess))
+        })
+        .collect();
+
+    let mut storage = storage.clone();
+    storage.sentences.set_jlpt_guesses(&guesses);
+
+    for lvl in (1..=5).rev() {
+        let count = storage.sentences.jlpt_map.get(&lvl).map_or(0, |i| i.len());
+        println!("N{lvl}: {count}");
+    }
+    println!("none: {}", sentences.len() - guesses.len());
+
+    let file = File::create(&output).unwrap_or_else(|err| {
+        eprintln!("Failed to create {output}: {err}");
+        exit(1);
+    });
+
+    if let Err(err) = resources::store(BufWriter::new(file), &storage) {
+        eprintln!("Failed to write resources: {err}");
+        exit(1);
+    }
+}
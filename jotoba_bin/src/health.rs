@@ -0,0 +1,37 @@
+use actix_web::HttpResponse;
+use indexes::storage::suggestions;
+use serde_json::{json, Map, Value};
+
+/// Returns all components which have to be loaded in order to serve requests, along with
+/// whether they're loaded already
+pub fn components() -> [(&'static str, bool); 4] {
+    [
+        ("resources", resources::is_loaded()),
+        ("indexes", indexes::storage::is_loaded()),
+        ("tokenizer", sentence_reader::is_loaded()),
+        ("suggestions", suggestions::is_loaded()),
+    ]
+}
+
+/// Reports that the process is up
+pub async fn healthz() -> HttpResponse {
+    HttpResponse::Ok().body("ok")
+}
+
+/// Reports whether all components are loaded and searches can be served
+pub async fn readyz() -> HttpResponse {
+    let components = components();
+    let ready = components.iter().all(|(_, loaded)| *loaded);
+
+    let loaded: Map<String, Value> = components
+        .iter()
+        .map(|(name, loaded)| (name.to_string(), Value::Bool(*loaded)))
+        .collect();
+    let body = json!({ "ready": ready, "components": loaded });
+
+    if ready {
+        HttpResponse::Ok().json(body)
+    } else {
+        HttpResponse::ServiceUnavailable().json(body)
+    }
+}
@@ -6,6 +6,10 @@ static ALLOC: snmalloc_rs::SnMalloc = snmalloc_rs::SnMalloc;
 
 mod check;
 mod cli;
+mod commands;
+mod health;
+mod metrics;
+mod rate_limit;
 mod webserver;
 
 #[actix_web::main]
@@ -18,6 +22,12 @@ pub async fn main() {
         return;
     }
 
+    // Run a command like `difficulty`
+    if !options.command.is_empty() {
+        commands::run(&options.command, options.command_args);
+        return;
+    }
+
     // Start the webserver on --stat/-s
     if options.start {
         webserver::start(options).await.expect("webserver failed");
@@ -0,0 +1,143 @@
+use actix_web::{web::Data, HttpResponse};
+use config::Config;
+use once_cell::sync::OnceCell;
+use search::metrics::{
+    self as search_metrics, labels, write_header, write_sample, Counter, Family, Histogram,
+};
+use std::{fs, path::Path, time::Duration};
+
+use crate::health;
+
+/// Content type of the prometheus text format
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Handled requests by route, method and status code
+static REQUESTS: Family<Counter> = Family::new();
+
+/// Duration of handled requests by route
+static REQUEST_DURATION: Family<Histogram> = Family::new();
+
+/// Sizes of the index files by name. The indexes can't be replaced once loaded, so their files
+/// only have to be read once
+static INDEX_SIZES: OnceCell<Vec<(String, u64)>> = OnceCell::new();
+
+/// Records a handled request. `route` is the pattern of the matched resource, not the actual
+/// path, to keep the amount of label values low
+pub fn observe_request(route: &str, method: &str, status: u16, duration: Duration) {
+    let status = status.to_string();
+    let counter_labels = labels(&[("route", route), ("method", method), ("status", &status)]);
+    REQUESTS.with(&counter_labels, |c| c.inc());
+    REQUEST_DURATION.with(&labels(&[("route", route)]), |h| h.observe(duration));
+}
+
+/// Serves all metrics in the prometheus text format
+pub async fn metrics(config: Data<Config>) -> HttpResponse {
+    let mut out = String::new();
+
+    REQUESTS.write(
+        &mut out,
+        "jotoba_http_requests_total",
+        "Handled requests by route, method and status",
+    );
+    REQUEST_DURATION.write(
+        &mut out,
+        "jotoba_http_request_duration_seconds",
+        "Duration of handled requests by route",
+    );
+
+    search_metrics::write(&mut out);
+    write_components(&mut out);
+    write_resources(&mut out);
+    write_index_sizes(&mut out, config.get_indexes_source());
+
+    HttpResponse::Ok().content_type(CONTENT_TYPE).body(out)
+}
+
+fn write_components(out: &mut String) {
+    let name = "jotoba_component_loaded";
+    write_header(out, name, "Whether a component is loaded", "gauge");
+    for (component, loaded) in health::components() {
+        let labels = labels(&[("component", component)]);
+        write_sample(out, name, &labels, loaded as u8);
+    }
+}
+
+fn write_resources(out: &mut String) {
+    let storage = match resources::try_get() {
+        Some(storage) => storage,
+        None => return,
+    };
+
+    let name = "jotoba_feature_loaded";
+    write_header(out, name, "Whether a feature is available", "gauge");
+    let features = storage.get_features();
+    for feature in resources::Feature::all() {
+        let labels = labels(&[("feature", &format!("{feature:?}"))]);
+        write_sample(out, name, &labels, features.contains(&feature) as u8);
+    }
+
+    let name = "jotoba_resource_entries";
+    write_header(out, name, "Amount of entries per resource", "gauge");
+    let counts = [
+        ("words", storage.words().count()),
+        ("kanji", storage.kanji().count()),
+        ("names", storage.names().count()),
+        ("sentences", storage.sentences().count()),
+    ];
+    for (resource, count) in counts {
+        write_sample(out, name, &labels(&[("resource", resource)]), count);
+    }
+}
+
+fn write_index_sizes(out: &mut String, index_folder: &str) {
+    if !indexes::storage::is_loaded() {
+        return;
+    }
+
+    let sizes = INDEX_SIZES.get_or_init(|| index_sizes(index_folder));
+    if sizes.is_empty() {
+        return;
+    }
+
+    let name = "jotoba_index_size_bytes";
+    write_header(out, name, "Size of the index files", "gauge");
+    for (index, size) in sizes {
+        write_sample(out, name, &labels(&[("index", index.as_str())]), size);
+    }
+}
+
+/// Returns the sizes of all files within `index_folder` by their names
+fn index_sizes(index_folder: &str) -> Vec<(String, u64)> {
+    let entries = match fs::read_dir(index_folder) {
+        Ok(entries) => entries,
+        Err(_) => return vec![],
+    };
+
+    let mut sizes: Vec<(String, u64)> = entries
+        .filter_map(|i| i.ok())
+        .map(|i| {
+            (
+                i.file_name().to_string_lossy().to_string(),
+                size_of(&i.path()),
+            )
+        })
+        .collect();
+    sizes.sort();
+    sizes
+}
+
+/// Returns the size of a file or all files within a directory
+fn size_of(path: &Path) -> u64 {
+    if !path.is_dir() {
+        return fs::metadata(path).map(|i| i.len()).unwrap_or(0);
+    }
+
+    fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .filter_map(|i| i.ok())
+                .map(|i| size_of(&i.path()))
+                .sum()
+        })
+        .unwrap_or(0)
+}
@@ -0,0 +1,329 @@
+use actix_web::{dev::ServiceRequest, HttpRequest};
+use api::RateLimit;
+use config::RateLimitConfig;
+use error::api_error::RestError;
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// Header to pass an API key in
+pub const API_KEY_HEADER: &str = "X-API-Key";
+
+/// Costs of expensive routes which aren't configured otherwise. Handlers of routes whose cost
+/// depends on the request, like batch searches, charge the rest of it themselves
+const DEFAULT_COSTS: &[(&str, u32)] = &[
+    ("/api/search/words", 2),
+    ("/api/search/sentences", 2),
+    ("/api/search/batch", 10),
+    ("/api/app/words", 3),
+    ("/api/app/names", 3),
+    ("/api/app/sentences", 3),
+    ("/api/app/kanji", 3),
+    ("/api/compounds", 3),
+    ("/api/compounds/split", 5),
+    ("/api/kanji/confusables", 5),
+    ("/api/furigana", 3),
+    ("/api/reader", 5),
+    ("/api/reader/difficulty", 5),
+    ("/api/img_scan", 20),
+];
+
+/// Lower bound for the configured refill rate
+const MIN_PER_SECOND: f64 = 0.001;
+
+/// Max amount of tracked clients. Once reached, clients with a full budget get dropped. If
+/// that isn't enough, the clients which were seen the longest time ago get dropped as well
+const MAX_CLIENTS: usize = 10_000;
+
+/// Limits requests per client using token buckets
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    costs: HashMap<String, u32>,
+    buckets: Mutex<HashMap<String, Bucket>>,
+    max_clients: usize,
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        let mut costs: HashMap<String, u32> = DEFAULT_COSTS
+            .iter()
+            .map(|(route, cost)| (route.to_string(), *cost))
+            .collect();
+        costs.extend(config.costs.clone().unwrap_or_default());
+
+        Self {
+            config,
+            costs,
+            buckets: Mutex::new(HashMap::new()),
+            max_clients: MAX_CLIENTS,
+        }
+    }
+
+    /// Charges the client of `req` for the request. Returns `RestError::TooManyRequests` if
+    /// its budget is exceeded
+    pub fn check(&self, req: &ServiceRequest) -> Result<(), RestError> {
+        let req = req.request();
+        self.charge_client(req, self.route_cost(req))
+    }
+
+    /// Returns the cost of the route of `req`
+    fn route_cost(&self, req: &HttpRequest) -> u32 {
+        req.match_pattern()
+            .and_then(|route| self.costs.get(&route).copied())
+            .unwrap_or(1)
+    }
+
+    /// Takes `cost` tokens from the budget of the client of `req`
+    fn charge_client(&self, req: &HttpRequest, cost: u32) -> Result<(), RestError> {
+        let client = match self.client_id(req) {
+            Some(client) => client,
+            None => return Ok(()),
+        };
+
+        self.take(client, cost, Instant::now())
+            .map_err(|wait| RestError::TooManyRequests(wait.as_secs_f64().ceil() as u64))
+    }
+
+    /// Returns the ID of the bucket to charge for `req` or `None` if the client isn't limited
+    fn client_id(&self, req: &HttpRequest) -> Option<String> {
+        let api_key = req
+            .headers()
+            .get(API_KEY_HEADER)
+            .and_then(|i| i.to_str().ok())
+            .filter(|i| self.config.is_api_key(i));
+
+        if let Some(key) = api_key {
+            if self.config.is_allowed(key) {
+                return None;
+            }
+            return Some(format!("key:{key}"));
+        }
+
+        let info = req.connection_info();
+        let ip = if self.config.is_behind_proxy() {
+            info.realip_remote_addr()
+        } else {
+            info.peer_addr()
+        }?;
+
+        if self.config.is_allowed(ip) {
+            return None;
+        }
+
+        Some(format!("ip:{ip}"))
+    }
+
+    /// Locks the buckets. A panic while holding the lock can't leave them in an inconsistent
+    /// state, so a poisoned lock gets recovered instead of failing all following requests
+    #[inline]
+    fn buckets(&self) -> MutexGuard<HashMap<String, Bucket>> {
+        self.buckets.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Takes `cost` tokens from the bucket of `client`. Returns the time until enough tokens
+    /// are available if the bucket doesn't hold enough
+    fn take(&self, client: String, cost: u32, now: Instant) -> Result<(), Duration> {
+        let burst = self.config.get_burst() as f64;
+        let per_second = self.config.get_per_second().max(MIN_PER_SECOND);
+        // Requests costing more than the burst would never be possible otherwise
+        let cost = (cost as f64).min(burst);
+
+        let mut buckets = self.buckets();
+        if buckets.len() >= self.max_clients && !buckets.contains_key(&client) {
+            buckets.retain(|_, bucket| bucket.tokens_at(now, per_second, burst) < burst);
+            evict_oldest(&mut buckets, self.max_clients);
+        }
+
+        let bucket = buckets.entry(client).or_insert(Bucket {
+            tokens: burst,
+            updated: now,
+        });
+
+        bucket.tokens = bucket.tokens_at(now, per_second, burst);
+        bucket.updated = now;
+
+        if bucket.tokens >= cost {
+            bucket.tokens -= cost;
+            return Ok(());
+        }
+
+        let missing = cost - bucket.tokens;
+        Err(Duration::from_secs_f64(missing / per_second))
+    }
+}
+
+impl RateLimit for RateLimiter {
+    fn charge(&self, req: &HttpRequest, cost: u32) -> Result<(), RestError> {
+        // The cost of the route was charged before the request got handled already
+        let cost = cost.saturating_sub(self.route_cost(req));
+        if cost == 0 {
+            return Ok(());
+        }
+
+        self.charge_client(req, cost)
+    }
+}
+
+/// Drops the least recently seen buckets until at most 90% of `max` buckets are left. Dropped
+/// clients start over with a full budget
+fn evict_oldest(buckets: &mut HashMap<String, Bucket>, max: usize) {
+    let keep = max - max / 10;
+    if buckets.len() <= keep {
+        return;
+    }
+
+    let mut updated: Vec<_> = buckets.values().map(|i| i.updated).collect();
+    let drop = buckets.len() - keep;
+    let (_, cutoff, _) = updated.select_nth_unstable(drop - 1);
+    let cutoff = *cutoff;
+    buckets.retain(|_, bucket| bucket.updated > cutoff);
+}
+
+impl Bucket {
+    /// Returns the amount of tokens the bucket has at `now`
+    #[inline]
+    fn tokens_at(&self, now: Instant, per_second: f64, burst: f64) -> f64 {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        (self.tokens + elapsed * per_second).min(burst)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use actix_web::test::TestRequest;
+
+    const IP: &str = "10.0.0.1";
+
+    fn limiter(burst: u32, per_second: f64) -> RateLimiter {
+        RateLimiter::new(RateLimitConfig {
+            burst: Some(burst),
+            per_second: Some(per_second),
+            api_keys: Some(vec!["key".to_string(), "free-key".to_string()]),
+            allowlist: Some(vec!["10.0.0.2".to_string(), "free-key".to_string()]),
+            ..Default::default()
+        })
+    }
+
+    fn request(ip: &str, api_key: Option<&str>) -> ServiceRequest {
+        let mut req = TestRequest::default().peer_addr(format!("{ip}:4000").parse().unwrap());
+        if let Some(key) = api_key {
+            req = req.insert_header((API_KEY_HEADER, key));
+        }
+        req.to_srv_request()
+    }
+
+    #[test]
+    fn test_burst() {
+        let limiter = limiter(3, 1.0);
+        let now = Instant::now();
+        for _ in 0..3 {
+            assert_eq!(limiter.take(IP.to_string(), 1, now), Ok(()));
+        }
+        assert!(limiter.take(IP.to_string(), 1, now).is_err());
+
+        // Other clients have their own budget
+        assert_eq!(limiter.take("10.0.0.3".to_string(), 3, now), Ok(()));
+    }
+
+    #[test]
+    fn test_cost_above_burst() {
+        let limiter = limiter(3, 1.0);
+        let now = Instant::now();
+        assert_eq!(limiter.take(IP.to_string(), 20, now), Ok(()));
+        assert!(limiter.take(IP.to_string(), 1, now).is_err());
+    }
+
+    #[test]
+    fn test_refill() {
+        let limiter = limiter(3, 2.0);
+        let now = Instant::now();
+        assert_eq!(limiter.take(IP.to_string(), 3, now), Ok(()));
+
+        let later = now + Duration::from_secs(1);
+        assert_eq!(limiter.take(IP.to_string(), 2, later), Ok(()));
+        assert!(limiter.take(IP.to_string(), 1, later).is_err());
+
+        // Refilling stops at the burst
+        let much_later = later + Duration::from_secs(60);
+        assert_eq!(limiter.take(IP.to_string(), 3, much_later), Ok(()));
+        assert!(limiter.take(IP.to_string(), 1, much_later).is_err());
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let limiter = limiter(2, 0.25);
+        let now = Instant::now();
+        assert_eq!(limiter.take(IP.to_string(), 2, now), Ok(()));
+        assert_eq!(
+            limiter.take(IP.to_string(), 1, now + Duration::from_secs(1)),
+            Err(Duration::from_secs(3))
+        );
+    }
+
+    #[test]
+    fn test_retry_after_rounded() {
+        let limiter = limiter(2, 0.25);
+        assert_eq!(limiter.check(&request(IP, None)), Ok(()));
+        assert_eq!(limiter.check(&request(IP, None)), Ok(()));
+        let res = limiter.check(&request(IP, None));
+        assert_eq!(res, Err(RestError::TooManyRequests(4)));
+    }
+
+    #[test]
+    fn test_charge() {
+        let limiter = limiter(20, 0.001);
+        let req = request(IP, None);
+        assert_eq!(limiter.check(&req), Ok(()));
+
+        // Only the part of the cost exceeding the one of the route gets charged
+        assert_eq!(limiter.charge(req.request(), 15), Ok(()));
+        assert_eq!(limiter.charge(req.request(), 6), Ok(()));
+        assert_eq!(limiter.charge(req.request(), 1), Ok(()));
+        assert!(limiter.charge(req.request(), 2).is_err());
+        assert!(limiter.check(&req).is_err());
+
+        let req = request("10.0.0.2", None);
+        assert_eq!(limiter.charge(req.request(), 100), Ok(()));
+    }
+
+    #[test]
+    fn test_allowlist() {
+        let limiter = limiter(1, 0.001);
+
+        for _ in 0..5 {
+            assert_eq!(limiter.check(&request("10.0.0.2", None)), Ok(()));
+            assert_eq!(limiter.check(&request(IP, Some("free-key"))), Ok(()));
+        }
+
+        // Known API keys get their own budget, unknown ones share the one of their IP
+        assert_eq!(limiter.check(&request(IP, None)), Ok(()));
+        assert_eq!(limiter.check(&request(IP, Some("key"))), Ok(()));
+        assert!(limiter.check(&request(IP, Some("unknown"))).is_err());
+        assert!(limiter.check(&request(IP, Some("key"))).is_err());
+    }
+
+    #[test]
+    fn test_max_clients() {
+        let mut limiter = limiter(5, 0.001);
+        limiter.max_clients = 10;
+
+        let start = Instant::now();
+        for i in 0..25 {
+            let now = start + Duration::from_secs(i);
+            assert_eq!(limiter.take(format!("ip:{i}"), 1, now), Ok(()));
+            assert!(limiter.buckets().len() <= 10);
+        }
+
+        let buckets = limiter.buckets();
+        assert!(buckets.contains_key("ip:24"));
+        assert!(!buckets.contains_key("ip:0"));
+    }
+}
@@ -3,16 +3,18 @@ use indexes::storage::suggestions;
 use localization::TranslationDict;
 
 use actix_web::{
+    dev::Service,
     http::header::{ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_ORIGIN, CACHE_CONTROL},
     middleware::{self, Compat, Compress},
     web::{self as actixweb, Data},
-    App, HttpRequest, HttpServer,
+    App, HttpRequest, HttpServer, ResponseError,
 };
+use api::RateLimit;
 use config::Config;
 use log::{debug, warn};
-use std::{path::Path, sync::Arc, thread, time::Instant};
+use std::{panic, path::Path, process, sync::Arc, thread, time::Instant};
 
-use crate::{check, cli::Options};
+use crate::{check, cli::Options, health, metrics, rate_limit::RateLimiter};
 
 /// How long frontend assets are going to be cached by the clients. Currently 1 week
 const ASSET_CACHE_MAX_AGE: u64 = 604800;
@@ -29,14 +31,26 @@ pub(super) async fn start(options: Options) -> std::io::Result<()> {
 
     setup_logger();
 
-    let start = Instant::now();
-
     let config = Config::new(None).expect("config failed");
     if options.debug {
         println!("{config:#?}");
     }
 
-    prepare_data(&config);
+    // Load the data in the background so the server is reachable right away. Endpoints report
+    // data which isn't loaded yet on their own
+    let cf = config.clone();
+    thread::spawn(move || {
+        let start = Instant::now();
+        let loaded = panic::catch_unwind(|| prepare_data(&cf)).is_ok();
+
+        if !loaded || !check() {
+            log::error!("Failed to load data! Exiting");
+            process::exit(1);
+        }
+
+        debug!("Resource loading took {:?}", start.elapsed());
+        debug_info();
+    });
 
     let locale_dict_arc = load_translations(&config);
 
@@ -44,21 +58,43 @@ pub(super) async fn start(options: Options) -> std::io::Result<()> {
     setup_sentry(&config);
 
     let address = config.server.listen_address.clone();
-
-    if !check() {
-        return Ok(());
-    }
-
-    debug!("Resource loading took {:?}", start.elapsed());
-    debug_info();
+    let metrics_enabled = config.server.metrics_enabled();
+    let health_checks = config.server.health_checks_enabled();
+    let rate_limiter = config
+        .rate_limit
+        .clone()
+        .map(|i| Arc::new(RateLimiter::new(i)));
 
     HttpServer::new(move || {
+        let rate_limiter = rate_limiter.clone();
+        // Lets handlers charge requests whose cost depends on their content
+        let request_limiter = rate_limiter
+            .clone()
+            .map(|i| Data::from(i as Arc<dyn RateLimit>));
         let app = App::new()
             // Data
             .app_data(Data::new(config.clone()))
             .app_data(Data::new(locale_dict_arc.clone()))
             // Middlewares
             .wrap(middleware::Logger::default())
+            .wrap_fn(move |req, srv| {
+                let start = Instant::now();
+                let route = metrics_enabled.then(|| {
+                    let route = req.match_pattern();
+                    let route = route.unwrap_or_else(|| String::from("unmatched"));
+                    (route, req.method().to_string())
+                });
+
+                let res = srv.call(req);
+                async move {
+                    let res = res.await?;
+                    if let Some((route, method)) = route {
+                        let status = res.status().as_u16();
+                        metrics::observe_request(&route, &method, status, start.elapsed());
+                    }
+                    Ok(res)
+                }
+            })
             .service(
                 actixweb::resource("/")
                     .wrap(Compat::new(middleware::Compress::default()))
@@ -113,6 +149,24 @@ pub(super) async fn start(options: Options) -> std::io::Result<()> {
             // API
             .service(
                 actixweb::scope("/api")
+                    .wrap_fn(move |req, srv| {
+                        let limited = match rate_limiter {
+                            Some(ref limiter) => limiter.check(&req),
+                            None => Ok(()),
+                        };
+
+                        let res = match limited {
+                            Ok(()) => Ok(srv.call(req)),
+                            Err(err) => Err(req.into_response(err.error_response())),
+                        };
+
+                        async move {
+                            match res {
+                                Ok(res) => res.await.map(|i| i.map_into_left_body()),
+                                Err(res) => Ok(res.map_into_right_body()),
+                            }
+                        }
+                    })
                     .wrap(
                         middleware::DefaultHeaders::new()
                             .add((ACCESS_CONTROL_ALLOW_ORIGIN, "*"))
@@ -163,6 +217,10 @@ pub(super) async fn start(options: Options) -> std::io::Result<()> {
                             .route(
                                 "sentences",
                                 actixweb::post().to(api::search::sentence::sentence_search),
+                            )
+                            .route(
+                                "batch",
+                                actixweb::post().to(api::search::batch::batch_search),
                             ),
                     )
                     .service(
@@ -174,6 +232,14 @@ pub(super) async fn start(options: Options) -> std::io::Result<()> {
                             .route(
                                 "decompgraph",
                                 actixweb::post().to(api::kanji::ids_tree::decomp_graph),
+                            )
+                            .route(
+                                "phonetic_series",
+                                actixweb::post().to(api::kanji::phonetic::phonetic_series),
+                            )
+                            .route(
+                                "confusables",
+                                actixweb::post().to(api::kanji::confusables::confusables),
                             ),
                     )
                     .route(
@@ -188,6 +254,18 @@ pub(super) async fn start(options: Options) -> std::io::Result<()> {
                         "/os-suggestions",
                         actixweb::get().to(api::completions::opensearch::suggestion_ep),
                     )
+                    .route("/reader", actixweb::post().to(api::reader::reader))
+                    .route("/furigana", actixweb::post().to(api::furigana::furigana))
+                    .route("/romaji", actixweb::post().to(api::romaji::romaji))
+                    .route(
+                        "/reader/difficulty",
+                        actixweb::post().to(api::reader::difficulty::difficulty),
+                    )
+                    .route("/compounds", actixweb::post().to(api::compound::compounds))
+                    .route(
+                        "/compounds/split",
+                        actixweb::post().to(api::compound::split),
+                    )
                     .route("/img_scan", actixweb::post().to(api::img::scan_ep))
                     .route("/news/short", actixweb::post().to(api::news::short::news))
                     .route(
@@ -234,6 +312,25 @@ pub(super) async fn start(options: Options) -> std::io::Result<()> {
                     ),
             );
 
+        // Operational endpoints
+        let app = if metrics_enabled {
+            app.route("/metrics", actixweb::get().to(metrics::metrics))
+        } else {
+            app
+        };
+
+        let app = if health_checks {
+            app.route("/healthz", actixweb::get().to(health::healthz))
+                .route("/readyz", actixweb::get().to(health::readyz))
+        } else {
+            app
+        };
+
+        let app = match request_limiter {
+            Some(limiter) => app.app_data(limiter),
+            None => app,
+        };
+
         //#[cfg(feature = "sentry_error")]
         //let app = app.wrap(sentry_actix::Sentry::new());
 
@@ -257,9 +354,20 @@ async fn docs(_req: HttpRequest) -> actix_web::Result<NamedFile> {
 }
 
 pub(crate) fn prepare_data(ccf: &Config) {
+    search::cache::init(ccf.get_search_cache_size(), ccf.get_search_cache_ttl());
+
     let cf = ccf.clone();
     thread::spawn(move || {
-        suggestions::load(cf.get_suggestion_sources()).expect("Failed to load suggestions");
+        // Localized names of hashtags which can be suggested
+        let dict = TranslationDict::new(
+            cf.server.get_locale_path(),
+            localization::language::Language::English,
+        )
+        .ok();
+        let extra_tags = search::query::tags::extra_hashtags(dict.as_ref());
+
+        suggestions::load(cf.get_suggestion_sources(), extra_tags)
+            .expect("Failed to load suggestions");
         log::debug!("Suggestions loaded");
     });
 
@@ -314,9 +422,14 @@ fn clean_img_scan_dir(config: &Config) {
 }
 
 fn debug_info() {
+    let storage = match resources::try_get() {
+        Some(storage) => storage,
+        None => return,
+    };
+
     log::debug!("All features: {:?}", resources::Feature::all());
-    log::debug!("Supported: {:?}", resources::get().get_features());
-    log::debug!("Not supported: {:?}", resources::get().missing_features());
+    log::debug!("Supported: {:?}", storage.get_features());
+    log::debug!("Not supported: {:?}", storage.missing_features());
 }
 
 pub fn load_resources(src: &str) {
@@ -341,7 +454,7 @@ pub fn load_indexes(config: &Config) {
 
 fn check() -> bool {
     if !check::resources() {
-        log::error!("Not all required data found! Exiting");
+        log::error!("Not all required data found!");
         return false;
     }
 
@@ -3,6 +3,7 @@ use actix_web::web::Json;
 use engine::task::SearchTask;
 use error::api_error::RestError;
 use japanese::JapaneseExt;
+use resources::retrieve::kanji::KanjiRetrieve;
 use search::{engine::words::native::Engine, word::order::native::NativeOrder};
 use sentence_reader::output::ParseResult;
 use types::{
@@ -14,13 +15,16 @@ use types::{
 };
 
 pub async fn details_ep(payload: Json<DetailsPayload>) -> Result<Json<sentence::Details>> {
+    crate::require(crate::SEARCH_DATA)?;
+
     Ok(Json(sentence_details(&payload).ok_or(RestError::NotFound)?))
 }
 
 fn sentence_details(payload: &DetailsPayload) -> Option<sentence::Details> {
-    let sentence = resources::get().sentences().by_id(payload.sequence)?;
+    let storage = resources::try_get()?;
+    let sentence = storage.sentences().by_id(payload.sequence)?;
 
-    let kanji = get_kanji(sentence);
+    let kanji = get_kanji(&storage.kanji(), sentence);
 
     let words = get_words(sentence, payload);
 
@@ -30,17 +34,17 @@ fn sentence_details(payload: &DetailsPayload) -> Option<sentence::Details> {
         payload.show_english,
     )?;
 
-    let sentence = convert_sentence(sentence);
+    let sentence = convert_sentence(&sentence);
     Some(sentence::Details::new(sentence, words, kanji))
 }
 
-fn get_kanji(sentence: &Sentence) -> Vec<Kanji> {
+fn get_kanji(retrieve: &KanjiRetrieve, sentence: &Sentence) -> Vec<Kanji> {
     let kanji_iter = sentence.japanese.chars().filter(|i| i.is_kanji());
 
     let mut out: Vec<Kanji> = vec![];
 
     for k_lit in kanji_iter {
-        if let Some(kanji) = resources::get().kanji().by_literal(k_lit) {
+        if let Some(kanji) = retrieve.by_literal(k_lit) {
             out.push(kanji.to_owned().into());
         }
     }
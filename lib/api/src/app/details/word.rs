@@ -1,7 +1,9 @@
 use crate::app::Result;
 use actix_web::web::Json;
 use error::api_error::RestError;
+use itertools::Itertools;
 use japanese::JapaneseExt;
+use resources::ResourceStorage;
 use types::{
     api::app::{
         details::{
@@ -13,24 +15,35 @@ use types::{
     jotoba::{languages::Language, words::adjust_language},
 };
 
+/// Max amount of related words to return
+const MAX_RELATED: usize = 20;
+
 pub async fn details(payload: Json<DetailsPayload>) -> Result<Json<word::Details>> {
+    crate::require(crate::SEARCH_DATA)?;
+    let storage = crate::resources()?;
+
     Ok(Json(
-        Details::new(&payload)
+        Details::new(storage, &payload)
             .ok_or(RestError::NotFound)?
             .get_details(),
     ))
 }
 
 pub(crate) struct Details<'a> {
+    storage: &'static ResourceStorage,
     payload: &'a DetailsPayload,
     word: &'static types::jotoba::words::Word,
 }
 
 impl<'a> Details<'a> {
     #[inline]
-    fn new(payload: &'a DetailsPayload) -> Option<Self> {
-        let word = resources::get().words().by_sequence(payload.sequence)?;
-        Some(Details { payload, word })
+    fn new(storage: &'static ResourceStorage, payload: &'a DetailsPayload) -> Option<Self> {
+        let word = storage.words().by_sequence(payload.sequence)?;
+        Some(Details {
+            storage,
+            payload,
+            word,
+        })
     }
 
     fn get_details(&self) -> word::Details {
@@ -38,7 +51,9 @@ impl<'a> Details<'a> {
         let has_sentence = self.has_sentence();
         let transitivity_pair = self.transitivity_pair();
         let collocations = self.get_collocations();
+        let related = self.get_related();
         let inflection_table = self.word.get_inflections();
+        let reading_breakdown = self.storage.kanji().reading_breakdown(self.word);
 
         let word = self.get_word();
 
@@ -46,14 +61,16 @@ impl<'a> Details<'a> {
             word,
             kanji,
             inflection_table,
+            reading_breakdown,
             collocations,
+            related,
             has_sentence,
             transitivity_pair,
         )
     }
 
     fn get_kanji(&self) -> Vec<Kanji> {
-        let retrieve = resources::get().kanji();
+        let retrieve = self.storage.kanji();
 
         self.word
             .get_reading()
@@ -87,7 +104,7 @@ impl<'a> Details<'a> {
             Some(colloc) => colloc,
             None => return vec![],
         };
-        let retrieve = resources::get().words();
+        let retrieve = self.storage.words();
 
         collocations
             .iter()
@@ -98,6 +115,28 @@ impl<'a> Details<'a> {
             .collect()
     }
 
+    /// Returns words referenced by the word (xref, antonym) followed by words referencing it
+    fn get_related(&self) -> Vec<Word> {
+        let retrieve = self.storage.words();
+
+        let outgoing = self
+            .word
+            .senses
+            .iter()
+            .flat_map(|i| i.xref_target.into_iter().chain(i.antonym_target))
+            .filter_map(|i| retrieve.by_sequence(i.sequence));
+
+        let incoming = retrieve.referenced_by(self.word.sequence);
+
+        outgoing
+            .chain(incoming)
+            .filter(|i| i.sequence != self.word.sequence)
+            .unique_by(|i| i.sequence)
+            .take(MAX_RELATED)
+            .map(|i| self.format_word(i))
+            .collect()
+    }
+
     #[inline]
     fn get_word(&self) -> Word {
         self.format_word(self.word)
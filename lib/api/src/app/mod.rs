@@ -47,6 +47,7 @@ pub(crate) fn conv_word(word: jotoba::words::Word, lang: Language) -> words::Wor
         intransive_verion: word.intransive_verion.map(|i| i.get()),
         sentences_available: word.sentences_available,
         audio,
+        explanation: None,
     }
 }
 
@@ -69,6 +70,8 @@ pub fn conv_ex_sentence(sense: jotoba::words::sense::Sense, lang: Language) -> w
         glosses,
         xref: sense.xref,
         antonym: sense.antonym,
+        xref_target: sense.xref_target,
+        antonym_target: sense.antonym_target,
         information: sense.information,
         part_of_speech: sense.part_of_speech,
         language: sense.language,
@@ -78,7 +81,7 @@ pub fn conv_ex_sentence(sense: jotoba::words::sense::Sense, lang: Language) -> w
 }
 
 fn get_example_sentence(id: u32, language: Language) -> Option<(String, String)> {
-    let sentence = resources::get().sentences().by_id(id)?;
+    let sentence = resources::try_get()?.sentences().by_id(id)?;
 
     let translation = sentence
         .translation_for(language)
@@ -9,9 +9,12 @@ use types::jotoba::search::SearchTarget;
 use types::{
     api::app::search::{
         query::SearchPayload,
-        responses::kanji::{self, CompoundWord},
+        responses::kanji::{self, CompoundWord, ReadingUsage},
+    },
+    jotoba::{
+        languages::Language,
+        words::{adjust_language, Word},
     },
-    jotoba::words::Word,
 };
 
 /// API response type
@@ -19,6 +22,8 @@ pub type Resp = Response<kanji::Response>;
 
 /// Do an app kanji search via API
 pub async fn search(payload: Json<SearchPayload>) -> Result<Json<Resp>> {
+    crate::require(crate::SEARCH_DATA)?;
+
     let query = convert_payload(&payload)
         .parse()
         .ok_or(RestError::BadRequest)?;
@@ -26,13 +31,18 @@ pub async fn search(payload: Json<SearchPayload>) -> Result<Json<Resp>> {
     let query_c = query.clone();
     let result = web::block(move || search::kanji::search(&query_c)).await??;
 
+    let lang = payload.settings.user_lang;
+    let show_english = payload.settings.show_english;
+
     let items = result
         .items
-        .into_iter()
+        .iter()
         .map(|i| {
-            let mut k: kanji::Kanji = i.kanji.into();
+            let reading_usage = convert_usage(&i.reading_usage, lang, show_english);
+            let mut k: kanji::Kanji = i.kanji.clone().into();
             k.set_on_compounds(convert_dicts(&i.on_dicts));
             k.set_kun_compounds(convert_dicts(&i.kun_dicts));
+            k.set_reading_usage(reading_usage);
             k
         })
         .collect::<Vec<_>>();
@@ -56,3 +66,31 @@ fn convert_dicts(dicts: &Option<Vec<Word>>) -> Vec<CompoundWord> {
         })
         .unwrap_or_default()
 }
+
+fn convert_usage(
+    usage: &[search::kanji::result::ReadingUsage],
+    lang: Language,
+    show_english: bool,
+) -> Vec<ReadingUsage> {
+    usage
+        .iter()
+        .map(|i| {
+            let examples = i
+                .examples()
+                .into_iter()
+                .map(|word| {
+                    let mut word = (*word).clone();
+                    adjust_language(&mut word, lang, show_english);
+                    CompoundWord::from_word(&word)
+                })
+                .collect();
+
+            ReadingUsage {
+                reading: i.reading.clone(),
+                count: i.count,
+                percentage: i.percent(),
+                examples,
+            }
+        })
+        .collect()
+}
@@ -62,7 +62,8 @@ pub(crate) fn convert_payload(pl: &SearchPayload) -> QueryParser {
         user_settings,
     )
     .with_page(pl.page.unwrap_or_default() as usize)
-    .with_word_index(pl.word_index.unwrap_or_default());
+    .with_word_index(pl.word_index.unwrap_or_default())
+    .with_explain(pl.explain);
 
     if let Some(lang) = pl.lang_overwrite {
         q_parser = q_parser.with_lang_overwrite(lang);
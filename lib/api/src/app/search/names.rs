@@ -3,6 +3,7 @@ use super::new_page;
 use super::convert_payload;
 use crate::app::Result;
 use actix_web::web::{self, Json};
+use config::Config;
 use error::api_error::RestError;
 use search::SearchExecutor;
 use types::{
@@ -17,20 +18,24 @@ use types::{
 pub type Resp = Response<names::Response>;
 
 /// Do an app name search via API
-pub async fn search(payload: Json<SearchPayload>) -> Result<Json<Resp>> {
+pub async fn search(payload: Json<SearchPayload>, config: web::Data<Config>) -> Result<Json<Resp>> {
+    crate::require(crate::SEARCH_DATA)?;
+
     let query = convert_payload(&payload)
         .parse()
         .ok_or(RestError::BadRequest)?;
 
     let query_c = query.clone();
+    let timeout = config.get_search_timeout(SearchTarget::Names.as_str());
     let result = web::block(move || {
         let search = search::name::Search::new(&query_c);
-        SearchExecutor::new(search).run()
+        SearchExecutor::new(search).with_timeout(timeout).run()
     })
     .await?;
-    let res = names::Response::new(result.items.into_iter().cloned().collect());
+    let res = names::Response::new(result.items.iter().map(|&name| name.clone()).collect());
     let len = result.total as u32;
     let page = new_page(&payload, res, len, payload.settings.page_size);
-    let res = super::new_response(page, SearchTarget::Names, &query);
+    let mut res = super::new_response(page, SearchTarget::Names, &query);
+    res.set_truncated(result.truncated);
     Ok(Json(res))
 }
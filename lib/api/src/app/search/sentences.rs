@@ -3,6 +3,7 @@ use super::new_page;
 use super::convert_payload;
 use crate::app::Result;
 use actix_web::web::{self, Json};
+use config::Config;
 use error::api_error::RestError;
 use types::{
     api::app::search::{
@@ -16,35 +17,41 @@ use types::{
 pub type Resp = Response<sentences::Response>;
 
 /// Do an app sentence search via API
-pub async fn search(payload: Json<SearchPayload>) -> Result<Json<Resp>> {
+pub async fn search(payload: Json<SearchPayload>, config: web::Data<Config>) -> Result<Json<Resp>> {
+    crate::require(crate::SEARCH_DATA)?;
+
     let query = convert_payload(&payload)
         .parse()
         .ok_or(RestError::BadRequest)?;
 
     let query_c = query.clone();
+    let timeout = config.get_search_timeout(SearchTarget::Sentences.as_str());
     let result = web::block(move || {
         let search = search::sentence::Search::new(&query_c);
-        search::SearchExecutor::new(search).run()
+        search::SearchExecutor::new(search)
+            .with_timeout(timeout)
+            .run()
     })
     .await?;
 
     let items = result
         .items
-        .into_iter()
-        .map(|i| convert_sentence(i))
+        .iter()
+        .map(convert_sentence)
         .collect::<Vec<_>>();
 
     let res = sentences::Response::new(items);
     let len = result.total as u32;
 
     let page = new_page(&payload, res, len, payload.settings.page_size);
-    let res = super::new_response(page, SearchTarget::Sentences, &query);
+    let mut res = super::new_response(page, SearchTarget::Sentences, &query);
+    res.set_truncated(result.truncated);
     Ok(Json(res))
 }
 
 #[inline]
 pub(crate) fn convert_sentence(
-    sentence: search::sentence::result::Sentence,
+    sentence: &search::sentence::result::Sentence,
 ) -> sentences::Sentence {
     sentences::Sentence::new(
         sentence.id,
@@ -3,6 +3,7 @@ use super::new_page;
 use super::convert_payload;
 use crate::app::Result;
 use actix_web::web::{self, Json};
+use config::Config;
 use error::api_error::RestError;
 use search::word::Search;
 use search::SearchExecutor;
@@ -21,16 +22,19 @@ use types::{
 pub type Resp = Response<words::Response>;
 
 /// Do an app word search via API
-pub async fn search(payload: Json<SearchPayload>) -> Result<Json<Resp>> {
+pub async fn search(payload: Json<SearchPayload>, config: web::Data<Config>) -> Result<Json<Resp>> {
+    crate::require(crate::SEARCH_DATA)?;
+
     let query = convert_payload(&payload)
         .parse()
         .ok_or(RestError::BadRequest)?;
     let user_lang = query.settings.user_lang;
 
     let query_c = query.clone();
+    let timeout = config.get_search_timeout(SearchTarget::Words.as_str());
     let result = web::block(move || {
         let search = Search::new(&query_c);
-        SearchExecutor::new(search).run()
+        SearchExecutor::new(search).with_timeout(timeout).run()
     })
     .await?;
 
@@ -39,20 +43,25 @@ pub async fn search(payload: Json<SearchPayload>) -> Result<Json<Resp>> {
         .map(|i| i.into())
         .collect::<Vec<_>>();
 
-    let words = result
+    let mut words = result
         .items
         .iter()
         .map(|i| super::super::conv_word(i.clone(), user_lang))
         .collect::<Vec<_>>();
 
+    for (word, explanation) in words.iter_mut().zip(result.explanations.iter()) {
+        word.explanation = Some(explanation.clone());
+    }
+
     let s_index = result.sentence_index();
 
     let sentence = result
         .other_data
         .sentence
-        .and_then(|i| i.parts)
-        .map(|i| conv_sentence(i, s_index));
-    let infl_info = result.other_data.inflection.map(|i| conv_infl_info(i));
+        .as_ref()
+        .and_then(|i| i.parts.as_ref())
+        .map(|i| conv_sentence(i.clone(), s_index));
+    let infl_info = result.other_data.inflection.as_ref().map(conv_infl_info);
 
     let original_query = result.other_data.raw_query.clone();
 
@@ -60,7 +69,8 @@ pub async fn search(payload: Json<SearchPayload>) -> Result<Json<Resp>> {
     let len = result.total as u32;
 
     let page = new_page(&payload, res, len, payload.settings.page_size);
-    let res = super::new_response(page, SearchTarget::Words, &query);
+    let mut res = super::new_response(page, SearchTarget::Words, &query);
+    res.set_truncated(result.truncated);
     Ok(Json(res))
 }
 
@@ -73,6 +83,8 @@ fn conv_sentence(sentence: sentence_reader::Sentence, index: usize) -> Sentence
     Sentence::new(index, parts)
 }
 
-fn conv_infl_info(infl_info: search::word::result::InflectionInformation) -> words::InflectionInfo {
-    words::InflectionInfo::new(infl_info.inflections, infl_info.lexeme)
+fn conv_infl_info(
+    infl_info: &search::word::result::InflectionInformation,
+) -> words::InflectionInfo {
+    words::InflectionInfo::new(infl_info.inflections.clone(), infl_info.lexeme.clone())
 }
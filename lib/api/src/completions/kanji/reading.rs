@@ -9,7 +9,7 @@ use types::{
 
 /// Gets suggestions for kanji reading search eg: "痛 いた.い"
 pub fn suggestions(kanji_reading: kanji::reading::ReadingSearch) -> Option<Response> {
-    let kanji_storage = resources::get().kanji();
+    let kanji_storage = resources::try_get()?.kanji();
 
     let query_reading = kanji_reading
         .reading
@@ -5,6 +5,7 @@ mod request;
 mod words;
 
 use actix_web::web::Json;
+use error::api_error::Origin;
 use japanese::JapaneseExt;
 use search::query::{Form, Query};
 use types::{
@@ -13,12 +14,21 @@ use types::{
 };
 use words::hashtag;
 
+/// Data required to generate suggestions
+const REQUIRED_DATA: &[Origin] = &[
+    Origin::Suggestions,
+    Origin::Resources,
+    Origin::Indexes,
+    Origin::Tokenizer,
+];
+
 pub async fn suggestion_ep(payload: Json<Request>) -> Result<Json<Response>, actix_web::Error> {
     Ok(Json(suggestion_ep_inner(payload.into_inner())?))
 }
 
 /// Get search suggestions endpoint
 pub(crate) fn suggestion_ep_inner(payload: Request) -> Result<Response, actix_web::Error> {
+    crate::require(REQUIRED_DATA)?;
     request::validate(&payload)?;
 
     if payload.hashtag {
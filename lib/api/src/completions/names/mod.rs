@@ -18,7 +18,8 @@ pub(crate) fn suggestions(query: Query) -> Option<Response> {
 /// Returns trascripted name suggestions
 pub fn transcription_suggestions(query: &Query) -> Option<Response> {
     let query_str = &query.query_str;
-    let index = indexes::get_suggestions().names_foreign();
+    let storage = indexes::try_get_suggestions()?;
+    let index = storage.names_foreign();
 
     let mut task = SuggestionTask::new(30);
 
@@ -29,7 +30,7 @@ pub fn transcription_suggestions(query: &Query) -> Option<Response> {
     task.add_query(def_query);
 
     if let Some(romaji_query) = super::words::foreign::try_romaji(query_str) {
-        let jp_index = indexes::get_suggestions().names_native();
+        let jp_index = storage.names_native();
         task.add_query(SuggestionQuery::new(jp_index, romaji_query.clone()));
 
         let katakana = to_katakana(romaji_query.as_str());
@@ -46,7 +47,7 @@ pub fn transcription_suggestions(query: &Query) -> Option<Response> {
 pub fn native_suggestions(query: &Query) -> Option<Response> {
     let query_str = &query.query_str;
 
-    let index = indexes::get_suggestions().names_native();
+    let index = indexes::try_get_suggestions()?.names_native();
     let mut task = SuggestionTask::new(30);
 
     let mut def_query = SuggestionQuery::new(index, query_str);
@@ -8,13 +8,16 @@ use autocompletion::suggest::{
 };
 use japanese::{
     guessing::{could_be_romaji, is_romaji_repl},
-    to_hira_fmt,
+    romaji::{self, KanaVariant},
 };
 use types::jotoba::languages::Language;
 use utils::real_string_len;
 
 use super::super::*;
 
+/// Max amount of kana variants of a romaji query to search suggestions for
+const MAX_ROMAJI_VARIANTS: usize = 3;
+
 /// Returns suggestions based on non japanese input
 pub fn suggestions(query: &Query, query_str: &str) -> Option<Vec<WordPair>> {
     let query_lower = autocompletion::index::basic::basic_format(query_str.trim());
@@ -35,11 +38,14 @@ pub fn suggestions(query: &Query, query_str: &str) -> Option<Vec<WordPair>> {
 
     // Romaji result
     //if let Some(hira_query) = try_romaji(query_str.trim()) {
-    let hira_query =
-        try_romaji(query_str.trim()).unwrap_or_else(|| japanese::to_hira_fmt(query_str));
+    let romaji_variants = try_romaji_variants(query_str.trim()).unwrap_or_default();
+    let hira_query = romaji_variants
+        .first()
+        .map(|i| i.kana.clone())
+        .unwrap_or_else(|| japanese::to_hira_fmt(query_str));
     //let hira_query = query_str.to_hiragana();
     println!("hira query: {hira_query}");
-    let jp_engine = indexes::get_suggestions().jp_words();
+    let jp_engine = indexes::try_get_suggestions()?.jp_words();
     let mut rom_query = SuggestionQuery::new(jp_engine, hira_query.clone());
     if could_be_romaji(query_str) {
         rom_query.weights.total_weight = 0.99;
@@ -84,6 +90,13 @@ pub fn suggestions(query: &Query, query_str: &str) -> Option<Vec<WordPair>> {
         rel
     });
 
+    // Variants with lengthened vowels (tokyo -> とうきょう), ranked below the exact one
+    for variant in romaji_variants.iter().skip(1) {
+        let mut var_query = SuggestionQuery::new(jp_engine, variant.kana.clone());
+        var_query.weights.total_weight = rom_query.weights.total_weight * variant.weight();
+        task.add_query(var_query);
+    }
+
     task.add_query(rom_query);
     //}
 
@@ -91,7 +104,7 @@ pub fn suggestions(query: &Query, query_str: &str) -> Option<Vec<WordPair>> {
 }
 
 fn new_suggestion_query(query: &str, lang: Language) -> Option<SuggestionQuery> {
-    let engine = indexes::get_suggestions().foreign_words(lang)?;
+    let engine = indexes::try_get_suggestions()?.foreign_words(lang)?;
 
     let mut suggestion_query = SuggestionQuery::new(engine, &query);
     suggestion_query.weights.str_weight = 1.5;
@@ -111,6 +124,13 @@ fn new_suggestion_query(query: &str, lang: Language) -> Option<SuggestionQuery>
 
 /// Returns Some(String) if `query_str` could be (part of) romaji search input and None if not
 pub(crate) fn try_romaji(query_str: &str) -> Option<String> {
+    let variants = try_romaji_variants(query_str)?;
+    variants.into_iter().next().map(|i| i.kana)
+}
+
+/// Returns the kana variants of `query_str` if it could be (part of) romaji search input, the
+/// exact-length one coming first
+pub(crate) fn try_romaji_variants(query_str: &str) -> Option<Vec<KanaVariant>> {
     let mut query_str = query_str.replace("-", "ー");
     if query_str.ends_with("m") {
         query_str.pop();
@@ -123,7 +143,7 @@ pub(crate) fn try_romaji(query_str: &str) -> Option<String> {
     }
 
     if let Some(v) = is_romaji_repl(query_str) {
-        return Some(to_hira_fmt(&v));
+        return Some(kana_variants(&v));
     }
 
     if str_len < 3 {
@@ -138,7 +158,7 @@ pub(crate) fn try_romaji(query_str: &str) -> Option<String> {
     if str_len > min_len {
         let prefix = strip_str_end(query_str, 1);
         if let Some(v) = is_romaji_repl(prefix) {
-            return Some(to_hira_fmt(&v));
+            return Some(kana_variants(&v));
         }
     }
 
@@ -147,13 +167,21 @@ pub(crate) fn try_romaji(query_str: &str) -> Option<String> {
     if str_len >= min_len + 2 && end_three_char_kana(query_str) {
         let prefix = strip_str_end(query_str, 2);
         if let Some(v) = is_romaji_repl(prefix) {
-            return Some(to_hira_fmt(&v));
+            return Some(kana_variants(&v));
         }
     }
 
     None
 }
 
+/// Returns the first [`MAX_ROMAJI_VARIANTS`] kana variants of `romaji`
+#[inline]
+fn kana_variants(romaji: &str) -> Vec<KanaVariant> {
+    let mut variants = romaji::kana_variants(romaji);
+    variants.truncate(MAX_ROMAJI_VARIANTS);
+    variants
+}
+
 /// Returns a substring of `inp` with `len` amount of tailing characters being removed.
 /// This works for non UTF-8 as well. If len > |inp| "" gets returned
 #[inline]
@@ -1,28 +1,44 @@
 use index_framework::traits::{backend::Backend, storage::IndexStorage};
+use itertools::Itertools;
 use std::ops::Deref;
 use types::{api::completions::WordPair, jotoba::search::SearchTarget};
 
+/// Max amount of tags found by their name or alias to add to the suggestions
+const MAX_TRIE_TAGS: usize = 5;
+
 pub fn suggestions(query: &str, search_target: SearchTarget) -> Option<Vec<WordPair>> {
     if query.trim().is_empty() {
-        return Some(empty(search_target));
+        return empty(search_target);
     }
 
-    let index = indexes::get_suggestions().hashtags();
+    let index = indexes::try_get_suggestions()?.hashtags();
     let res = index.ngram_search(query, &[search_target]);
-    let max = res.first()?.1;
+    let max = res.first().map(|i| i.1).unwrap_or_default();
 
-    let out: Vec<_> = res
+    let mut out: Vec<_> = res
         .into_iter()
         .filter(|i| i.1 >= max - 0.4)
         .map(|i| WordPair::new(i.0.tag.clone()))
         .collect();
 
-    Some(out)
+    // Tags like fields or dialects are only in the trie and can be found by their localized names too
+    let trie_query = query.trim().trim_start_matches('#');
+    let trie_tags = index
+        .trie_search(trie_query, &[search_target])
+        .into_iter()
+        .filter(|i| !out.iter().any(|j| j.primary.eq_ignore_ascii_case(&i.tag)))
+        .map(|i| i.tag.clone())
+        .unique()
+        .take(MAX_TRIE_TAGS)
+        .collect::<Vec<_>>();
+    out.extend(trie_tags.into_iter().map(WordPair::new));
+
+    (!out.is_empty()).then(|| out)
 }
 
-fn empty(search_target: SearchTarget) -> Vec<WordPair> {
+fn empty(search_target: SearchTarget) -> Option<Vec<WordPair>> {
     let start = std::time::Instant::now();
-    let index = &indexes::get_suggestions().hashtags();
+    let index = &indexes::try_get_suggestions()?.hashtags();
     let ngindex = index.index.deref();
 
     let mut out: Vec<_> = ngindex
@@ -40,5 +56,5 @@ fn empty(search_target: SearchTarget) -> Vec<WordPair> {
         .map(|i| WordPair::new(i.tag.clone()))
         .collect();
     println!("took: {:?}", start.elapsed());
-    res
+    Some(res)
 }
@@ -10,13 +10,14 @@ use autocompletion::{
         task::SuggestionTask,
     },
 };
+use resources::retrieve::kanji::KanjiRetrieve;
 use wana_kana::ConvertJapanese;
 
 const MAX_SENTENCE_LEN: usize = 15;
 
 /// Get suggestions for foreign search input
 pub fn suggestions(query: &Query, _romaji_query: &str, radicals: &[char]) -> Option<Vec<WordPair>> {
-    let jp_engine = indexes::get_suggestions().jp_words();
+    let jp_engine = indexes::try_get_suggestions()?.jp_words();
     let query_str = query.query_str.as_str();
 
     let mut suggestion_task = SuggestionTask::new(30);
@@ -92,7 +93,9 @@ pub fn suggestions(query: &Query, _romaji_query: &str, radicals: &[char]) -> Opt
     }
 
     // radical filter
-    let word_res = resources::get().words();
+    let storage = resources::try_get()?;
+    let word_res = storage.words();
+    let kanji_res = storage.kanji();
     suggestion_task.set_filter(move |item| {
         if radicals.is_empty() {
             return true;
@@ -102,7 +105,7 @@ pub fn suggestions(query: &Query, _romaji_query: &str, radicals: &[char]) -> Opt
             Some(word) => word,
             None => return true,
         };
-        word_rad_filter(query_str, word, radicals)
+        word_rad_filter(&kanji_res, query_str, word, radicals)
     });
 
     Some(convert_results(suggestion_task.search()))
@@ -132,14 +135,17 @@ pub(crate) fn normalize_inflections(query_str: &str) -> (Option<String>, Vec<Str
     (None, vec![])
 }
 
-fn word_rad_filter(query: &str, word: &types::jotoba::words::Word, radicals: &[char]) -> bool {
+fn word_rad_filter(
+    retrieve: &KanjiRetrieve,
+    query: &str,
+    word: &types::jotoba::words::Word,
+    radicals: &[char],
+) -> bool {
     let kanji = match word.reading.kanji.as_ref() {
         Some(k) => &k.reading,
         None => return false,
     };
 
-    let retrieve = resources::get().kanji();
-
     let query_kanji = query.chars().filter(|i| i.is_kanji()).collect::<Vec<_>>();
 
     kanji
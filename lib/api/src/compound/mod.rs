@@ -0,0 +1,99 @@
+use actix_web::web::{self, Json};
+use error::api_error::RestError;
+use search::word::compound::{self, Compounds, Options, Position, MAX_SPLIT_LEN};
+use types::{
+    api::compound::{
+        self as api_compound, CompoundWord, Group, Request, Response, Segment, SplitRequest,
+        SplitResponse,
+    },
+    jotoba::words::Word,
+};
+
+/// Max amount of characters a term to find compounds for is allowed to have
+pub const MAX_TERM_LEN: usize = 16;
+
+/// Max amount of compounds per position which can be requested at once
+pub const MAX_PAGE_SIZE: usize = 100;
+
+/// Returns all compounds containing a kanji or word, grouped by the position of the term
+pub async fn compounds(payload: Json<Request>) -> Result<Json<Response>, RestError> {
+    crate::require(crate::SEARCH_DATA)?;
+
+    let term = payload.term.trim();
+    if term.is_empty() || utils::real_string_len(term) > MAX_TERM_LEN {
+        return Err(RestError::BadRequest);
+    }
+
+    let page_size = payload.page_size.clamp(1, MAX_PAGE_SIZE);
+    let position = payload.position.map(conv_position);
+    let options = Options {
+        common_only: payload.common_only,
+        position,
+        offset: search::query::parser::calc_page_offset(payload.page, page_size),
+        limit: page_size,
+    };
+
+    let term = term.to_string();
+    let compounds = web::block(move || compound::find(&term, &options)).await?;
+
+    Ok(Json(conv_compounds(&compounds, position)))
+}
+
+/// Splits a compound into the dictionary words it consists of
+pub async fn split(payload: Json<SplitRequest>) -> Result<Json<SplitResponse>, RestError> {
+    crate::require(crate::SEARCH_DATA)?;
+
+    let text = payload.compound.trim();
+    if text.is_empty() || utils::real_string_len(text) > MAX_SPLIT_LEN {
+        return Err(RestError::BadRequest);
+    }
+
+    let text = text.to_string();
+    let segments = web::block(move || compound::split(&text))
+        .await?
+        .ok_or(RestError::NotFound)?;
+
+    let segments = segments
+        .into_iter()
+        .map(|i| Segment::new(i.text, i.word.map(conv_word)))
+        .collect();
+
+    Ok(Json(SplitResponse::new(segments)))
+}
+
+fn conv_compounds(compounds: &Compounds, filter: Option<Position>) -> Response {
+    let group = |pos: Position| {
+        if filter.map(|f| f != pos).unwrap_or(false) {
+            return None;
+        }
+        let group = compounds.get(pos);
+        let words = group.words.iter().map(|i| conv_word(i)).collect();
+        Some(Group::new(group.total, words))
+    };
+
+    Response::new(
+        group(Position::Prefix),
+        group(Position::Infix),
+        group(Position::Suffix),
+    )
+}
+
+#[inline]
+fn conv_position(position: api_compound::Position) -> Position {
+    match position {
+        api_compound::Position::Prefix => Position::Prefix,
+        api_compound::Position::Infix => Position::Infix,
+        api_compound::Position::Suffix => Position::Suffix,
+    }
+}
+
+#[inline]
+fn conv_word(word: &Word) -> CompoundWord {
+    CompoundWord::new(
+        word.sequence,
+        word.get_reading().reading.clone(),
+        word.get_kana().to_string(),
+        word.is_common(),
+        word.get_jlpt_lvl(),
+    )
+}
@@ -0,0 +1,96 @@
+use actix_web::web::{self, Json};
+use error::api_error::RestError;
+use japanese::{
+    furigana::{self, format::Format as FuriFormat, SentencePart, SentencePartRef},
+    JapaneseExt,
+};
+use resources::retrieve::kanji::KanjiRetrieve;
+use types::api::furigana::{Format, Request, Response, Segment};
+
+/// Annotates a text with furigana in the requested format
+pub async fn furigana(payload: Json<Request>) -> Result<Json<Response>, RestError> {
+    let text = crate::check_text(&payload.text)?;
+
+    crate::require(crate::SEARCH_DATA)?;
+    let retrieve = crate::resources()?.kanji();
+
+    let text = text.to_string();
+    let parts = web::block(move || search::reader::furigana::annotate(&text)).await?;
+
+    let parts = parts
+        .iter()
+        .map(|i| strip_known(&retrieve, i, payload.known_jlpt, payload.known_grade));
+
+    let res = match text_format(payload.format) {
+        Some(format) => Response::with_text(furigana::format::encode(parts, format)),
+        None => Response::with_segments(parts.map(conv_segment).collect()),
+    };
+
+    Ok(Json(res))
+}
+
+/// Removes the reading of `part` if all of its kanji are known by the user
+fn strip_known<'a>(
+    retrieve: &KanjiRetrieve,
+    part: &'a SentencePart,
+    jlpt: Option<u8>,
+    grade: Option<u8>,
+) -> SentencePartRef<'a> {
+    let part = part.as_ref();
+    let kanji = match part.kanji {
+        Some(k) => k,
+        None => return part,
+    };
+
+    if (jlpt.is_some() || grade.is_some())
+        && kanji.chars().all(|c| is_known(retrieve, c, jlpt, grade))
+    {
+        return SentencePartRef::new(kanji);
+    }
+
+    part
+}
+
+/// Returns `true` if `lit` is a kanji of the given JLPT level (or easier) or school grade (or lower).
+/// Non kanji characters are always known
+fn is_known(retrieve: &KanjiRetrieve, lit: char, jlpt: Option<u8>, grade: Option<u8>) -> bool {
+    if !lit.is_kanji() {
+        return true;
+    }
+
+    let kanji = match retrieve.by_literal(lit) {
+        Some(k) => k,
+        None => return false,
+    };
+
+    let known_jlpt = jlpt
+        .zip(kanji.jlpt)
+        // Lower JLPT levels are harder
+        .map(|(known, lvl)| lvl >= known)
+        .unwrap_or(false);
+
+    let known_grade = grade
+        .zip(kanji.grade)
+        .map(|(known, g)| g <= known)
+        .unwrap_or(false);
+
+    known_jlpt || known_grade
+}
+
+/// Returns the furigana text format for the requested format or `None` if segments are requested
+fn text_format(format: Format) -> Option<FuriFormat> {
+    Some(match format {
+        Format::Jotoba => FuriFormat::Jotoba,
+        Format::Html => FuriFormat::Html,
+        Format::Anki => FuriFormat::Anki,
+        Format::Aozora => FuriFormat::Aozora,
+        Format::Json => return None,
+    })
+}
+
+fn conv_segment(part: SentencePartRef) -> Segment {
+    match part.kanji {
+        Some(kanji) => Segment::new(kanji.to_string(), Some(part.kana.to_string())),
+        None => Segment::new(part.kana.to_string(), None),
+    }
+}
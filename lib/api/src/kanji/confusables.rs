@@ -0,0 +1,74 @@
+use actix_web::web::Json;
+use error::api_error::RestError;
+use itertools::Itertools;
+use japanese::JapaneseExt;
+use resources::retrieve::kanji::KanjiRetrieve;
+use types::{
+    api::kanji::confusables::{ConfusableSet, Entry, Request, Response},
+    jotoba::kanji::Kanji,
+};
+
+/// Max amount of sets which can be requested at once
+pub const MAX_LIMIT: usize = 100;
+
+/// Returns sets of kanji which are likely to be confused with each other
+pub async fn confusables(payload: Json<Request>) -> Result<Json<Response>, RestError> {
+    let retrieve = crate::resources()?.kanji();
+    let limit = payload.limit.min(MAX_LIMIT);
+
+    let kanji: Vec<&Kanji> = if let Some(ref kanji) = payload.kanji {
+        kanji
+            .chars()
+            .filter(|i| i.is_kanji())
+            .unique()
+            .filter_map(|i| retrieve.by_literal(i))
+            .collect()
+    } else if let Some(jlpt) = payload.jlpt {
+        retrieve
+            .by_jlpt(jlpt)
+            .into_iter()
+            .flatten()
+            .filter_map(|i| retrieve.by_literal(*i))
+            .sorted_by_key(|i| i.frequency.unwrap_or(u16::MAX))
+            .collect()
+    } else {
+        return Err(RestError::BadRequest);
+    };
+
+    let sets = kanji
+        .into_iter()
+        .filter_map(|i| confusable_set(&retrieve, i))
+        .take(limit)
+        .collect();
+
+    Ok(Json(Response::new(sets)))
+}
+
+/// Returns the confusable set of `kanji`. `None` if there are no confusable kanji
+fn confusable_set(retrieve: &KanjiRetrieve, kanji: &Kanji) -> Option<ConfusableSet> {
+    let confusables: Vec<_> = retrieve
+        .confusables(kanji.literal)
+        .filter_map(|(literal, score)| {
+            Some(conv_entry(retrieve.by_literal(*literal)?, Some(*score)))
+        })
+        .collect();
+
+    if confusables.is_empty() {
+        return None;
+    }
+
+    Some(ConfusableSet::new(conv_entry(kanji, None), confusables))
+}
+
+#[inline]
+fn conv_entry(kanji: &Kanji, similarity: Option<f32>) -> Entry {
+    Entry::new(
+        kanji.literal,
+        kanji.stroke_count,
+        kanji.jlpt,
+        kanji.meanings.clone(),
+        kanji.onyomi.clone(),
+        kanji.kunyomi.clone(),
+        similarity,
+    )
+}
@@ -25,7 +25,7 @@ impl KanjiTreeBuilder {
 
     /// Recursive method to build the OutObjects
     pub fn build(&self, c: char) -> Option<OutObject> {
-        let retrieve = resources::get().kanji();
+        let retrieve = resources::try_get()?.kanji();
         let ids_kanji = retrieve.ids(c)?;
 
         let mut out = OutObject::new(c);
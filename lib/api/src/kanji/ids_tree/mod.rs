@@ -2,11 +2,13 @@ pub mod builder;
 
 use crate::kanji::ids_tree::builder::KanjiTreeBuilder;
 use actix_web::web::Json;
-use error::api_error::RestError;
+use error::api_error::{Origin, RestError};
 use types::api::kanji::ids_tree::{Request, Response};
 
 /// Get a decomposition graph
 pub async fn decomp_graph(payload: Json<Request>) -> Result<Json<Response>, RestError> {
+    crate::require(&[Origin::Resources])?;
+
     let tree = KanjiTreeBuilder::new(payload.full)
         .build(payload.literal)
         .ok_or(RestError::NotFound)?;
@@ -1 +1,3 @@
+pub mod confusables;
 pub mod ids_tree;
+pub mod phonetic;
@@ -0,0 +1,54 @@
+use actix_web::web::Json;
+use error::api_error::RestError;
+use resources::retrieve::kanji::KanjiRetrieve;
+use types::{
+    api::kanji::phonetic::{Member, Request, Response, Series},
+    jotoba::kanji::phonetic::PhoneticSeries,
+};
+
+/// Returns the phonetic series of a component along with all series a kanji is a member of
+pub async fn phonetic_series(payload: Json<Request>) -> Result<Json<Response>, RestError> {
+    let retrieve = crate::resources()?.kanji();
+
+    let literal = payload.literal;
+
+    // The own series of a component comes first
+    let member_of = retrieve
+        .phonetic_series_of(literal)
+        .filter(|i| i.component != literal);
+    let series: Vec<_> = retrieve
+        .phonetic_series(literal)
+        .into_iter()
+        .chain(member_of)
+        .map(|i| conv_series(&retrieve, i))
+        .collect();
+
+    if series.is_empty() {
+        return Err(RestError::NotFound);
+    }
+
+    Ok(Json(Response::new(series)))
+}
+
+fn conv_series(retrieve: &KanjiRetrieve, series: &PhoneticSeries) -> Series {
+    let members = series
+        .members
+        .iter()
+        .map(|literal| {
+            let onyomi = retrieve
+                .by_literal(*literal)
+                .map(|i| i.onyomi.clone())
+                .unwrap_or_default();
+            let regular = onyomi.contains(&series.reading);
+            Member::new(*literal, onyomi, regular)
+        })
+        .collect();
+
+    Series::new(
+        series.component,
+        series.reading.clone(),
+        series.regular,
+        series.regularity(),
+        members,
+    )
+}
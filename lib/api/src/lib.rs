@@ -1,7 +1,82 @@
 pub mod app;
 pub mod completions;
+pub mod compound;
+pub mod furigana;
 pub mod img;
 pub mod kanji;
 pub mod news;
 pub mod radical;
+pub mod reader;
+pub mod romaji;
 pub mod search;
+
+use actix_web::HttpRequest;
+use error::api_error::{Origin, RestError};
+use indexes::storage::suggestions;
+use resources::ResourceStorage;
+
+/// Max amount of characters a text passed to the text analyzing endpoints is allowed to have
+pub const MAX_TEXT_LEN: usize = 5000;
+
+/// Data required by searches and everything analyzing japanese text
+pub(crate) const SEARCH_DATA: &[Origin] = &[Origin::Resources, Origin::Indexes, Origin::Tokenizer];
+
+/// Rate limiter charging clients for requests whose cost depends on their content. Is part of the
+/// app data if the server limits requests
+pub trait RateLimit: Send + Sync {
+    /// Charges the client of `req` with the total `cost` of the request. Returns
+    /// `RestError::TooManyRequests` if its budget is exceeded
+    fn charge(&self, req: &HttpRequest, cost: u32) -> Result<(), RestError>;
+}
+
+/// Returns a `RestError::Loading` for the first of `data` which isn't loaded yet. Has to be called
+/// by all endpoints accessing data as the server accepts requests while the data is still loading
+pub(crate) fn require(data: &[Origin]) -> Result<(), RestError> {
+    match data.iter().find(|i| !is_loaded(**i)) {
+        Some(origin) => Err(RestError::Loading(*origin)),
+        None => Ok(()),
+    }
+}
+
+/// Returns the resource storage or `RestError::Loading` if it isn't loaded yet
+#[inline]
+pub(crate) fn resources() -> Result<&'static ResourceStorage, RestError> {
+    resources::try_get().ok_or(RestError::Loading(Origin::Resources))
+}
+
+fn is_loaded(origin: Origin) -> bool {
+    match origin {
+        Origin::Resources | Origin::Radicals => resources::is_loaded(),
+        Origin::Indexes => indexes::storage::is_loaded(),
+        Origin::Tokenizer => sentence_reader::is_loaded(),
+        Origin::Suggestions => suggestions::is_loaded(),
+        Origin::File => true,
+    }
+}
+
+/// Returns the trimmed `text` or `RestError::BadRequest` if it is empty or longer than
+/// [`MAX_TEXT_LEN`]
+pub(crate) fn check_text(text: &str) -> Result<&str, RestError> {
+    let text = text.trim();
+    if text.is_empty() || utils::real_string_len(text) > MAX_TEXT_LEN {
+        return Err(RestError::BadRequest);
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_check_text() {
+        assert_eq!(check_text("  見る "), Ok("見る"));
+        assert_eq!(check_text(""), Err(RestError::BadRequest));
+        assert_eq!(check_text(" \n "), Err(RestError::BadRequest));
+
+        let max = "あ".repeat(MAX_TEXT_LEN);
+        assert_eq!(check_text(&max), Ok(max.as_str()));
+        let too_long = "あ".repeat(MAX_TEXT_LEN + 1);
+        assert_eq!(check_text(&too_long), Err(RestError::BadRequest));
+    }
+}
@@ -1,22 +1,27 @@
 use actix_web::web::Json;
+use error::api_error::{Origin, RestError};
 use intmap::{int_set::IntSet, IntMap};
+use resources::retrieve::kanji::KanjiRetrieve;
 use std::{collections::HashMap, time::Instant};
 use types::api::radical::find_kanji::{Request, Response};
 
 /// Get kanji by its radicals
 pub async fn kanji_by_radicals(payload: Json<Request>) -> Result<Json<Response>, actix_web::Error> {
+    let retrieve = resources::try_get()
+        .ok_or(RestError::Loading(Origin::Radicals))?
+        .kanji();
+
     let start = Instant::now();
-    let res = find_kanji(&payload.radicals);
+    let res = find_kanji(&retrieve, &payload.radicals);
     log::debug!("Radical results took: {:?}", start.elapsed());
 
     Ok(Json(res))
 }
 
-pub fn find_kanji(rads: &[char]) -> Response {
+pub fn find_kanji(k_retrieve: &KanjiRetrieve, rads: &[char]) -> Response {
     let mut possible_rads_set = IntSet::with_capacity(rads.len() * 3);
     let mut kanji_res: IntMap<Vec<char>> = IntMap::with_capacity(8);
 
-    let k_retrieve = resources::get().kanji();
     for kanji in k_retrieve.by_radicals(rads) {
         push_or_insert(&mut kanji_res, kanji.stroke_count as u32, kanji.literal);
 
@@ -1,6 +1,7 @@
 use engine::task::SearchTask;
 use itertools::Itertools;
 use japanese::JapaneseExt;
+use resources::retrieve::kanji::KanjiRetrieve;
 use search::{engine::words::native::Engine, word::order::native::NativeOrder};
 use std::collections::{HashMap, HashSet};
 use types::{api::radical::search::KanjiRads, jotoba::kanji::Kanji};
@@ -14,12 +15,12 @@ pub fn search(query: &str) -> Vec<char> {
     kana_search(query)
 }
 
-/// Returns a List of kanji that use similar radicals as the query.
-pub fn similar_kanji_search(query: &str) -> Vec<KanjiRads> {
+/// Returns a List of kanji that look similar or use similar radicals as the query.
+pub fn similar_kanji_search(retrieve: &KanjiRetrieve, query: &str) -> Vec<KanjiRads> {
     let kanji = query
         .chars()
         .filter(|i| i.is_kanji())
-        .filter_map(|lit| get_kanji(lit));
+        .filter_map(|lit| retrieve.by_literal(lit));
 
     let mut dups: HashSet<char> = HashSet::new();
     let mut out: Vec<KanjiRads> = Vec::new();
@@ -29,8 +30,19 @@ pub fn similar_kanji_search(query: &str) -> Vec<KanjiRads> {
         out.push(into_kanji_rads(kanji));
         dups.insert(kanji.literal);
 
+        // Visually similar kanji come first
+        for (literal, _) in retrieve.visually_similar(kanji.literal) {
+            if dups.contains(literal) {
+                continue;
+            }
+            if let Some(k) = retrieve.by_literal(*literal) {
+                dups.insert(k.literal);
+                out.push(into_kanji_rads(k));
+            }
+        }
+
         for part in kanji.parts.iter() {
-            let mut kanji_w_r = resources::get().kanji().by_radicals(&[*part]);
+            let mut kanji_w_r = retrieve.by_radicals(&[*part]);
             kanji_w_r.sort_by(|a, b| a.stroke_count.cmp(&b.stroke_count));
             for k in kanji_w_r.into_iter().take(10) {
                 if k.stroke_count < kanji.stroke_count || dups.contains(&k.literal) {
@@ -32,7 +32,7 @@ fn word_search(query: &str, language: Language) -> Vec<char> {
         SearchTask::with_language(&query, language).limit(3);
 
     let order = ForeignOrder::new();
-    search_task.with_custom_order(move |item| order.score(item, language));
+    search_task.with_explained_order(move |item, explain| order.score(item, language, explain));
 
     let kanji_retr = resources::get().kanji();
     search_task
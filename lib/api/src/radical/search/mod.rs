@@ -19,6 +19,7 @@ pub async fn search_radical(
     mut payload: Json<Request>,
     request: HttpRequest,
 ) -> Result<Json<Response>, actix_web::Error> {
+    crate::require(crate::SEARCH_DATA)?;
     verify_payload(&mut payload)?;
 
     let rad_res;
@@ -26,7 +27,8 @@ pub async fn search_radical(
 
     if payload.query.is_japanese() {
         rad_res = jp_search::search(&payload.query);
-        kanji_res = jp_search::similar_kanji_search(&payload.query);
+        let retrieve = crate::resources()?.kanji();
+        kanji_res = jp_search::similar_kanji_search(&retrieve, &payload.query);
     } else {
         rad_res = meaning::search(&payload.query, user_lang(&request));
     }
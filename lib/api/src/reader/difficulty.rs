@@ -0,0 +1,73 @@
+use actix_web::web::{self, Json};
+use error::api_error::RestError;
+use search::reader::difficulty::Report;
+use types::{
+    api::reader::difficulty::{JlptShare, Kanji, Request, Response, Vocabulary},
+    jotoba::words::Word,
+};
+
+/// Max amount of words to pre-learn which can be requested
+pub const MAX_TOP_N: usize = 100;
+
+/// Analyzes the difficulty of a text
+pub async fn difficulty(payload: Json<Request>) -> Result<Json<Response>, RestError> {
+    let text = crate::check_text(&payload.text)?;
+
+    crate::require(crate::SEARCH_DATA)?;
+
+    let text = text.to_string();
+    let top_n = payload.top_n.min(MAX_TOP_N);
+    let report = web::block(move || search::reader::difficulty::analyze(&text, top_n)).await?;
+
+    Ok(Json(conv_report(&report)))
+}
+
+/// Converts a difficulty report into its API representation
+pub fn conv_report(report: &Report) -> Response {
+    let jlpt_levels = (0..=5)
+        .rev()
+        .map(|lvl| {
+            let level = (lvl > 0).then(|| lvl);
+            JlptShare::new(
+                level,
+                report.jlpt_tokens[lvl as usize],
+                report.jlpt_share(lvl),
+            )
+        })
+        .collect();
+
+    let kanji = report
+        .kanji
+        .iter()
+        .map(|(k, count)| Kanji::new(k.literal, k.grade, k.jlpt, *count))
+        .collect();
+
+    let rare = report.rare.iter().map(|(w, c)| conv_word(w, *c)).collect();
+
+    let vocabulary = report
+        .vocabulary
+        .iter()
+        .map(|(w, c)| conv_word(w, *c))
+        .collect();
+
+    Response::new(
+        report.estimated_jlpt,
+        report.token_count,
+        jlpt_levels,
+        kanji,
+        report.unknown.clone(),
+        rare,
+        vocabulary,
+    )
+}
+
+#[inline]
+fn conv_word(word: &Word, count: usize) -> Vocabulary {
+    Vocabulary::new(
+        word.sequence,
+        word.get_reading().reading.clone(),
+        word.get_kana().to_string(),
+        word.get_jlpt_lvl(),
+        count,
+    )
+}
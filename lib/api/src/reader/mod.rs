@@ -0,0 +1,88 @@
+pub mod difficulty;
+
+use actix_web::web::{self, Json};
+use error::api_error::RestError;
+use search::reader::Token;
+use types::api::reader::{self, Request, Response};
+
+/// Splits a text into sentences and returns all tokens along with their readings and words
+pub async fn reader(payload: Json<Request>) -> Result<Json<Response>, RestError> {
+    let text = crate::check_text(&payload.text)?;
+
+    crate::require(crate::SEARCH_DATA)?;
+
+    let text = text.to_string();
+    let sentences = web::block(move || search::reader::read_text(&text)).await?;
+    Ok(Json(conv_sentences(sentences)))
+}
+
+/// Converts the tokens of all read sentences into the API response
+pub fn conv_sentences(sentences: Vec<Vec<Token>>) -> Response {
+    let sentences = sentences
+        .into_iter()
+        .map(|tokens| reader::Sentence::new(tokens.into_iter().map(conv_token).collect()))
+        .collect();
+
+    Response::new(sentences)
+}
+
+fn conv_token(token: Token) -> reader::Token {
+    let jlpt_lvl = token.jlpt_lvl();
+    let sequences = token.words.iter().map(|i| i.sequence).collect();
+    let part = token.part;
+
+    reader::Token::new(
+        part.get_inflected(),
+        part.get_normalized(),
+        part.furigana().map(|i| i.to_string()),
+        part.word_class(),
+        sequences,
+        part.inflections().to_vec(),
+        jlpt_lvl,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::Value;
+
+    fn read(text: &str) -> Value {
+        if !sentence_reader::is_loaded() {
+            sentence_reader::load_parser("../../resources/unidic-mecab");
+        }
+        // Same as `search::reader::read_text` but without looking up words, which would
+        // require the resources and indexes
+        let sentences = sentence_reader::split_sentences(text)
+            .map(|sentence| {
+                sentence_reader::Parser::new(sentence)
+                    .parts()
+                    .into_iter()
+                    .map(|part| Token {
+                        part,
+                        words: vec![],
+                    })
+                    .collect()
+            })
+            .collect();
+        serde_json::to_value(conv_sentences(sentences)).unwrap()
+    }
+
+    #[test]
+    fn test_conv_sentences() {
+        let res = read("見たくない。美味しい");
+        let sentences = res["sentences"].as_array().unwrap();
+        assert_eq!(sentences.len(), 2);
+
+        let token = &sentences[0]["tokens"][0];
+        assert_eq!(token["inflected"], "見たくない");
+        assert_eq!(token["normalized"], "見る");
+        assert_eq!(token["word_class"], "Verb");
+        assert!(!token["inflections"].as_array().unwrap().is_empty());
+        // No matching words were passed
+        assert!(token.get("sequences").is_none());
+        assert!(token.get("jlpt_lvl").is_none());
+
+        assert_eq!(sentences[1]["tokens"][0]["inflected"], "美味しい");
+    }
+}
@@ -0,0 +1,51 @@
+use actix_web::web::{self, Json};
+use error::api_error::RestError;
+use japanese::romaji::{self, Options};
+use types::{
+    api::romaji::{LongVowels, Request, Response, System},
+    jotoba::words::{part_of_speech::PosSimple, Word},
+};
+
+/// Romanizes a japanese text
+pub async fn romaji(payload: Json<Request>) -> Result<Json<Response>, RestError> {
+    let text = crate::check_text(&payload.text)?;
+
+    crate::require(crate::SEARCH_DATA)?;
+
+    let options = conv_options(payload.system, payload.long_vowels);
+    let text = text.to_string();
+    let romaji = web::block(move || search::reader::romaji::romanize_text(&text, options)).await?;
+
+    Ok(Json(Response::new(romaji)))
+}
+
+/// Romanizes a kana only text using the default options
+#[inline]
+pub fn romanize_kana(kana: &str) -> String {
+    romaji::romanize(kana, Options::default())
+}
+
+/// Romanizes the kana reading of `word` using the default options
+pub fn romanize_word(word: &Word) -> String {
+    let kana = word.get_kana();
+    if word.has_pos(&[PosSimple::Verb]) {
+        return romaji::romanize_verb(kana, Options::default());
+    }
+    romanize_kana(kana)
+}
+
+fn conv_options(system: System, long_vowels: LongVowels) -> Options {
+    let system = match system {
+        System::Hepburn => romaji::System::Hepburn,
+        System::Kunrei => romaji::System::Kunrei,
+        System::Nihon => romaji::System::Nihon,
+    };
+
+    let long_vowels = match long_vowels {
+        LongVowels::Macron => romaji::LongVowels::Macron,
+        LongVowels::Circumflex => romaji::LongVowels::Circumflex,
+        LongVowels::Spelled => romaji::LongVowels::Spelled,
+    };
+
+    Options::new(system, long_vowels)
+}
@@ -0,0 +1,209 @@
+use super::Result;
+use crate::RateLimit;
+use actix_web::{
+    web::{self, Data, Json},
+    HttpRequest, ResponseError,
+};
+use config::Config;
+use error::api_error::RestError;
+use rayon::prelude::*;
+use search::SearchExecutor;
+use types::{
+    api::search::batch::{Item, ItemError, ItemResult, Request, Response},
+    jotoba::search::SearchTarget,
+};
+
+/// Max amount of items in a single batch request
+pub const MAX_ITEMS: usize = 100;
+
+/// Max summed up cost of all items in a single batch request
+pub const MAX_COST: u32 = 150;
+
+/// Looks up multiple queries or sequence IDs at once
+pub async fn batch_search(
+    payload: Json<Request>,
+    config: web::Data<Config>,
+    req: HttpRequest,
+) -> Result<Json<Response>> {
+    let cost = request_cost(&payload.items)?;
+
+    crate::require(crate::SEARCH_DATA)?;
+
+    if let Some(limiter) = req.app_data::<Data<dyn RateLimit>>() {
+        limiter.charge(&req, cost)?;
+    }
+
+    let payload = payload.into_inner();
+    let results = web::block(move || {
+        payload
+            .items
+            .par_iter()
+            .map(|item| match run_item(item, &payload, &config) {
+                Ok(res) => res,
+                Err(err) => ItemResult::Error(conv_error(err)),
+            })
+            .collect::<Vec<_>>()
+    })
+    .await?;
+
+    Ok(Json(Response::new(results)))
+}
+
+/// Returns the summed up cost of all `items` or `RestError::InvalidRequest` if there are too
+/// many of them or they are too expensive
+fn request_cost(items: &[Item]) -> Result<u32> {
+    if items.is_empty() {
+        return Err(RestError::InvalidRequest("No items given".to_string()));
+    }
+
+    if items.len() > MAX_ITEMS {
+        return Err(RestError::InvalidRequest(format!(
+            "A batch request may contain up to {MAX_ITEMS} items"
+        )));
+    }
+
+    let cost = items.iter().map(item_cost).sum();
+    if cost > MAX_COST {
+        return Err(RestError::InvalidRequest(format!(
+            "The summed up cost of all items may not exceed {MAX_COST}"
+        )));
+    }
+
+    Ok(cost)
+}
+
+/// Returns the cost of an item. Sequence lookups are cheaper than searches
+fn item_cost(item: &Item) -> u32 {
+    if item.sequence.is_some() {
+        return 1;
+    }
+
+    match item.target {
+        SearchTarget::Words | SearchTarget::Sentences => 2,
+        SearchTarget::Kanji | SearchTarget::Names => 1,
+    }
+}
+
+/// Runs the search of a single item
+fn run_item(item: &Item, payload: &Request, config: &Config) -> Result<ItemResult> {
+    let q_str = match (&item.query, item.sequence) {
+        (Some(query), None) => query.clone(),
+        // Kanji don't have sequence IDs
+        (None, Some(_)) if item.target == SearchTarget::Kanji => {
+            return Err(RestError::InvalidRequest(
+                "Kanji can't be looked up by a sequence ID".to_string(),
+            ));
+        }
+        (None, Some(seq)) => format!("seq:{seq}"),
+        _ => {
+            return Err(RestError::InvalidRequest(
+                "Either query or sequence has to be set".to_string(),
+            ))
+        }
+    };
+
+    let language = item.language.unwrap_or(payload.language);
+    let query = super::new_query(q_str, item.target, language, payload.no_english, false)?;
+    let timeout = config.get_search_timeout(item.target.as_str());
+
+    let res = match item.target {
+        SearchTarget::Words => {
+            let search = search::word::Search::new(&query);
+            let result = SearchExecutor::new(search).with_timeout(timeout).run();
+            ItemResult::Words(super::word::conv_result(&result, payload.romaji))
+        }
+        SearchTarget::Kanji => {
+            let result = search::kanji::search(&query)?;
+            ItemResult::Kanji(super::kanji::to_response(&result.items))
+        }
+        SearchTarget::Names => {
+            let search = search::name::Search::new(&query);
+            let result = SearchExecutor::new(search).with_timeout(timeout).run();
+            ItemResult::Names(super::name::conv_result(&result, payload.romaji))
+        }
+        SearchTarget::Sentences => {
+            let search = search::sentence::Search::new(&query);
+            let result = SearchExecutor::new(search).with_timeout(timeout).run();
+            ItemResult::Sentences(super::sentence::conv_result(&result))
+        }
+    };
+
+    Ok(res)
+}
+
+fn conv_error(err: RestError) -> ItemError {
+    ItemError::new(err.status_code().as_u16(), err.name(), err.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn item(query: Option<&str>, sequence: Option<u32>, target: SearchTarget) -> Item {
+        Item {
+            query: query.map(|i| i.to_string()),
+            sequence,
+            target,
+            language: None,
+        }
+    }
+
+    fn request(items: Vec<Item>) -> Request {
+        Request {
+            items,
+            language: Default::default(),
+            no_english: false,
+            romaji: false,
+        }
+    }
+
+    #[test]
+    fn test_item_cost() {
+        let query = Some("学校");
+        assert_eq!(item_cost(&item(query, None, SearchTarget::Words)), 2);
+        assert_eq!(item_cost(&item(query, None, SearchTarget::Sentences)), 2);
+        assert_eq!(item_cost(&item(query, None, SearchTarget::Kanji)), 1);
+        assert_eq!(item_cost(&item(query, None, SearchTarget::Names)), 1);
+        assert_eq!(item_cost(&item(None, Some(1), SearchTarget::Words)), 1);
+        assert_eq!(item_cost(&item(None, Some(1), SearchTarget::Sentences)), 1);
+    }
+
+    #[test]
+    fn test_request_cost() {
+        let words = |n: u32| -> Vec<Item> {
+            (0..n)
+                .map(|_| item(Some("a"), None, SearchTarget::Words))
+                .collect()
+        };
+        let seqs = |n: u32| -> Vec<Item> {
+            (0..n)
+                .map(|i| item(None, Some(i), SearchTarget::Words))
+                .collect()
+        };
+
+        assert_eq!(request_cost(&words(75)), Ok(150));
+        assert_eq!(request_cost(&seqs(MAX_ITEMS as u32)), Ok(MAX_ITEMS as u32));
+
+        let errors = [vec![], words(76), seqs(MAX_ITEMS as u32 + 1)];
+        for items in errors {
+            let err = request_cost(&items).unwrap_err();
+            assert!(matches!(err, RestError::InvalidRequest(_)));
+        }
+    }
+
+    #[test]
+    fn test_item_validation() {
+        let config = Config::default();
+        let invalid = [
+            item(Some("学校"), Some(1), SearchTarget::Words),
+            item(None, None, SearchTarget::Words),
+            item(None, Some(1), SearchTarget::Kanji),
+        ];
+
+        for item in invalid {
+            let payload = request(vec![]);
+            let err = run_item(&item, &payload, &config).err();
+            assert!(matches!(err, Some(RestError::InvalidRequest(_))));
+        }
+    }
+}
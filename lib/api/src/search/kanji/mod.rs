@@ -1,6 +1,6 @@
 use actix_web::web::{self, Json};
 use types::{
-    api::search::kanji::{Kanji, Response},
+    api::search::kanji::{Kanji, ReadingUsage, Response},
     jotoba::search::SearchTarget,
 };
 
@@ -9,14 +9,29 @@ use super::{Result, SearchRequest};
 /// Do a kanji search via API
 pub async fn kanji_search(payload: Json<SearchRequest>) -> Result<Json<Response>> {
     let query = super::parse_query(payload, SearchTarget::Kanji)?;
-    let result = web::block(move || search::kanji::search(&query))
-        .await??
-        .items;
-    Ok(Json(to_response(result)))
+    let result = web::block(move || search::kanji::search(&query)).await??;
+    Ok(Json(to_response(&result.items)))
 }
 
+/// Converts the found kanji into the API response
 #[inline]
-fn to_response(items: Vec<search::kanji::result::Item>) -> Response {
-    let kanji = items.into_iter().map(|i| Kanji::from(&i.kanji)).collect();
+pub fn to_response(items: &[search::kanji::result::Item]) -> Response {
+    let kanji = items.iter().map(convert_item).collect();
     Response { kanji }
 }
+
+fn convert_item(item: &search::kanji::result::Item) -> Kanji {
+    let mut kanji = Kanji::from(&item.kanji);
+
+    let reading_usage = item
+        .reading_usage
+        .iter()
+        .map(|i| {
+            let examples = i.examples().into_iter().map(|j| j.into()).collect();
+            ReadingUsage::new(i.reading.clone(), i.count, i.percent(), examples)
+        })
+        .collect();
+    kanji.set_reading_usage(reading_usage);
+
+    kanji
+}
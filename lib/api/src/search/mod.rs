@@ -1,3 +1,4 @@
+pub mod batch;
 pub mod kanji;
 pub mod name;
 pub mod sentence;
@@ -6,20 +7,42 @@ pub mod word;
 use actix_web::web::Json;
 use error::api_error::RestError;
 use search::query::{parser::QueryParser, Query, UserSettings};
-use types::{api::search::SearchRequest, jotoba::search::SearchTarget};
+use types::{
+    api::search::SearchRequest,
+    jotoba::{languages::Language, search::SearchTarget},
+};
 
 pub type Result<T> = std::result::Result<T, RestError>;
 
 pub(crate) fn parse_query(payload: Json<SearchRequest>, q_type: SearchTarget) -> Result<Query> {
+    crate::require(crate::SEARCH_DATA)?;
+
+    let q_str = payload.query_str.clone();
+    new_query(
+        q_str,
+        q_type,
+        payload.language,
+        payload.no_english,
+        payload.explain,
+    )
+}
+
+/// Parses `q_str` into a query of `q_type`
+pub(crate) fn new_query(
+    q_str: String,
+    q_type: SearchTarget,
+    language: Language,
+    no_english: bool,
+    explain: bool,
+) -> Result<Query> {
     let settings = UserSettings {
-        user_lang: payload.language,
-        show_english: !payload.no_english,
+        user_lang: language,
+        show_english: !no_english,
         ..UserSettings::default()
     };
 
-    let q_str = payload.query_str.clone();
-
     let query = QueryParser::new(q_str, q_type, settings)
+        .with_explain(explain)
         .parse()
         .ok_or(RestError::BadRequest)?;
 
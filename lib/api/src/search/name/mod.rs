@@ -1,16 +1,47 @@
 use actix_web::web::{self, Json};
-use search::SearchExecutor;
-use types::{api::search::name::Response, jotoba::search::SearchTarget};
+use config::Config;
+use search::{name::result::AddResData, result::SearchResult, SearchExecutor};
+use types::{
+    api::search::name::Response,
+    jotoba::{names::Name, search::SearchTarget},
+};
 
 use super::{Result, SearchRequest};
 
 /// Do a name search via API
-pub async fn name_search(payload: Json<SearchRequest>) -> Result<Json<Response>> {
+pub async fn name_search(
+    payload: Json<SearchRequest>,
+    config: web::Data<Config>,
+) -> Result<Json<Response>> {
+    let with_romaji = payload.romaji;
     let query = super::parse_query(payload, SearchTarget::Kanji)?;
+    let timeout = config.get_search_timeout(SearchTarget::Names.as_str());
     let result = web::block(move || {
         let search = search::name::Search::new(&query);
-        SearchExecutor::new(search).run()
+        SearchExecutor::new(search).with_timeout(timeout).run()
     })
     .await?;
-    Ok(Json(result.items.into()))
+
+    Ok(Json(conv_result(&result, with_romaji)))
+}
+
+/// Converts the result of a name search into the API response
+pub fn conv_result(
+    result: &SearchResult<&'static Name, AddResData>,
+    with_romaji: bool,
+) -> Response {
+    let mut res: Response = result.items.clone().into();
+    res.set_truncated(result.truncated);
+
+    let transcriptions = result.other_data.transcriptions.iter();
+    res.set_transcriptions(transcriptions.map(|i| i.kana.clone()).collect());
+
+    if with_romaji {
+        for name in res.names_mut() {
+            let romaji = crate::romaji::romanize_kana(&name.kana);
+            name.romaji = Some(japanese::romaji::capitalize(&romaji));
+        }
+    }
+
+    res
 }
@@ -1,4 +1,9 @@
 use actix_web::web::{self, Json};
+use config::Config;
+use search::{
+    result::SearchResult,
+    sentence::result::{ResData, Sentence as ResSentence},
+};
 use types::{
     api::search::sentence::{Response, Sentence},
     jotoba::search::SearchTarget,
@@ -7,24 +12,39 @@ use types::{
 use super::{Result, SearchRequest};
 
 /// Do a Sentence search via API
-pub async fn sentence_search(payload: Json<SearchRequest>) -> Result<Json<Response>> {
+pub async fn sentence_search(
+    payload: Json<SearchRequest>,
+    config: web::Data<Config>,
+) -> Result<Json<Response>> {
     let query = super::parse_query(payload, SearchTarget::Kanji)?;
+    let timeout = config.get_search_timeout(SearchTarget::Sentences.as_str());
 
     let result = web::block(move || {
         let search = search::sentence::Search::new(&query);
-        search::SearchExecutor::new(search).run()
+        search::SearchExecutor::new(search)
+            .with_timeout(timeout)
+            .run()
     })
-    .await?
-    .items
-    .into_iter()
-    .map(|i| search_to_sentence(i))
-    .collect::<Vec<_>>();
+    .await?;
+
+    Ok(Json(conv_result(&result)))
+}
+
+/// Converts the result of a sentence search into the API response
+pub fn conv_result(result: &SearchResult<ResSentence, ResData>) -> Response {
+    let sentences = result
+        .items
+        .iter()
+        .map(search_to_sentence)
+        .collect::<Vec<_>>();
 
-    Ok(Json(result.into()))
+    let mut res: Response = sentences.into();
+    res.set_truncated(result.truncated);
+    res
 }
 
 #[inline]
-fn search_to_sentence(sentence: search::sentence::result::Sentence) -> Sentence {
+fn search_to_sentence(sentence: &ResSentence) -> Sentence {
     Sentence {
         eng: sentence.get_english().map(|i| i.to_owned()),
         content: sentence.content.to_string(),
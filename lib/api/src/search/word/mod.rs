@@ -1,6 +1,11 @@
 use super::{Result, SearchRequest};
 use actix_web::web::{self, Json};
-use search::{word::Search, SearchExecutor};
+use config::Config;
+use search::{
+    result::SearchResult,
+    word::{result::AddResData, Search},
+    SearchExecutor,
+};
 use types::{
     api::search::{
         kanji::Kanji,
@@ -10,18 +15,44 @@ use types::{
 };
 
 /// Do a word search via API
-pub async fn word_search(payload: Json<SearchRequest>) -> Result<Json<Response>> {
+pub async fn word_search(
+    payload: Json<SearchRequest>,
+    config: web::Data<Config>,
+) -> Result<Json<Response>> {
+    let with_romaji = payload.romaji;
     let query = super::parse_query(payload, SearchTarget::Words)?;
+    let timeout = config.get_search_timeout(SearchTarget::Words.as_str());
     let result = web::block(move || {
         let search = Search::new(&query);
-        SearchExecutor::new(search).run()
+        SearchExecutor::new(search).with_timeout(timeout).run()
     })
     .await?;
 
+    Ok(Json(conv_result(&result, with_romaji)))
+}
+
+/// Converts the result of a word search into the API response
+pub fn conv_result(
+    result: &SearchResult<types::jotoba::words::Word, AddResData>,
+    with_romaji: bool,
+) -> Response {
     let kanji: Vec<Kanji> = search::word::kanji::load_word_kanji_info(&result.items)
         .into_iter()
         .map(|i| (&i).into())
         .collect();
-    let words: Vec<Word> = result.items.into_iter().map(|i| (&i).into()).collect();
-    Ok(Json(Response::new(words, kanji)))
+    let mut words: Vec<Word> = result.items.iter().map(|i| i.into()).collect();
+
+    for (word, explanation) in words.iter_mut().zip(result.explanations.iter()) {
+        word.set_explanation(explanation.clone());
+    }
+
+    if with_romaji {
+        for (word, res_word) in words.iter_mut().zip(result.items.iter()) {
+            word.set_romaji(crate::romaji::romanize_word(res_word));
+        }
+    }
+
+    let mut res = Response::new(words, kanji);
+    res.set_truncated(result.truncated);
+    res
 }
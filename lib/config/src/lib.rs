@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     fs::DirEntry,
     io::{BufReader, Read, Write},
     time::Duration,
@@ -15,6 +16,7 @@ pub struct Config {
     pub server: ServerConfig,
     pub sentry: Option<SentryConfig>,
     pub search: Option<SearchConfig>,
+    pub rate_limit: Option<RateLimitConfig>,
 
     #[serde(skip)]
     pub asset_hash: String,
@@ -31,6 +33,10 @@ pub struct ServerConfig {
     pub news_folder: Option<String>,
     pub unidic_dict: Option<String>,
     pub debug_mode: Option<bool>,
+    /// Expose prometheus metrics at `/metrics`
+    pub metrics: Option<bool>,
+    /// Expose the `/healthz` and `/readyz` endpoints
+    pub health_checks: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -43,6 +49,39 @@ pub struct SearchConfig {
     pub suggestion_sources: Option<String>,
     pub indexes_source: Option<String>,
     pub report_queries_after: Option<u64>,
+    /// JSONL file to log queries taking longer than `report_queries_after` to
+    pub slow_query_log: Option<String>,
+    /// JSONL file to log anonymised queries without any result to
+    pub zero_result_log: Option<String>,
+    /// Max amount of cached results per search type. 0 disables the cache
+    pub cache_size: Option<usize>,
+    /// Time in seconds a cached result stays valid
+    pub cache_ttl: Option<u64>,
+    /// Time in milliseconds a search may take before returning the results found so far. 0
+    /// disables the deadline
+    pub search_timeout: Option<u64>,
+    /// Overrides `search_timeout` per search target, eg. `names = 2000`. Kanji searches don't
+    /// have a deadline
+    pub search_timeouts: Option<HashMap<String, u64>>,
+}
+
+/// Limits for API requests. Each client has a budget of tokens which refills over time. Requests
+/// cost one token unless configured otherwise
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct RateLimitConfig {
+    /// Max amount of tokens a client can have
+    pub burst: Option<u32>,
+    /// Amount of tokens a client regains per second
+    pub per_second: Option<f64>,
+    /// Costs of single routes, eg. `"/api/img_scan" = 20`
+    pub costs: Option<HashMap<String, u32>>,
+    /// API keys, passed in the `X-API-Key` header, which get their own budget instead of
+    /// sharing the one of their IP
+    pub api_keys: Option<Vec<String>>,
+    /// IPs and API keys which aren't limited at all
+    pub allowlist: Option<Vec<String>>,
+    /// Take the client IP from the `Forwarded` or `X-Forwarded-For` header
+    pub behind_proxy: Option<bool>,
 }
 
 impl Config {
@@ -73,6 +112,48 @@ impl Config {
         Duration::from_secs(timeout)
     }
 
+    /// Returns the configured slow query log file. `None` if slow queries shouldn't be logged
+    pub fn get_slow_query_log(&self) -> Option<&str> {
+        self.search.as_ref()?.slow_query_log.as_deref()
+    }
+
+    /// Returns the configured zero result log file. `None` if queries without results
+    /// shouldn't be logged
+    pub fn get_zero_result_log(&self) -> Option<&str> {
+        self.search.as_ref()?.zero_result_log.as_deref()
+    }
+
+    /// Returns the configured amount of cached results per search type
+    pub fn get_search_cache_size(&self) -> usize {
+        self.search
+            .as_ref()
+            .and_then(|i| i.cache_size)
+            .unwrap_or(1000)
+    }
+
+    /// Returns the configured time a cached search result stays valid
+    pub fn get_search_cache_ttl(&self) -> Duration {
+        let ttl = self
+            .search
+            .as_ref()
+            .and_then(|i| i.cache_ttl)
+            .unwrap_or(600);
+        Duration::from_secs(ttl)
+    }
+
+    /// Returns the configured deadline for searches of `target`. `None` if searches shouldn't
+    /// be limited
+    pub fn get_search_timeout(&self, target: &str) -> Option<Duration> {
+        let search = self.search.as_ref();
+        let timeout = search
+            .and_then(|i| i.search_timeouts.as_ref())
+            .and_then(|i| i.get(target).copied())
+            .or_else(|| search.and_then(|i| i.search_timeout))
+            .unwrap_or(5000);
+
+        (timeout > 0).then(|| Duration::from_millis(timeout))
+    }
+
     /// Returns the configured (or default) path for storage data
     pub fn get_storage_data_path(&self) -> String {
         self.server
@@ -127,6 +208,8 @@ impl Default for ServerConfig {
             tess_data: None,
             news_folder: Some(String::from("./resources/news")),
             debug_mode: Some(false),
+            metrics: Some(false),
+            health_checks: Some(true),
         }
     }
 }
@@ -147,6 +230,44 @@ impl ServerConfig {
     pub fn get_news_folder(&self) -> &str {
         self.news_folder.as_deref().unwrap_or("./resources/news")
     }
+
+    /// Returns `true` if the `/metrics` endpoint is enabled
+    pub fn metrics_enabled(&self) -> bool {
+        self.metrics.unwrap_or(false)
+    }
+
+    /// Returns `true` if the `/healthz` and `/readyz` endpoints are enabled
+    pub fn health_checks_enabled(&self) -> bool {
+        self.health_checks.unwrap_or(true)
+    }
+}
+
+impl RateLimitConfig {
+    pub fn get_burst(&self) -> u32 {
+        self.burst.unwrap_or(60)
+    }
+
+    pub fn get_per_second(&self) -> f64 {
+        self.per_second.unwrap_or(1.0)
+    }
+
+    /// Returns `true` if `id`, an IP or API key, isn't limited
+    pub fn is_allowed(&self, id: &str) -> bool {
+        self.allowlist
+            .as_ref()
+            .map_or(false, |i| i.iter().any(|i| i == id))
+    }
+
+    /// Returns `true` if `key` is a known API key
+    pub fn is_api_key(&self, key: &str) -> bool {
+        self.api_keys
+            .as_ref()
+            .map_or(false, |i| i.iter().any(|i| i == key))
+    }
+
+    pub fn is_behind_proxy(&self) -> bool {
+        self.behind_proxy.unwrap_or(false)
+    }
 }
 
 impl Config {
@@ -0,0 +1,124 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+/// Amount of iterations between two deadline checks within loops
+pub const CHECK_INTERVAL: usize = 64;
+
+/// Point in time a search has to be finished at. Searches check it cooperatively and stop early,
+/// keeping what they have found so far. Clones share whether any search stopped early
+#[derive(Clone, Debug, Default)]
+pub struct Deadline(Option<Arc<Inner>>);
+
+#[derive(Debug)]
+struct Inner {
+    at: Instant,
+    /// Set once a search stopped early because of the deadline
+    hit: AtomicBool,
+}
+
+impl Deadline {
+    /// A deadline which never expires
+    pub const NONE: Deadline = Deadline(None);
+
+    /// Creates a deadline expiring `timeout` from now
+    #[inline]
+    pub fn after(timeout: Duration) -> Self {
+        let inner = Instant::now().checked_add(timeout).map(|at| Inner {
+            at,
+            hit: AtomicBool::new(false),
+        });
+        Self(inner.map(Arc::new))
+    }
+
+    /// Creates a deadline expiring `timeout` from now or one which never expires if `timeout` is
+    /// `None`
+    #[inline]
+    pub fn from_timeout(timeout: Option<Duration>) -> Self {
+        timeout.map(Self::after).unwrap_or_default()
+    }
+
+    /// Returns `true` if the deadline has passed
+    #[inline]
+    pub fn is_expired(&self) -> bool {
+        self.0.as_ref().map_or(false, |i| Instant::now() >= i.at)
+    }
+
+    /// Returns `true` if the deadline has passed. Only checks the time every `CHECK_INTERVAL`th
+    /// iteration to keep the overhead in hot loops low. Meant for loops which stop once it
+    /// returns `true`, so the deadline gets marked as hit
+    #[inline]
+    pub fn expired_at(&self, iteration: usize) -> bool {
+        let expired = self.0.is_some() && iteration % CHECK_INTERVAL == 0 && self.is_expired();
+        if expired {
+            self.mark_hit();
+        }
+        expired
+    }
+
+    /// Marks that a search stopped early because of the deadline
+    #[inline]
+    pub fn mark_hit(&self) {
+        if let Some(inner) = &self.0 {
+            inner.hit.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Returns `true` if any search sharing this deadline stopped early because of it
+    #[inline]
+    pub fn was_hit(&self) -> bool {
+        self.0
+            .as_ref()
+            .map_or(false, |i| i.hit.load(Ordering::Relaxed))
+    }
+
+    /// Returns the time left until the deadline or `None` if there is none
+    #[inline]
+    pub fn remaining(&self) -> Option<Duration> {
+        self.0
+            .as_ref()
+            .map(|i| i.at.saturating_duration_since(Instant::now()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_none() {
+        assert!(!Deadline::NONE.is_expired());
+        assert!(!Deadline::from_timeout(None).expired_at(0));
+        assert_eq!(Deadline::NONE.remaining(), None);
+        assert!(!Deadline::NONE.was_hit());
+    }
+
+    #[test]
+    fn test_expired() {
+        let deadline = Deadline::after(Duration::ZERO);
+        assert!(deadline.is_expired());
+        assert!(!deadline.was_hit());
+        assert!(deadline.expired_at(0));
+        assert!(deadline.expired_at(CHECK_INTERVAL));
+        assert!(!deadline.expired_at(1));
+        assert_eq!(deadline.remaining(), Some(Duration::ZERO));
+        assert!(deadline.was_hit());
+
+        let deadline = Deadline::after(Duration::from_secs(60));
+        assert!(!deadline.is_expired());
+        assert!(!deadline.expired_at(0));
+        assert!(!deadline.was_hit());
+    }
+
+    #[test]
+    fn test_hit_shared() {
+        let deadline = Deadline::after(Duration::ZERO);
+        let clone = deadline.clone();
+        assert!(clone.expired_at(0));
+        assert!(deadline.was_hit());
+    }
+}
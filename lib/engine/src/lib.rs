@@ -1,3 +1,4 @@
+pub mod deadline;
 pub mod pushable;
 pub mod relevance;
 pub mod result;
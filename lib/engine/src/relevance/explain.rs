@@ -0,0 +1,77 @@
+use types::jotoba::search::explain::{Explanation, Factor, FactorOp};
+
+/// Records the contributions to the score of an item. Recording is a no-op if the explainer is
+/// disabled, so ranking functions can record their factors unconditionally
+#[derive(Debug, Default)]
+pub struct Explain {
+    inner: Option<Explanation>,
+}
+
+impl Explain {
+    /// Creates a new explainer which only records if `enabled` is `true`
+    #[inline]
+    pub fn new(enabled: bool) -> Self {
+        let inner = enabled.then(Explanation::default);
+        Self { inner }
+    }
+
+    /// Creates a new explainer which doesn't record anything
+    #[inline]
+    pub fn disabled() -> Self {
+        Self { inner: None }
+    }
+
+    /// Returns `true` if the explainer records factors
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    /// Sets the raw relevance of the item
+    #[inline]
+    pub fn relevance(&mut self, relevance: f32) {
+        if let Some(ref mut inner) = self.inner {
+            inner.relevance = Some(relevance);
+        }
+    }
+
+    #[inline]
+    pub fn add<S: ToString>(&mut self, name: S, value: f32) {
+        self.record(name, FactorOp::Add, value);
+    }
+
+    #[inline]
+    pub fn sub<S: ToString>(&mut self, name: S, value: f32) {
+        self.record(name, FactorOp::Sub, value);
+    }
+
+    #[inline]
+    pub fn mul<S: ToString>(&mut self, name: S, value: f32) {
+        self.record(name, FactorOp::Mul, value);
+    }
+
+    #[inline]
+    pub fn div<S: ToString>(&mut self, name: S, value: f32) {
+        self.record(name, FactorOp::Div, value);
+    }
+
+    #[inline]
+    pub fn set<S: ToString>(&mut self, name: S, value: f32) {
+        self.record(name, FactorOp::Set, value);
+    }
+
+    #[inline]
+    pub fn record<S: ToString>(&mut self, name: S, op: FactorOp, value: f32) {
+        if let Some(ref mut inner) = self.inner {
+            inner.factors.push(Factor::new(name, op, value));
+        }
+    }
+
+    /// Finishes the explanation with the final score of the item. Returns `None` if disabled
+    #[inline]
+    pub fn finish(self, score: f32) -> Option<Explanation> {
+        let mut inner = self.inner?;
+        inner.score = score;
+        Some(inner)
+    }
+}
@@ -2,19 +2,33 @@ use std::{
     cmp::Ordering,
     hash::{Hash, Hasher},
 };
+use types::jotoba::search::explain::{Explanation, Factor, FactorOp};
 
 /// A single item (result) in a set of search results
-#[derive(Clone, Copy, Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct RelItem<T> {
     pub item: T,
     pub relevance: f32,
+    /// Breakdown of the relevance. Only set if the search was asked to explain its ranking
+    pub explanation: Option<Box<Explanation>>,
 }
 
 impl<T: PartialEq> RelItem<T> {
     /// Create a new ResultItem<T>
     #[inline]
     pub fn new(item: T, relevance: f32) -> Self {
-        Self { item, relevance }
+        Self {
+            item,
+            relevance,
+            explanation: None,
+        }
+    }
+
+    /// Sets the explanation of the items relevance
+    #[inline]
+    pub fn with_explanation(mut self, explanation: Option<Explanation>) -> Self {
+        self.explanation = explanation.map(Box::new);
+        self
     }
 }
 
@@ -29,8 +43,29 @@ impl<T> RelItem<T> {
         RelItem {
             item,
             relevance: self.relevance,
+            explanation: self.explanation,
+        }
+    }
+
+    /// Multiplies the items relevance with `factor` and records it in the explanation, if any
+    #[inline]
+    pub fn scale<S: ToString>(&mut self, name: S, factor: f32) {
+        self.relevance *= factor;
+        if let Some(ref mut explanation) = self.explanation {
+            let factor = Factor::new(name, FactorOp::Mul, factor);
+            explanation.factors.push(factor);
+            explanation.score = self.relevance;
         }
     }
+
+    /// Returns the explanation of the item, creating one with the current relevance as score if
+    /// there is none yet
+    #[inline]
+    pub fn explanation_mut(&mut self) -> &mut Explanation {
+        let relevance = self.relevance;
+        self.explanation
+            .get_or_insert_with(|| Box::new(Explanation::new(relevance)))
+    }
 }
 
 impl<T: PartialEq> PartialEq for RelItem<T> {
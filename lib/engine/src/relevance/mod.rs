@@ -1,7 +1,9 @@
 pub mod data;
+pub mod explain;
 pub mod item;
 
 use data::SortData;
+use explain::Explain;
 
 pub trait RelevanceEngine {
     type OutItem;
@@ -12,4 +14,14 @@ pub trait RelevanceEngine {
         &self,
         item: &SortData<'item, 'query, Self::OutItem, Self::IndexItem, Self::Query>,
     ) -> f32;
+
+    /// Same as `score` but records all ranking contributions into `explain`. Engines which don't
+    /// implement this only report their final score
+    fn score_explained<'item, 'query>(
+        &self,
+        item: &SortData<'item, 'query, Self::OutItem, Self::IndexItem, Self::Query>,
+        _explain: &mut Explain,
+    ) -> f32 {
+        self.score(item)
+    }
 }
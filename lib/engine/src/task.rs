@@ -1,7 +1,8 @@
 use crate::{
+    deadline::Deadline,
     pushable::{MaxCounter, PushMod, Pushable},
     relevance::item::RelItem,
-    relevance::{data::SortData, RelevanceEngine},
+    relevance::{data::SortData, explain::Explain, RelevanceEngine},
     result::SearchResult,
     Engine,
 };
@@ -35,6 +36,11 @@ pub struct SearchTask<'index, E: Engine<'index>> {
     limit: usize,
     offset: usize,
     est_limit: usize,
+
+    /// Attach an explanation of the score to each result
+    explain: bool,
+    /// Stop retrieving once passed
+    deadline: Deadline,
     phantom: PhantomData<E>,
 }
 
@@ -111,6 +117,20 @@ where
         self
     }
 
+    /// Attaches an itemised explanation of the score to each result if `explain` is `true`
+    #[inline]
+    pub fn with_explain(mut self, explain: bool) -> Self {
+        self.explain = explain;
+        self
+    }
+
+    /// Stops the search once `deadline` has passed, keeping the results found until then
+    #[inline]
+    pub fn with_deadline(mut self, deadline: Deadline) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
     /// Set the search task's raw document filter
     pub fn with_item_filter<F: 'static>(mut self, item_filter: F) -> Self
     where
@@ -192,15 +212,21 @@ where
 
         let mut pushed = 0;
 
-        loop {
+        for retrieved in 1.. {
+            if self.deadline.expired_at(retrieved) {
+                break;
+            }
+
             let (index_item, out_items) = match self.retrieve_next(&mut retr) {
                 Some(v) => v,
                 None => break,
             };
 
             for i in out_items {
+                let mut explain = Explain::new(sort && self.explain);
+
                 let score = if sort || self.has_threshold() {
-                    self.score(&i, &index_item, &query)
+                    self.score(&i, &index_item, &query, &mut explain)
                 } else {
                     0.0
                 };
@@ -211,7 +237,8 @@ where
 
                 // Break if caller doesn't want to consume more
                 pushed += 1;
-                if !out.push(RelItem::new(i, score)) {
+                let item = RelItem::new(i, score).with_explanation(explain.finish(score));
+                if !out.push(item) {
                     break;
                 }
             }
@@ -221,7 +248,13 @@ where
     }
 
     #[inline]
-    fn score(&self, out_item: &E::Output, index_item: &E::Document, query: &E::Query) -> f32 {
+    fn score(
+        &self,
+        out_item: &E::Output,
+        index_item: &E::Document,
+        query: &E::Query,
+        explain: &mut Explain,
+    ) -> f32 {
         let s_data = SortData::new(
             out_item,
             index_item,
@@ -232,7 +265,7 @@ where
         );
         self.cust_order
             .as_ref()
-            .map(|i| i.score(&s_data))
+            .map(|i| i.score_explained(&s_data, explain))
             .unwrap_or(0.0)
     }
 
@@ -292,6 +325,8 @@ impl<'a, T: Engine<'a>> Default for SearchTask<'a, T> {
             limit: 1000,
             offset: 0,
             est_limit: 100,
+            explain: false,
+            deadline: Deadline::NONE,
             phantom: PhantomData,
         }
     }
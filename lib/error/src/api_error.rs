@@ -1,6 +1,10 @@
 #![allow(dead_code, unreachable_patterns)]
 
-use actix_web::{error::BlockingError, http::StatusCode, HttpResponse, ResponseError};
+use actix_web::{
+    error::BlockingError,
+    http::{header::RETRY_AFTER, StatusCode},
+    HttpResponse, ResponseError,
+};
 use serde::Serialize;
 use thiserror::Error;
 
@@ -9,6 +13,9 @@ pub enum Origin {
     Radicals,
     Suggestions,
     File,
+    Resources,
+    Indexes,
+    Tokenizer,
 }
 
 impl std::fmt::Debug for Origin {
@@ -20,12 +27,15 @@ impl std::fmt::Debug for Origin {
                 Origin::Radicals => "radicals",
                 Origin::Suggestions => "suggestions",
                 Origin::File => "file",
+                Origin::Resources => "resources",
+                Origin::Indexes => "indexes",
+                Origin::Tokenizer => "tokenizer",
             }
         )
     }
 }
 
-#[derive(Error, Debug, Clone, Copy, PartialEq)]
+#[derive(Error, Debug, Clone, PartialEq)]
 pub enum RestError {
     #[error("Not found")]
     NotFound,
@@ -33,6 +43,10 @@ pub enum RestError {
     #[error("Bad request")]
     BadRequest,
 
+    /// Bad request with a message explaining what's wrong with it
+    #[error("{0}")]
+    InvalidRequest(String),
+
     #[error("Internal server error")]
     Internal,
 
@@ -50,6 +64,14 @@ pub enum RestError {
 
     #[error("missing {0:?}")]
     Missing(Origin),
+
+    /// Data required to handle the request is still being loaded
+    #[error("{0:?} still loading")]
+    Loading(Origin),
+
+    /// The client exceeded its request budget. Holds the seconds until it may retry
+    #[error("Too many requests")]
+    TooManyRequests(u64),
 }
 
 /// Error response format. Used as json encoding structure
@@ -64,12 +86,14 @@ impl RestError {
     pub fn name(&self) -> String {
         match self {
             Self::NotFound => "NotFound".to_string(),
-            Self::BadRequest => "BadRequest".to_string(),
+            Self::BadRequest | Self::InvalidRequest(_) => "BadRequest".to_string(),
             Self::Internal => "InternalError".to_string(),
             Self::Timeout => "Timeout".to_string(),
             Self::IoError => "IoError".to_string(),
             Self::NoTextFound => "NoTextFound".to_string(),
             Self::FormatNotSupported => "FormatNotSupported".to_string(),
+            Self::Loading(_) => "Loading".to_string(),
+            Self::TooManyRequests(_) => "TooManyRequests".to_string(),
             _ => "InternalError".to_string(),
         }
     }
@@ -80,11 +104,13 @@ impl ResponseError for RestError {
     fn status_code(&self) -> StatusCode {
         match *self {
             Self::NotFound => StatusCode::NOT_FOUND,
-            Self::BadRequest => StatusCode::BAD_REQUEST,
+            Self::BadRequest | Self::InvalidRequest(_) => StatusCode::BAD_REQUEST,
             Self::Internal => StatusCode::INTERNAL_SERVER_ERROR,
             Self::Timeout => StatusCode::REQUEST_TIMEOUT,
             Self::FormatNotSupported => StatusCode::BAD_REQUEST,
             Self::NoTextFound => StatusCode::SEE_OTHER,
+            Self::Loading(_) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -96,7 +122,12 @@ impl ResponseError for RestError {
             message: self.to_string(),
             error: self.name(),
         };
-        HttpResponse::build(status_code).json(error_response)
+
+        let mut response = HttpResponse::build(status_code);
+        if let Self::TooManyRequests(retry_after) = self {
+            response.insert_header((RETRY_AFTER, retry_after.to_string()));
+        }
+        response.json(error_response)
     }
 }
 
@@ -28,6 +28,10 @@ pub async fn direct_ep(
     config: web::Data<Config>,
     request: HttpRequest,
 ) -> Result<HttpResponse, Error> {
+    if !search::is_loaded() {
+        return Err(Error::Loading);
+    }
+
     let settings = user_settings::parse(&request);
 
     let (stype, id) = h_query.into_inner();
@@ -42,7 +46,7 @@ pub async fn direct_ep(
 
     if let Err(err) = result_data {
         return match err {
-            web_error::Error::NotFound => Err(err),
+            web_error::Error::NotFound | web_error::Error::Loading => Err(err),
             _ => Ok(redirect_home()),
         };
     }
@@ -81,7 +85,11 @@ fn set_og_tag(base_data: &mut BaseData, query_type: SearchTarget) {
 fn search_res_val(res: &SearchResult) -> Option<String> {
     Some(match &res.result {
         ResultData::Word(w) => w.items[0].get_reading().reading.clone(),
-        ResultData::Name(n) => n[0].kanji.as_ref().unwrap_or(&n[0].kana).to_string(),
+        ResultData::Name(n) => n.items[0]
+            .kanji
+            .as_ref()
+            .unwrap_or(&n.items[0].kana)
+            .to_string(),
         _ => return None,
     })
 }
@@ -90,7 +98,8 @@ fn search_res_val(res: &SearchResult) -> Option<String> {
 pub async fn find_direct_word(id: &str, settings: &UserSettings) -> Result<ResultData, Error> {
     let sequence_id: u32 = id.parse().map_err(|_| Error::NotFound)?;
 
-    let res_name = resources::get()
+    let res_name = resources::try_get()
+        .ok_or(Error::Loading)?
         .words()
         .by_sequence(sequence_id)
         .ok_or(web_error::Error::NotFound)?
@@ -104,10 +113,12 @@ pub async fn find_direct_word(id: &str, settings: &UserSettings) -> Result<Resul
 
     let word = results.remove(0);
 
-    Ok(ResultData::Word(search::result::SearchResult::<
+    Ok(ResultData::Word(Arc::new(search::result::SearchResult::<
         Word,
         AddResData,
-    >::with_other_default(vec![word], 1)))
+    >::with_other_default(
+        vec![word], 1
+    ))))
     /*
     Ok(ResultData::Word(WordResult {
         items,
@@ -124,19 +135,22 @@ pub async fn find_direct_word(id: &str, settings: &UserSettings) -> Result<Resul
 pub async fn find_direct_name(id: &str) -> Result<ResultData, Error> {
     let sequence_id: u32 = id.parse().map_err(|_| Error::NotFound)?;
 
-    let res_word = resources::get()
+    let res_word = resources::try_get()
+        .ok_or(Error::Loading)?
         .names()
         .by_sequence(sequence_id)
         .ok_or(web_error::Error::NotFound)?;
 
-    Ok(ResultData::Name(vec![res_word]))
+    let result = search::result::SearchResult::with_other_default(vec![res_word], 1);
+    Ok(ResultData::Name(Arc::new(result)))
 }
 
 /// Find direct sentence
 pub async fn find_direct_sentence(id: &str, settings: &UserSettings) -> Result<ResultData, Error> {
     let sequence_id: u32 = id.parse().map_err(|_| Error::NotFound)?;
 
-    let res_sentence = resources::get()
+    let res_sentence = resources::try_get()
+        .ok_or(Error::Loading)?
         .sentences()
         .by_id(sequence_id)
         .ok_or(web_error::Error::NotFound)?;
@@ -146,9 +160,11 @@ pub async fn find_direct_sentence(id: &str, settings: &UserSettings) -> Result<R
             .unwrap();
 
     use search::result::SearchResult as SearchResult2;
-    Ok(ResultData::Sentence(SearchResult2 {
-        items: vec![res_sentence],
-        total: 1,
-        other_data: sentence::result::ResData::new(false),
-    }))
+    Ok(ResultData::Sentence(Arc::new(
+        SearchResult2::with_other_data(
+            vec![res_sentence],
+            1,
+            sentence::result::ResData::new(false),
+        ),
+    )))
 }
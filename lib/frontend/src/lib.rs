@@ -9,6 +9,7 @@ pub mod help_page;
 pub mod index;
 pub mod news_ep;
 pub mod og_tags;
+pub mod query_log;
 pub mod search_ep;
 //pub mod search_help;
 mod session;
@@ -18,7 +19,7 @@ mod url_query;
 pub mod user_settings;
 pub mod web_error;
 
-use std::fmt::Display;
+use std::{fmt::Display, sync::Arc};
 
 use config::Config;
 use localization::{
@@ -28,9 +29,10 @@ use localization::{
 };
 use news::NewsEntry;
 use og_tags::TagKeyName;
-use search::{query::Query, result::SearchResult as SearchResult2};
+use once_cell::sync::Lazy;
+use search::{executor::ProducerRun, query::Query, result::SearchResult as SearchResult2};
 
-use search::{kanji::result::Item as KanjiItem, query::UserSettings};
+use search::{kanji::result::KanjiResult, query::UserSettings};
 use types::jotoba::{
     names::Name,
     pagination::Pagination,
@@ -71,10 +73,12 @@ pub struct SearchResult<'a> {
 /// The particular search result items
 #[derive(Clone)]
 pub enum ResultData {
-    Word(SearchResult2<Word, search::word::result::AddResData>),
-    KanjiInfo(Vec<KanjiItem>),
-    Name(Vec<&'static Name>),
-    Sentence(SearchResult2<search::sentence::result::Sentence, search::sentence::result::ResData>),
+    Word(Arc<SearchResult2<Word, search::word::result::AddResData>>),
+    KanjiInfo(Arc<KanjiResult>),
+    Name(Arc<SearchResult2<&'static Name, search::name::result::AddResData>>),
+    Sentence(
+        Arc<SearchResult2<search::sentence::result::Sentence, search::sentence::result::ResData>>,
+    ),
 }
 
 impl<'a> BaseData<'a> {
@@ -226,6 +230,15 @@ impl<'a> BaseData<'a> {
         }
         self.site.og_tags()
     }
+
+    /// Returns a JS array of the hashtags which aren't part of the hashtag index, so the
+    /// frontend suggests the same tags the search understands
+    pub fn extra_hashtags(&self) -> UnescapedStr<'static> {
+        static TAGS: Lazy<String> = Lazy::new(|| {
+            serde_json::to_string(&search::query::tags::extra_hashtag_names()).unwrap()
+        });
+        TAGS.as_str().into()
+    }
 }
 
 impl<'a> Site<'a> {
@@ -253,11 +266,44 @@ impl ResultData {
     pub fn is_empty(&self) -> bool {
         match self {
             ResultData::Word(w) => w.items.is_empty(),
-            ResultData::KanjiInfo(k) => k.is_empty(),
-            ResultData::Name(n) => n.is_empty(),
+            ResultData::KanjiInfo(k) => k.items.is_empty(),
+            ResultData::Name(n) => n.items.is_empty(),
             ResultData::Sentence(s) => s.items.is_empty(),
         }
     }
+
+    /// Returns the total amount of results, ignoring pagination
+    #[inline]
+    pub fn total(&self) -> usize {
+        match self {
+            ResultData::Word(w) => w.total,
+            ResultData::KanjiInfo(k) => k.items.len(),
+            ResultData::Name(n) => n.total,
+            ResultData::Sentence(s) => s.total,
+        }
+    }
+
+    /// Returns the producers which ran to find the results
+    #[inline]
+    pub fn producers(&self) -> &[ProducerRun] {
+        match self {
+            ResultData::Word(w) => &w.producers,
+            ResultData::KanjiInfo(_) => &[],
+            ResultData::Name(n) => &n.producers,
+            ResultData::Sentence(s) => &s.producers,
+        }
+    }
+
+    /// Returns `true` if the search hit its deadline and only returned the results found until then
+    #[inline]
+    pub fn truncated(&self) -> bool {
+        match self {
+            ResultData::Word(w) => w.truncated,
+            ResultData::KanjiInfo(_) => false,
+            ResultData::Name(n) => n.truncated,
+            ResultData::Sentence(s) => s.truncated,
+        }
+    }
 }
 
 impl<'a> SearchResult<'a> {
@@ -294,8 +340,8 @@ impl<'a> SearchResult<'a> {
     fn result_count(&self) -> usize {
         match &self.result {
             ResultData::Word(w) => w.items.len(),
-            ResultData::KanjiInfo(k) => k.len(),
-            ResultData::Name(n) => n.len(),
+            ResultData::KanjiInfo(k) => k.items.len(),
+            ResultData::Name(n) => n.items.len(),
             ResultData::Sentence(s) => s.items.len(),
         }
     }
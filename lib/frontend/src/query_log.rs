@@ -0,0 +1,200 @@
+use crate::ResultData;
+use config::Config;
+use once_cell::sync::Lazy;
+use search::query::Query;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+    sync::{
+        mpsc::{self, Sender},
+        Mutex, PoisonError,
+    },
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use types::jotoba::{languages::Language, search::SearchTarget};
+
+/// Hands log lines over to a background thread so searches don't have to wait for the file IO.
+/// The thread also serializes the writes to the log files
+static WRITER: Lazy<Mutex<Sender<LogLine>>> = Lazy::new(|| {
+    let (sender, receiver) = mpsc::channel::<LogLine>();
+    thread::spawn(move || {
+        for entry in receiver {
+            write_line(&entry.path, &entry.line);
+        }
+    });
+    Mutex::new(sender)
+});
+
+/// A serialized entry to append to a log file
+struct LogLine {
+    path: String,
+    line: String,
+}
+
+/// A search which took longer than `report_queries_after`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SlowQuery {
+    /// Unix timestamp of the search
+    pub time: u64,
+    pub query: String,
+    pub target: String,
+    pub language: Language,
+    /// Total amount of results
+    pub results: usize,
+    pub duration_ms: f64,
+    /// Empty if the result was served from the cache
+    pub producers: Vec<ProducerTiming>,
+}
+
+/// Timing of a single producer within a slow query
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProducerTiming {
+    pub name: String,
+    pub found: usize,
+    pub duration_ms: f64,
+}
+
+/// A search without any result. Doesn't contain anything but the normalized query and the day
+/// of the search in order to not be traceable to a user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ZeroResult {
+    /// Days since the unix epoch
+    pub day: u64,
+    pub query: String,
+    pub target: String,
+    pub language: Language,
+}
+
+/// Logs the search of `query` if it was slow or didn't find anything and logging is enabled.
+/// `cached` tells whether the result was served from the cache
+pub fn record(
+    config: &Config,
+    query: &Query,
+    result: &ResultData,
+    cached: bool,
+    duration: Duration,
+) {
+    if let Some(path) = config.get_slow_query_log() {
+        if duration >= config.get_query_report_timeout() {
+            append(path, &slow_query(query, result, cached, duration));
+        }
+    }
+
+    if let Some(path) = config.get_zero_result_log() {
+        if result.is_empty() {
+            append(path, &zero_result(query));
+        }
+    }
+}
+
+/// Reads all entries of a log file. Lines which can't be parsed get skipped
+pub fn read<T: DeserializeOwned, P: AsRef<Path>>(path: P) -> io::Result<Vec<T>> {
+    let reader = BufReader::new(File::open(path)?);
+
+    let mut entries = vec![];
+    for line in reader.lines() {
+        if let Ok(entry) = serde_json::from_str(&line?) {
+            entries.push(entry);
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Normalizes a query so the same query typed differently gets aggregated
+#[inline]
+pub fn normalize(query: &str) -> String {
+    query.trim().to_lowercase()
+}
+
+/// Name of a search target as written into the logs
+#[inline]
+pub fn target_name(target: SearchTarget) -> String {
+    format!("{target:?}").to_lowercase()
+}
+
+fn slow_query(query: &Query, result: &ResultData, cached: bool, duration: Duration) -> SlowQuery {
+    // The producers of a cached result ran during an earlier search
+    let producers = if cached {
+        vec![]
+    } else {
+        result
+            .producers()
+            .iter()
+            .map(|i| ProducerTiming {
+                name: i.name.clone(),
+                found: i.found,
+                duration_ms: millis(i.duration),
+            })
+            .collect()
+    };
+
+    SlowQuery {
+        time: unix_time().as_secs(),
+        query: query.raw_query.clone(),
+        target: target_name(query.target),
+        language: query.settings.user_lang,
+        results: result.total(),
+        duration_ms: millis(duration),
+        producers,
+    }
+}
+
+fn zero_result(query: &Query) -> ZeroResult {
+    ZeroResult {
+        day: unix_time().as_secs() / (24 * 60 * 60),
+        query: normalize(&query.query_str),
+        target: target_name(query.target),
+        language: query.settings.user_lang,
+    }
+}
+
+fn append<T: Serialize>(path: &str, entry: &T) {
+    let line = match serde_json::to_string(entry) {
+        Ok(line) => line,
+        Err(err) => {
+            log::warn!("Failed to serialize query log entry: {err}");
+            return;
+        }
+    };
+
+    let entry = LogLine {
+        path: path.to_string(),
+        line,
+    };
+    let sent = WRITER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .send(entry);
+
+    if sent.is_err() {
+        log::warn!("Query log writer stopped, dropping entry for {path}");
+    }
+}
+
+fn write_line(path: &str, line: &str) {
+    let res = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| writeln!(file, "{line}"));
+
+    if let Err(err) = res {
+        log::warn!("Failed to write query log {path}: {err}");
+    }
+}
+
+#[inline]
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[inline]
+fn unix_time() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
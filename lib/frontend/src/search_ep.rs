@@ -1,7 +1,7 @@
 use super::user_settings;
 use super::web_error;
 use crate::{
-    templates,
+    query_log, templates,
     url_query::{NoJSQueryStruct, QueryStruct},
     BaseData, ResultData,
 };
@@ -14,7 +14,10 @@ use search::{
     self,
     query::{Query, UserSettings},
 };
-use std::{sync::Arc, time::Instant};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use types::jotoba::search::help::SearchHelp;
 use types::jotoba::search::SearchTarget;
 
@@ -48,6 +51,10 @@ async fn search(
     config: web::Data<Config>,
     request: HttpRequest,
 ) -> Result<HttpResponse, web_error::Error> {
+    if !search::is_loaded() {
+        return Err(web_error::Error::Loading);
+    }
+
     let settings = user_settings::parse(&request);
 
     // Parse query and redirect to home on error
@@ -83,13 +90,15 @@ async fn do_search<'a>(
     query: &'a Query,
     config: &'a Config,
 ) -> Result<BaseData<'a>, web_error::Error> {
+    let start = Instant::now();
     let mut base_data = BaseData::new(locale_dict, settings, &config.asset_hash, &config);
+    let timeout = config.get_search_timeout(querytype.as_str());
 
-    let result_data = match querytype {
+    let (result_data, cached) = match querytype {
         SearchTarget::Kanji => kanji_search(&mut base_data, &query).await,
-        SearchTarget::Sentences => sentence_search(&mut base_data, &query).await,
-        SearchTarget::Names => name_search(&mut base_data, &query).await,
-        SearchTarget::Words => word_search(&mut base_data, &query).await,
+        SearchTarget::Sentences => sentence_search(&mut base_data, &query, timeout).await,
+        SearchTarget::Names => name_search(&mut base_data, &query, timeout).await,
+        SearchTarget::Words => word_search(&mut base_data, &query, timeout).await,
     }?;
 
     let mut search_help: Option<SearchHelp> = None;
@@ -98,24 +107,33 @@ async fn do_search<'a>(
         search_help = web::block(move || search::build_help(querytype, &query)).await?;
     }
 
+    query_log::record(config, query, &result_data, cached, start.elapsed());
+
     Ok(base_data.with_search_result(query, result_data, search_help))
 }
 
-type SResult = Result<ResultData, web_error::Error>;
+/// The result of a search and whether it was served from the cache
+type SResult = Result<(ResultData, bool), web_error::Error>;
 
 /// Perform a sentence search
-async fn sentence_search<'a>(base_data: &mut BaseData<'a>, query: &'a Query) -> SResult {
+async fn sentence_search<'a>(
+    base_data: &mut BaseData<'a>,
+    query: &'a Query,
+    timeout: Option<Duration>,
+) -> SResult {
     let q = query.to_owned();
 
     //let result = web::block(move || search::sentence::Search::new(&q).search()).await??;
-    let result = web::block(move || {
+    let (result, cached) = web::block(move || {
         let s = search::sentence::Search::new(&q);
-        search::SearchExecutor::new(s).run()
+        search::SearchExecutor::new(s)
+            .with_timeout(timeout)
+            .run_with_hit()
     })
     .await?;
 
     base_data.with_pages(result.total as u32, query.page as u32);
-    Ok(ResultData::Sentence(result))
+    Ok((ResultData::Sentence(result), cached))
 }
 
 /// Perform a kanji search
@@ -128,33 +146,46 @@ async fn kanji_search<'a>(base_data: &mut BaseData<'a>, query: &'a Query) -> SRe
         query.settings.kanji_page_size,
         400,
     );
-    Ok(ResultData::KanjiInfo(result.items))
+    // Kanji searches don't run any producers
+    Ok((ResultData::KanjiInfo(result), false))
 }
 
 /// Perform a name search
-async fn name_search<'a>(base_data: &mut BaseData<'a>, query: &'a Query) -> SResult {
+async fn name_search<'a>(
+    base_data: &mut BaseData<'a>,
+    query: &'a Query,
+    timeout: Option<Duration>,
+) -> SResult {
     let q = query.to_owned();
-    let result = web::block(move || {
+    let (result, cached) = web::block(move || {
         let search = search::name::Search::new(&q);
-        SearchExecutor::new(search).run()
+        SearchExecutor::new(search)
+            .with_timeout(timeout)
+            .run_with_hit()
     })
     .await?;
 
     base_data.with_pages(result.total as u32, query.page as u32);
-    Ok(ResultData::Name(result.items))
+    Ok((ResultData::Name(result), cached))
 }
 
 /// Perform a word search
-async fn word_search<'a>(base_data: &mut BaseData<'a>, query: &'a Query) -> SResult {
+async fn word_search<'a>(
+    base_data: &mut BaseData<'a>,
+    query: &'a Query,
+    timeout: Option<Duration>,
+) -> SResult {
     let q = query.to_owned();
-    let result = web::block(move || {
+    let (result, cached) = web::block(move || {
         let search = search::word::Search::new(&q);
-        SearchExecutor::new(search).run()
+        SearchExecutor::new(search)
+            .with_timeout(timeout)
+            .run_with_hit()
     })
     .await?;
 
     base_data.with_pages(result.total as u32, query.page as u32);
-    Ok(ResultData::Word(result))
+    Ok((ResultData::Word(result), cached))
 }
 
 pub(crate) fn redirect_home() -> HttpResponse {
@@ -1,12 +1,12 @@
 use itertools::Itertools;
 use japanese::furigana::{self, SentencePartRef};
 use localization::{traits::Translatable, TranslationDict};
-use search::result::SearchResult;
+use search::{kanji::result::ReadingUsage, result::SearchResult};
 use types::jotoba::{
     kanji::Kanji,
     languages::Language,
     names::Name,
-    words::{filter_languages, sense::Sense, Word},
+    words::{filter_languages, reading_breakdown::ReadingBreakdown, sense::Sense, Word},
 };
 
 use crate::unescaped::UnescapedString;
@@ -106,6 +106,33 @@ pub fn get_types_humanized(
     }
 }
 
+/// Returns `true` if the words reading is long enough to be split into the dictionary words it
+/// consists of
+pub fn is_splittable(word: &Word) -> bool {
+    let len = word.get_reading().reading.chars().count();
+    (3..=search::word::compound::MAX_SPLIT_LEN).contains(&len)
+}
+
+/// Returns the per kanji reading breakdown of a word. `None` if the resources aren't loaded yet
+pub fn reading_breakdown(word: &Word) -> Option<ReadingBreakdown> {
+    resources::try_get()?.kanji().reading_breakdown(word)
+}
+
+/// Returns the tooltip of a kanji reading, containing its usage and example words
+pub fn reading_usage_title(usage: &ReadingUsage) -> String {
+    let percent = format!("{:.1}%", usage.percent());
+    let examples = usage.examples();
+    if examples.is_empty() {
+        return percent;
+    }
+
+    let examples = examples
+        .iter()
+        .map(|i| i.get_reading().reading.as_str())
+        .join(", ");
+    format!("{percent} · {examples}")
+}
+
 pub fn word_kanji<O>(res: &SearchResult<Word, O>) -> Vec<Kanji> {
     search::word::kanji::load_word_kanji_info(&res.items)
 }
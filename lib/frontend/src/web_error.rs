@@ -11,6 +11,8 @@ pub enum Error {
     NotFound,
     SearchTimeout,
     BadRequest,
+    /// The data required to handle the request is still being loaded
+    Loading,
 }
 
 impl std::fmt::Display for Error {
@@ -45,6 +47,7 @@ impl ResponseError for Error {
             Error::NotFound => StatusCode::NOT_FOUND,
             Error::SearchTimeout => StatusCode::REQUEST_TIMEOUT,
             Error::BadRequest => StatusCode::BAD_REQUEST,
+            Error::Loading => StatusCode::SERVICE_UNAVAILABLE,
         }
     }
 
@@ -83,6 +86,7 @@ impl Error {
                 Error::NotFound => ("The page", "was not found"),
                 Error::SearchTimeout => ("Search", "timed out"),
                 Error::BadRequest => ("Bad request", ""),
+                Error::Loading => ("Jotoba is starting", "try again in a moment"),
             }
         };
 
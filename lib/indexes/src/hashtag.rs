@@ -20,6 +20,29 @@ impl HashTagIndex {
         Self { tags, index, trie }
     }
 
+    /// Adds `tags` which can be found in `trie_search` by their name or one of the given aliases.
+    /// Tags which are already part of the index are ignored
+    pub fn add_tags(&mut self, tags: Vec<(RawHashtag, Vec<String>)>) {
+        for (tag, aliases) in tags {
+            if self
+                .tags
+                .iter()
+                .any(|i| i.tag.eq_ignore_ascii_case(&tag.tag))
+            {
+                continue;
+            }
+
+            let id = self.tags.len() as u32;
+            let name = tag.tag.trim_start_matches('#').to_lowercase();
+            for key in std::iter::once(name).chain(aliases) {
+                if self.trie.get_str(&key).is_none() {
+                    self.trie.insert_str(&key, id);
+                }
+            }
+            self.tags.push(tag);
+        }
+    }
+
     #[inline]
     pub fn get(&self, pos: usize) -> Option<&RawHashtag> {
         self.tags.get(pos)
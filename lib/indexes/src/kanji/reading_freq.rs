@@ -46,6 +46,16 @@ impl FreqData {
             .find(|i| i.reading == r.as_ref())
             .map(|i| i.count)
     }
+
+    /// Returns the share of counted words using `r` as value between 0 and 1. `None` if the
+    /// reading is unknown or no words have been counted
+    #[inline]
+    pub fn reading_share<S: AsRef<str>>(&self, r: S) -> Option<f32> {
+        if self.total == 0 {
+            return None;
+        }
+        Some(self.get_reading(r)? as f32 / self.total as f32)
+    }
 }
 
 impl FrequencyIndex {
@@ -9,4 +9,8 @@ pub mod sentences;
 pub mod storage;
 pub mod words;
 
-pub use storage::{get, suggestions::get_suggestions};
+pub use storage::{
+    get,
+    suggestions::{get_suggestions, try_get_suggestions},
+    try_get,
+};
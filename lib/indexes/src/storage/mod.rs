@@ -7,7 +7,11 @@ pub(crate) mod utils;
 pub mod word;
 
 use once_cell::sync::OnceCell;
-use std::{error::Error, path::Path};
+use std::{
+    error::Error,
+    path::Path,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 use {
     kanji::KanjiStore, name::NameStore, radical::RadicalStore, sentence::SentenceStore,
     word::WordStore,
@@ -16,66 +20,120 @@ use {
 /// In-memory store for all indexes
 pub(crate) static INDEX_STORE: OnceCell<IndexStore> = OnceCell::new();
 
-/// Store for all indexes
+/// Amount of times the indexes were (re)loaded
+static GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+/// A part of the indexes which can be loaded on its own
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndexPart {
+    Word,
+    Sentence,
+    Name,
+    Radical,
+    Kanji,
+}
+
+impl IndexPart {
+    pub const ALL: &'static [IndexPart] = &[
+        IndexPart::Word,
+        IndexPart::Sentence,
+        IndexPart::Name,
+        IndexPart::Radical,
+        IndexPart::Kanji,
+    ];
+}
+
+/// Store for all indexes. Parts which weren't loaded are `None`
 pub struct IndexStore {
-    word: WordStore,
-    sentence: SentenceStore,
-    name: NameStore,
-    radical: RadicalStore,
-    kanji: KanjiStore,
+    word: Option<WordStore>,
+    sentence: Option<SentenceStore>,
+    name: Option<NameStore>,
+    radical: Option<RadicalStore>,
+    kanji: Option<KanjiStore>,
 }
 
 impl IndexStore {
     #[inline(always)]
     pub fn word(&self) -> &WordStore {
-        &self.word
+        self.word.as_ref().expect("Word index not loaded")
     }
 
     #[inline(always)]
     pub fn sentence(&self) -> &SentenceStore {
-        &self.sentence
+        self.sentence.as_ref().expect("Sentence index not loaded")
     }
 
     #[inline(always)]
     pub fn name(&self) -> &NameStore {
-        &self.name
+        self.name.as_ref().expect("Name index not loaded")
     }
 
     #[inline(always)]
     pub fn radical(&self) -> &RadicalStore {
-        &self.radical
+        self.radical.as_ref().expect("Radical index not loaded")
     }
 
     #[inline(always)]
     pub fn kanji(&self) -> &KanjiStore {
-        &self.kanji
+        self.kanji.as_ref().expect("Kanji index not loaded")
     }
 
     /// Returns `true` if all indexes are properly loaded
     pub fn check(&self) -> bool {
-        self.word.check() && self.sentence.check() && self.name.check() && self.radical.check()
+        self.kanji.is_some()
+            && self.word.as_ref().map_or(false, |i| i.check())
+            && self.sentence.as_ref().map_or(false, |i| i.check())
+            && self.name.as_ref().map_or(false, |i| i.check())
+            && self.radical.as_ref().map_or(false, |i| i.check())
+    }
+
+    /// Returns `true` if all loaded parts of the indexes are valid
+    fn check_loaded(&self) -> bool {
+        self.word.as_ref().map_or(true, |i| i.check())
+            && self.sentence.as_ref().map_or(true, |i| i.check())
+            && self.name.as_ref().map_or(true, |i| i.check())
+            && self.radical.as_ref().map_or(true, |i| i.check())
     }
 }
 
-/// Returns an IndexStore which can be used to retrieve all indexes
+/// Returns an IndexStore which can be used to retrieve all indexes. Panics if the indexes aren't
+/// loaded yet, so it may only be used where loading is guaranteed. Request handlers have to use
+/// `try_get` and report the missing data
 #[inline(always)]
 pub fn get() -> &'static IndexStore {
-    unsafe { INDEX_STORE.get_unchecked() }
+    try_get().expect("Indexes not loaded")
+}
+
+/// Returns the IndexStore or `None` if the indexes aren't loaded yet
+#[inline(always)]
+pub fn try_get() -> Option<&'static IndexStore> {
+    INDEX_STORE.get()
 }
 
 /// Loads all indexes
 pub fn load<P: AsRef<Path>>(index_folder: P) -> Result<bool, Box<dyn Error + Send + Sync>> {
+    load_parts(index_folder, IndexPart::ALL)
+}
+
+/// Loads the given parts of the indexes only. Accessing any other part of the loaded IndexStore
+/// panics, so this may only be used by tools which know the indexes they need
+pub fn load_parts<P: AsRef<Path>>(
+    index_folder: P,
+    parts: &[IndexPart],
+) -> Result<bool, Box<dyn Error + Send + Sync>> {
     if is_loaded() {
         return Ok(true);
     }
 
-    let store = load_raw(index_folder)?;
+    let store = load_raw_parts(index_folder, parts)?;
 
-    if !store.check() {
+    if !store.check_loaded() {
         return Ok(false);
     }
 
-    INDEX_STORE.set(store).ok();
+    if INDEX_STORE.set(store).is_ok() {
+        GENERATION.fetch_add(1, Ordering::AcqRel);
+    }
 
     Ok(true)
 }
@@ -84,6 +142,13 @@ pub fn is_loaded() -> bool {
     INDEX_STORE.get().is_some()
 }
 
+/// Returns 1 once the indexes are loaded and 0 before. The indexes can't be replaced after they
+/// were loaded, so this only changes once. Can be used to invalidate data derived while loading
+#[inline]
+pub fn generation() -> usize {
+    GENERATION.load(Ordering::Acquire)
+}
+
 /// Needed for tests only
 pub fn wait() {
     INDEX_STORE.wait();
@@ -92,20 +157,50 @@ pub fn wait() {
 pub fn load_raw<P: AsRef<Path>>(
     index_folder: P,
 ) -> Result<IndexStore, Box<dyn Error + Send + Sync>> {
-    log::debug!("Loading word index");
-    let word = word::load(index_folder.as_ref())?;
-
-    log::debug!("Loading sentence index");
-    let sentence = sentence::load(index_folder.as_ref())?;
-
-    log::debug!("Loading name index");
-    let name = name::load(index_folder.as_ref())?;
-
-    log::debug!("Loading radical index");
-    let radical = radical::load(index_folder.as_ref())?;
+    load_raw_parts(index_folder, IndexPart::ALL)
+}
 
-    log::debug!("Loading kanji reading frequency index");
-    let kanji = kanji::load(index_folder.as_ref())?;
+pub fn load_raw_parts<P: AsRef<Path>>(
+    index_folder: P,
+    parts: &[IndexPart],
+) -> Result<IndexStore, Box<dyn Error + Send + Sync>> {
+    let index_folder = index_folder.as_ref();
+    let has = |part| parts.contains(&part);
+
+    let word = has(IndexPart::Word)
+        .then(|| {
+            log::debug!("Loading word index");
+            word::load(index_folder)
+        })
+        .transpose()?;
+
+    let sentence = has(IndexPart::Sentence)
+        .then(|| {
+            log::debug!("Loading sentence index");
+            sentence::load(index_folder)
+        })
+        .transpose()?;
+
+    let name = has(IndexPart::Name)
+        .then(|| {
+            log::debug!("Loading name index");
+            name::load(index_folder)
+        })
+        .transpose()?;
+
+    let radical = has(IndexPart::Radical)
+        .then(|| {
+            log::debug!("Loading radical index");
+            radical::load(index_folder)
+        })
+        .transpose()?;
+
+    let kanji = has(IndexPart::Kanji)
+        .then(|| {
+            log::debug!("Loading kanji reading frequency index");
+            kanji::load(index_folder)
+        })
+        .transpose()?;
 
     Ok(IndexStore {
         word,
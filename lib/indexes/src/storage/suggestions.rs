@@ -5,7 +5,7 @@ use autocompletion::index::{basic::BasicIndex, japanese::JapaneseIndex};
 use once_cell::sync::OnceCell;
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, error::Error, path::Path};
-use types::jotoba::languages::Language;
+use types::jotoba::{indexes::hashtag::RawHashtag, languages::Language};
 
 pub const K_MEANING_NGRAM: usize = 3;
 
@@ -92,12 +92,33 @@ pub fn load_raw<P: AsRef<Path>>(
     utils::deser_file(file, "")
 }
 
-pub fn load<P: AsRef<Path>>(file: P) -> Result<bool, Box<dyn Error + Sync + Send>> {
-    let store = load_raw(file)?;
+/// Loads the suggestion store and adds the hashtags `extra_tags` which aren't part of the
+/// hashtag index
+pub fn load<P: AsRef<Path>>(
+    file: P,
+    extra_tags: Vec<(RawHashtag, Vec<String>)>,
+) -> Result<bool, Box<dyn Error + Sync + Send>> {
+    let mut store = load_raw(file)?;
+    store.hashtag.add_tags(extra_tags);
     Ok(SUGGESTION_STORE.set(store).is_ok())
 }
 
+/// Returns `true` if the suggestion store is loaded
+#[inline]
+pub fn is_loaded() -> bool {
+    SUGGESTION_STORE.get().is_some()
+}
+
+/// Returns the suggestion store. Panics if it isn't loaded yet, so it may only be used where
+/// loading is guaranteed. Request handlers have to use `try_get_suggestions` and report the
+/// missing data
 #[inline]
 pub fn get_suggestions() -> &'static SuggestionStorage {
-    unsafe { SUGGESTION_STORE.get_unchecked() }
+    try_get_suggestions().expect("Suggestions not loaded")
+}
+
+/// Returns the suggestion store or `None` if it isn't loaded yet
+#[inline]
+pub fn try_get_suggestions() -> Option<&'static SuggestionStorage> {
+    SUGGESTION_STORE.get()
 }
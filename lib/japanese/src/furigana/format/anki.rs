@@ -0,0 +1,95 @@
+use super::push_text;
+use crate::{
+    furigana::{SentencePart, SentencePartRef},
+    JapaneseExt,
+};
+
+/// Encodes sentence parts in Anki's furigana format: `日本語[にほんご]を 勉強[べんきょう]する`.
+/// Anki takes everything from the last space up to the `[` as base text, so kanji blocks
+/// are separated from preceding kana, kanji or readings by a space
+pub fn encode<'a, I>(parts: I) -> String
+where
+    I: IntoIterator<Item = SentencePartRef<'a>>,
+{
+    let mut out = String::new();
+
+    for part in parts {
+        match part.kanji {
+            Some(kanji) => {
+                let needs_space = out
+                    .chars()
+                    .last()
+                    .map_or(false, |c| c == ']' || c.is_kana() || c.is_kanji());
+                if needs_space {
+                    out.push(' ');
+                }
+                out.push_str(kanji);
+                out.push('[');
+                out.push_str(part.kana);
+                out.push(']');
+            }
+            None => out.push_str(part.kana),
+        }
+    }
+
+    out
+}
+
+/// Parses text in Anki's furigana format
+pub fn parse(input: &str) -> Vec<SentencePart> {
+    let mut out = vec![];
+    let mut text = String::new();
+    let mut chars = input.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '[' {
+            text.push(c);
+            continue;
+        }
+
+        let reading: String = chars.by_ref().take_while(|c| *c != ']').collect();
+
+        let base_start = text.rfind(' ').map(|i| i + 1).unwrap_or(0);
+        let base = text[base_start..].to_string();
+        if base.is_empty() {
+            // Brackets without base text aren't furigana
+            text.push('[');
+            text.push_str(&reading);
+            text.push(']');
+            continue;
+        }
+
+        // Remove base and its separating space from the text
+        text.truncate(base_start.saturating_sub(1));
+        push_text(&mut out, &text);
+        text.clear();
+
+        out.push(SentencePart::with_kanji(reading, base));
+    }
+
+    push_text(&mut out, &text);
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::furigana::{format::assert_parts, parse};
+    use test_case::test_case;
+
+    #[test_case("[日本語|に|ほん|ご]を[勉強|べん|きょう]する", "日[に] 本[ほん] 語[ご]を 勉[べん] 強[きょう]する"; "Simple")]
+    #[test_case("かなだけ", "かなだけ"; "Kana only")]
+    #[test_case("[漢字|かんじ]", "漢字[かんじ]"; "Kanji only")]
+    #[test_case("「[漢字|かんじ]」", "「漢字[かんじ]」"; "After punctuation")]
+    #[test_case("[日本|に|ほん][語|ご]", "日[に] 本[ほん] 語[ご]"; "Consecutive kanji")]
+    fn test_encode(furi: &str, expected: &str) {
+        assert_eq!(encode(parse::from_str(furi)), expected);
+    }
+
+    #[test_case("日本語[にほんご]を 勉強[べんきょう]する", &[("日本語", "にほんご"), ("を", ""), ("勉強", "べんきょう"), ("する", "")]; "Simple")]
+    #[test_case("かなだけ", &[("かなだけ", "")]; "Kana only")]
+    #[test_case("[かっこ]", &[("[かっこ]", "")]; "No base")]
+    fn test_parse(inp: &str, expected: &[(&str, &str)]) {
+        assert_parts(parse(inp), expected);
+    }
+}
@@ -0,0 +1,94 @@
+use super::push_text;
+use crate::{
+    furigana::{SentencePart, SentencePartRef},
+    JapaneseExt,
+};
+
+/// Encodes sentence parts in Aozora Bunko's ruby format: `｜日本語《にほんご》を｜勉強《べんきょう》する`
+pub fn encode<'a, I>(parts: I) -> String
+where
+    I: IntoIterator<Item = SentencePartRef<'a>>,
+{
+    let mut out = String::new();
+
+    for part in parts {
+        match part.kanji {
+            Some(kanji) => {
+                out.push('｜');
+                out.push_str(kanji);
+                out.push('《');
+                out.push_str(part.kana);
+                out.push('》');
+            }
+            None => out.push_str(part.kana),
+        }
+    }
+
+    out
+}
+
+/// Parses text in Aozora Bunko's ruby format. The base text starts at the last `｜` or, if
+/// omitted, covers all kanji directly in front of the reading
+pub fn parse(input: &str) -> Vec<SentencePart> {
+    let mut out = vec![];
+    let mut text = String::new();
+    let mut base_start: Option<usize> = None;
+    let mut chars = input.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '｜' => base_start = Some(text.len()),
+            '《' => {
+                let reading: String = chars.by_ref().take_while(|c| *c != '》').collect();
+                let start = base_start
+                    .take()
+                    .unwrap_or_else(|| kanji_suffix_start(&text));
+                let base = text[start..].to_string();
+                text.truncate(start);
+                push_text(&mut out, &text);
+                text.clear();
+
+                if base.is_empty() {
+                    push_text(&mut out, format!("《{reading}》"));
+                    continue;
+                }
+
+                out.push(SentencePart::with_kanji(reading, base));
+            }
+            _ => text.push(c),
+        }
+    }
+
+    push_text(&mut out, &text);
+    out
+}
+
+/// Returns the byte index at which the trailing kanji of `text` start
+fn kanji_suffix_start(text: &str) -> usize {
+    text.char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_kanji())
+        .last()
+        .map(|i| i.0)
+        .unwrap_or(text.len())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::furigana::{format::assert_parts, parse};
+    use test_case::test_case;
+
+    #[test_case("[日本語|にほんご]を[勉強|べんきょう]する", "｜日本語《にほんご》を｜勉強《べんきょう》する"; "Simple")]
+    #[test_case("かなだけ", "かなだけ"; "Kana only")]
+    fn test_encode(furi: &str, expected: &str) {
+        assert_eq!(encode(parse::from_str(furi)), expected);
+    }
+
+    #[test_case("｜日本語《にほんご》を勉強《べんきょう》する", &[("日本語", "にほんご"), ("を", ""), ("勉強", "べんきょう"), ("する", "")]; "Simple")]
+    #[test_case("お金《かね》", &[("お", ""), ("金", "かね")]; "Implicit base")]
+    #[test_case("かなだけ", &[("かなだけ", "")]; "Kana only")]
+    fn test_parse(inp: &str, expected: &[(&str, &str)]) {
+        assert_parts(parse(inp), expected);
+    }
+}
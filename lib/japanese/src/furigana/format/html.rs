@@ -0,0 +1,118 @@
+use super::push_text;
+use crate::furigana::{SentencePart, SentencePartRef};
+
+/// Encodes sentence parts as HTML using ruby tags:
+/// `<ruby>漢字<rp>(</rp><rt>かんじ</rt><rp>)</rp></ruby>`
+pub fn encode<'a, I>(parts: I) -> String
+where
+    I: IntoIterator<Item = SentencePartRef<'a>>,
+{
+    let mut out = String::new();
+
+    for part in parts {
+        match part.kanji {
+            Some(kanji) => {
+                out.push_str("<ruby>");
+                out.push_str(&escape(kanji));
+                out.push_str("<rp>(</rp><rt>");
+                out.push_str(&escape(part.kana));
+                out.push_str("</rt><rp>)</rp></ruby>");
+            }
+            None => out.push_str(&escape(part.kana)),
+        }
+    }
+
+    out
+}
+
+/// Parses HTML ruby annotated text. Supports multiple base/reading pairs within one ruby
+/// tag and ignores `<rp>` fallbacks
+pub fn parse(input: &str) -> Vec<SentencePart> {
+    let mut out = vec![];
+    let mut rest = input;
+
+    while let Some(start) = rest.find("<ruby>") {
+        push_text(&mut out, unescape(&rest[..start]));
+        rest = &rest[start + "<ruby>".len()..];
+
+        let end = rest.find("</ruby>").unwrap_or(rest.len());
+        parse_ruby(&mut out, &rest[..end]);
+        rest = &rest[(end + "</ruby>".len()).min(rest.len())..];
+    }
+
+    push_text(&mut out, unescape(rest));
+    out
+}
+
+/// Parses the content of a single ruby tag
+fn parse_ruby(out: &mut Vec<SentencePart>, content: &str) {
+    let content = strip_rp(content);
+    let mut rest = content.as_str();
+
+    while let Some(rt_start) = rest.find("<rt>") {
+        let base = unescape(&rest[..rt_start]);
+        rest = &rest[rt_start + "<rt>".len()..];
+
+        let rt_end = rest.find("</rt>").unwrap_or(rest.len());
+        let reading = unescape(&rest[..rt_end]);
+        rest = &rest[(rt_end + "</rt>".len()).min(rest.len())..];
+
+        if base.is_empty() {
+            continue;
+        }
+
+        out.push(SentencePart::with_kanji(reading, base));
+    }
+
+    push_text(out, unescape(rest));
+}
+
+/// Removes all `<rp>` tags along with their content
+fn strip_rp(content: &str) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(start) = rest.find("<rp>") {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest.find("</rp>").map(|i| i + "</rp>".len());
+        rest = &rest[end.unwrap_or(rest.len())..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+#[inline]
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[inline]
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::furigana::{format::assert_parts, parse};
+    use test_case::test_case;
+
+    #[test_case("[漢字|かんじ]です", "<ruby>漢字<rp>(</rp><rt>かんじ</rt><rp>)</rp></ruby>です"; "Simple")]
+    #[test_case("かなだけ", "かなだけ"; "Kana only")]
+    fn test_encode(furi: &str, expected: &str) {
+        assert_eq!(encode(parse::from_str(furi)), expected);
+    }
+
+    #[test_case("<ruby>漢字<rp>(</rp><rt>かんじ</rt><rp>)</rp></ruby>です", &[("漢字", "かんじ"), ("です", "")]; "Simple")]
+    #[test_case("<ruby>漢<rt>かん</rt>字<rt>じ</rt></ruby>", &[("漢", "かん"), ("字", "じ")]; "Multiple bases")]
+    #[test_case("a &lt; b", &[("a < b", "")]; "Escaped")]
+    fn test_parse(inp: &str, expected: &[(&str, &str)]) {
+        assert_parts(parse(inp), expected);
+    }
+}
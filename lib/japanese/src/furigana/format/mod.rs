@@ -0,0 +1,71 @@
+pub mod anki;
+pub mod aozora;
+pub mod html;
+
+use super::{SentencePart, SentencePartRef};
+use itertools::Itertools;
+
+/// Text formats furigana can be encoded in or parsed from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Jotobas internal format: `[漢字|かん|じ]`
+    Jotoba,
+    /// HTML ruby tags: `<ruby>漢字<rp>(</rp><rt>かんじ</rt><rp>)</rp></ruby>`
+    Html,
+    /// Anki furigana fields: `漢字[かんじ]`
+    Anki,
+    /// Aozora Bunko ruby: `｜漢字《かんじ》`
+    Aozora,
+}
+
+/// Encodes sentence parts into a furigana string of the given format
+pub fn encode<'a, I>(parts: I, format: Format) -> String
+where
+    I: IntoIterator<Item = SentencePartRef<'a>>,
+{
+    match format {
+        Format::Jotoba => parts.into_iter().map(|i| i.encode()).join(""),
+        Format::Html => html::encode(parts),
+        Format::Anki => anki::encode(parts),
+        Format::Aozora => aozora::encode(parts),
+    }
+}
+
+/// Parses a furigana string of the given format into its sentence parts
+pub fn parse(input: &str, format: Format) -> Vec<SentencePart> {
+    match format {
+        Format::Jotoba => super::parse::from_str(input)
+            .map(|i| i.to_owned())
+            .collect(),
+        Format::Html => html::parse(input),
+        Format::Anki => anki::parse(input),
+        Format::Aozora => aozora::parse(input),
+    }
+}
+
+/// Asserts that `parts` equal `expected`, given as `(kanji or kana, kana)` pairs. The second
+/// item of kana only parts is empty
+#[cfg(test)]
+fn assert_parts(parts: Vec<SentencePart>, expected: &[(&str, &str)]) {
+    let parts = parts
+        .into_iter()
+        .map(|i| match i.kanji {
+            Some(k) => (k, i.kana),
+            None => (i.kana, String::new()),
+        })
+        .collect::<Vec<_>>();
+    let expected = expected
+        .iter()
+        .map(|(a, b)| (a.to_string(), b.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(parts, expected);
+}
+
+/// Pushes `text` as kana only part to `out` if it's not empty
+#[inline]
+fn push_text<S: AsRef<str>>(out: &mut Vec<SentencePart>, text: S) {
+    let text = text.as_ref();
+    if !text.is_empty() {
+        out.push(SentencePart::new(text.to_string()));
+    }
+}
@@ -1,3 +1,4 @@
+pub mod format;
 pub mod generate;
 pub mod parse;
 mod tests;
@@ -2,6 +2,8 @@ pub mod compress;
 pub mod furigana;
 pub mod guessing;
 pub mod radicals;
+pub mod romaji;
+pub mod transcription;
 
 use itertools::Itertools;
 use std::{iter, ops::Range};
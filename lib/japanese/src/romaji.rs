@@ -0,0 +1,739 @@
+use itertools::Itertools;
+
+/// Romanization systems
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum System {
+    /// Modified Hepburn: し -> shi, ち -> chi, つ -> tsu, を -> o
+    #[default]
+    Hepburn,
+    /// Kunrei-shiki: し -> si, ち -> ti, つ -> tu, ぢ -> zi
+    Kunrei,
+    /// Nihon-shiki: like Kunrei-shiki but keeps ぢ -> di, づ -> du and を -> wo
+    Nihon,
+}
+
+/// How long vowels (おう, うう, ー, ..) should be written
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LongVowels {
+    /// Tōkyō
+    #[default]
+    Macron,
+    /// Tôkyô
+    Circumflex,
+    /// Toukyou
+    Spelled,
+}
+
+/// Romanization options
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct Options {
+    pub system: System,
+    pub long_vowels: LongVowels,
+}
+
+impl Options {
+    #[inline]
+    pub fn new(system: System, long_vowels: LongVowels) -> Self {
+        Self {
+            system,
+            long_vowels,
+        }
+    }
+}
+
+/// Romanizes kana text. Non kana characters are kept as they are, except for japanese
+/// punctuation which gets replaced with its latin equivalent. The text is treated as a single
+/// morpheme, use [`romanize_segments`] if the morpheme boundaries are known
+pub fn romanize(kana: &str, options: Options) -> String {
+    romanize_segments([kana], options)
+}
+
+/// Romanizes the kana reading of a verb. The trailing う of a verb is its ending and doesn't
+/// lengthen the vowel before (おもう -> omou)
+pub fn romanize_verb(kana: &str, options: Options) -> String {
+    match kana.strip_suffix('う').or_else(|| kana.strip_suffix('ウ')) {
+        Some(stem) => romanize_segments([stem, "う"], options),
+        None => romanize(kana, options),
+    }
+}
+
+/// Romanizes kana text split into morphemes. Vowels are only merged into long vowels within a
+/// single segment, so a segment boundary keeps eg. the o and u of お|う separate
+pub fn romanize_segments<'a, I>(segments: I, options: Options) -> String
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut out = String::new();
+    // Whether the last syllable was a ん
+    let mut after_n = false;
+    // Whether the last syllable was a っ
+    let mut sokuon = false;
+
+    for segment in segments {
+        let chars: Vec<char> = segment.chars().map(to_hiragana).collect();
+        out.reserve(segment.len() * 2);
+
+        // The last kana syllable of the current segment
+        let mut last: Option<&str> = None;
+
+        let mut pos = 0;
+        while pos < chars.len() {
+            let c = chars[pos];
+
+            if c == 'っ' {
+                if sokuon {
+                    // っっ
+                    out.push('\'');
+                }
+                sokuon = true;
+                after_n = false;
+                last = None;
+                pos += 1;
+                continue;
+            }
+
+            if c == 'ー' {
+                if let Some(vowel) = out.chars().last().filter(|i| is_vowel(*i)) {
+                    lengthen(&mut out, vowel, options.long_vowels);
+                }
+                pos += 1;
+                continue;
+            }
+
+            // Try digraphs first
+            let found = chars
+                .get(pos + 1)
+                .and_then(|next| {
+                    let s: String = [c, *next].iter().collect();
+                    syllable(&s, options.system).map(|i| (i, 2))
+                })
+                .or_else(|| syllable(c.encode_utf8(&mut [0; 4]), options.system).map(|i| (i, 1)));
+
+            let syllable = match found {
+                Some((s, len)) => {
+                    pos += len;
+                    s
+                }
+                None => {
+                    if sokuon {
+                        // Glottal stop, eg. あっ!
+                        out.push('\'');
+                    }
+                    out.push_str(&punctuation(c));
+                    after_n = false;
+                    sokuon = false;
+                    last = None;
+                    pos += 1;
+                    continue;
+                }
+            };
+
+            let first = syllable.chars().next().unwrap_or_default();
+
+            if after_n && (is_vowel(first) || first == 'y') {
+                out.push('\'');
+            }
+
+            if sokuon {
+                if syllable.starts_with("ch") && options.system == System::Hepburn {
+                    out.push('t');
+                } else if !is_vowel(first) {
+                    out.push(first);
+                }
+                sokuon = false;
+            }
+
+            let long = options.long_vowels != LongVowels::Spelled && is_long_vowel(last, syllable);
+            if let Some(vowel) = out.chars().last().filter(|_| long) {
+                lengthen(&mut out, vowel, options.long_vowels);
+            } else {
+                out.push_str(syllable);
+            }
+
+            after_n = syllable == "n";
+            last = Some(syllable);
+        }
+    }
+
+    // A trailing っ is a glottal stop
+    if sokuon {
+        out.push('\'');
+    }
+
+    out
+}
+
+/// Max amount of kana variants returned by [`kana_variants`]
+pub const MAX_VARIANTS: usize = 12;
+
+/// Max amount of vowels getting lengthened within a single variant
+const MAX_LENGTHENED: usize = 2;
+
+/// Relevance multiplier for each lengthened vowel of a variant
+const LENGTHENED_PENALTY: f32 = 0.9;
+
+/// A plausible kana spelling of a romaji input
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KanaVariant {
+    pub kana: String,
+    /// Amount of vowels lengthened compared to the input
+    pub lengthened: usize,
+}
+
+impl KanaVariant {
+    #[inline]
+    fn new(kana: String, lengthened: usize) -> Self {
+        Self { kana, lengthened }
+    }
+
+    /// Returns the relevance weight of the variant. Exact-length variants have a weight of 1.0
+    #[inline]
+    pub fn weight(&self) -> f32 {
+        LENGTHENED_PENALTY.powi(self.lengthened as i32)
+    }
+}
+
+/// A single syllable of parsed romaji input
+struct RomajiSyllable {
+    kana: &'static str,
+    /// Vowel of the syllable
+    vowel: Option<char>,
+    /// `true` if the vowel was explicitly marked long using a macron or circumflex
+    long: bool,
+}
+
+/// Converts romaji input of any supported system into hiragana. Long vowels written with macrons
+/// or circumflexes are spelled out (ō -> おう). Falls back to [`crate::to_hira_fmt`] for input
+/// which can't be parsed
+pub fn to_kana(romaji: &str) -> String {
+    kana_variants(romaji)
+        .into_iter()
+        .next()
+        .map(|i| i.kana)
+        .unwrap_or_else(|| crate::to_hira_fmt(romaji))
+}
+
+/// Expands a romaji query into all plausible kana spellings. Since long vowels are often not
+/// written in romaji ("tokyo" -> とうきょう), variants with lengthened o and u sounds are added.
+/// Variants are ordered by the amount of lengthened vowels, the exact-length spelling coming first
+pub fn kana_variants(romaji: &str) -> Vec<KanaVariant> {
+    let syllables = match parse_romaji(romaji) {
+        Some(s) if !s.is_empty() => s,
+        _ => return vec![KanaVariant::new(crate::to_hira_fmt(romaji), 0)],
+    };
+
+    // Positions which can be lengthened optionally along with their possible extensions
+    let optional: Vec<(usize, &[&str])> = syllables
+        .iter()
+        .enumerate()
+        .filter(|(pos, s)| !s.long && !is_lengthened(&syllables, *pos))
+        .filter_map(|(pos, s)| match s.vowel? {
+            'o' => Some((pos, &["う", "お"][..])),
+            'u' => Some((pos, &["う"][..])),
+            _ => None,
+        })
+        .collect();
+
+    let mut out = vec![];
+
+    for count in 0..=MAX_LENGTHENED.min(optional.len()) {
+        for positions in optional.iter().combinations(count) {
+            // `multi_cartesian_product` doesn't yield anything for zero iterators
+            let extensions: Vec<Vec<&&str>> = if positions.is_empty() {
+                vec![vec![]]
+            } else {
+                let ext_iter = positions.iter().map(|i| i.1.iter());
+                ext_iter.multi_cartesian_product().collect()
+            };
+
+            for ext in extensions {
+                if out.len() >= MAX_VARIANTS {
+                    return out;
+                }
+
+                let lengthen = |pos: usize| {
+                    positions
+                        .iter()
+                        .zip(ext.iter())
+                        .find(|(p, _)| p.0 == pos)
+                        .map(|i| **i.1)
+                };
+
+                let kana = syllables
+                    .iter()
+                    .enumerate()
+                    .map(|(pos, s)| {
+                        let ext = if s.long {
+                            long_vowel_kana(s.vowel)
+                        } else {
+                            lengthen(pos).unwrap_or("")
+                        };
+                        format!("{}{ext}", s.kana)
+                    })
+                    .collect::<String>();
+
+                if !out.iter().any(|i: &KanaVariant| i.kana == kana) {
+                    out.push(KanaVariant::new(kana, count));
+                }
+            }
+        }
+    }
+
+    out
+}
+
+/// Returns `true` if the vowel of the syllable at `pos` is already lengthened by a surrounding
+/// vowel-only syllable (おお, おう, ..)
+fn is_lengthened(syllables: &[RomajiSyllable], pos: usize) -> bool {
+    let is_bare_vowel = |s: &RomajiSyllable| matches!(s.kana, "あ" | "い" | "う" | "え" | "お");
+
+    let followed = syllables.get(pos + 1).map_or(false, is_bare_vowel);
+    let preceded = pos > 0 && is_bare_vowel(&syllables[pos]) && syllables[pos - 1].vowel.is_some();
+
+    followed || preceded
+}
+
+/// Returns the kana spelling out a long `vowel`
+fn long_vowel_kana(vowel: Option<char>) -> &'static str {
+    match vowel {
+        Some('a') => "あ",
+        Some('i') | Some('e') => "い",
+        Some('u') | Some('o') => "う",
+        _ => "",
+    }
+}
+
+/// Parses romaji written in Hepburn, Kunrei-shiki or Nihon-shiki into kana syllables. Returns
+/// `None` if the input contains anything else than romaji
+fn parse_romaji(romaji: &str) -> Option<Vec<RomajiSyllable>> {
+    // Unify long vowel marks
+    let mut long_marks = vec![];
+    let chars: Vec<char> = romaji
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| {
+            let (c, long) = match c {
+                'ā' | 'â' => ('a', true),
+                'ī' | 'î' => ('i', true),
+                'ū' | 'û' => ('u', true),
+                'ē' | 'ê' => ('e', true),
+                'ō' | 'ô' => ('o', true),
+                _ => (c, false),
+            };
+            long_marks.push(long);
+            c
+        })
+        .collect();
+
+    let mut out = vec![];
+    let mut pos = 0;
+
+    while pos < chars.len() {
+        let c = chars[pos];
+        let next = chars.get(pos + 1).copied();
+
+        if c == '-' || c == 'ー' {
+            out.push(RomajiSyllable::plain("ー"));
+            pos += 1;
+            continue;
+        }
+
+        if c == 'n' {
+            match next {
+                Some('\'') => {
+                    out.push(RomajiSyllable::plain("ん"));
+                    pos += 2;
+                    continue;
+                }
+                // "nn" not followed by a vowel
+                Some('n')
+                    if !chars
+                        .get(pos + 2)
+                        .map_or(false, |i| is_vowel(*i) || *i == 'y') =>
+                {
+                    out.push(RomajiSyllable::plain("ん"));
+                    pos += 2;
+                    continue;
+                }
+                Some(n) if is_vowel(n) || n == 'y' => (),
+                _ => {
+                    out.push(RomajiSyllable::plain("ん"));
+                    pos += 1;
+                    continue;
+                }
+            }
+        }
+
+        // Doubled consonants and "tch" are written with a small tsu
+        if !is_vowel(c) && c != 'n' && (next == Some(c) || (c == 't' && next == Some('c'))) {
+            out.push(RomajiSyllable::plain("っ"));
+            pos += 1;
+            continue;
+        }
+
+        let (kana, len) = (1..=3)
+            .rev()
+            .filter(|len| pos + len <= chars.len())
+            .find_map(|len| {
+                let s: String = chars[pos..pos + len].iter().collect();
+                romaji_syllable(&s).map(|kana| (kana, len))
+            })?;
+
+        let long = long_marks[pos..pos + len].iter().any(|i| *i);
+        let vowel = chars[pos + len - 1];
+        out.push(RomajiSyllable {
+            kana,
+            vowel: is_vowel(vowel).then(|| vowel),
+            long,
+        });
+        pos += len;
+    }
+
+    Some(out)
+}
+
+impl RomajiSyllable {
+    #[inline]
+    fn plain(kana: &'static str) -> Self {
+        Self {
+            kana,
+            vowel: None,
+            long: false,
+        }
+    }
+}
+
+/// Returns the kana of a single romaji syllable written in any of the supported systems
+fn romaji_syllable(romaji: &str) -> Option<&'static str> {
+    let alias = match romaji {
+        "jya" => Some("じゃ"),
+        "jyu" => Some("じゅ"),
+        "jyo" => Some("じょ"),
+        "wi" => Some("うぃ"),
+        "we" => Some("うぇ"),
+        _ => None,
+    };
+
+    alias.or_else(|| {
+        SYLLABLES
+            .iter()
+            .find(|i| i.1 == romaji || i.2 == romaji || i.3 == romaji)
+            .map(|i| i.0)
+    })
+}
+
+/// Uppercases the first letter of each word in `s`
+pub fn capitalize(s: &str) -> String {
+    s.split(' ')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns `true` if `syllable` extends the vowel of `last` to a long vowel. えい and いい are
+/// not treated as long vowels as they're spelled out in all systems
+fn is_long_vowel(last: Option<&str>, syllable: &str) -> bool {
+    let last_vowel = match last.and_then(|i| i.chars().last()) {
+        Some(l) => l,
+        None => return false,
+    };
+
+    matches!(
+        (last_vowel, syllable),
+        ('a', "a") | ('u', "u") | ('e', "e") | ('o', "o") | ('o', "u")
+    )
+}
+
+/// Replaces the trailing vowel of `out` with its long form
+fn lengthen(out: &mut String, vowel: char, style: LongVowels) {
+    let long = match style {
+        LongVowels::Spelled => {
+            out.push(vowel);
+            return;
+        }
+        LongVowels::Macron => match vowel {
+            'a' => 'ā',
+            'i' => 'ī',
+            'u' => 'ū',
+            'e' => 'ē',
+            'o' => 'ō',
+            _ => return,
+        },
+        LongVowels::Circumflex => match vowel {
+            'a' => 'â',
+            'i' => 'î',
+            'u' => 'û',
+            'e' => 'ê',
+            'o' => 'ô',
+            _ => return,
+        },
+    };
+
+    if out.ends_with(vowel) {
+        out.pop();
+    }
+    out.push(long);
+}
+
+#[inline]
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'i' | 'u' | 'e' | 'o')
+}
+
+/// Converts katakana into hiragana. Other characters are returned unchanged
+#[inline]
+fn to_hiragana(c: char) -> char {
+    if ('\u{30A1}'..='\u{30F6}').contains(&c) {
+        char::from_u32(c as u32 - 0x60).unwrap_or(c)
+    } else {
+        c
+    }
+}
+
+fn punctuation(c: char) -> String {
+    match c {
+        '。' => ".".to_string(),
+        '、' => ",".to_string(),
+        '！' => "!".to_string(),
+        '？' => "?".to_string(),
+        '・' | '　' => " ".to_string(),
+        '「' | '」' | '『' | '』' => "\"".to_string(),
+        _ => c.to_string(),
+    }
+}
+
+/// Kana syllables along with their Hepburn, Kunrei-shiki and Nihon-shiki romanization. Ordered by
+/// priority for parsing romaji: if multiple syllables share a romanization, the first one wins
+const SYLLABLES: &[(&str, &str, &str, &str)] = &[
+    ("あ", "a", "a", "a"),
+    ("い", "i", "i", "i"),
+    ("う", "u", "u", "u"),
+    ("え", "e", "e", "e"),
+    ("お", "o", "o", "o"),
+    ("か", "ka", "ka", "ka"),
+    ("き", "ki", "ki", "ki"),
+    ("く", "ku", "ku", "ku"),
+    ("け", "ke", "ke", "ke"),
+    ("こ", "ko", "ko", "ko"),
+    ("が", "ga", "ga", "ga"),
+    ("ぎ", "gi", "gi", "gi"),
+    ("ぐ", "gu", "gu", "gu"),
+    ("げ", "ge", "ge", "ge"),
+    ("ご", "go", "go", "go"),
+    ("さ", "sa", "sa", "sa"),
+    ("し", "shi", "si", "si"),
+    ("す", "su", "su", "su"),
+    ("せ", "se", "se", "se"),
+    ("そ", "so", "so", "so"),
+    ("ざ", "za", "za", "za"),
+    ("じ", "ji", "zi", "zi"),
+    ("ず", "zu", "zu", "zu"),
+    ("ぜ", "ze", "ze", "ze"),
+    ("ぞ", "zo", "zo", "zo"),
+    ("た", "ta", "ta", "ta"),
+    ("ち", "chi", "ti", "ti"),
+    ("つ", "tsu", "tu", "tu"),
+    ("て", "te", "te", "te"),
+    ("と", "to", "to", "to"),
+    ("だ", "da", "da", "da"),
+    ("ぢ", "ji", "zi", "di"),
+    ("づ", "zu", "zu", "du"),
+    ("で", "de", "de", "de"),
+    ("ど", "do", "do", "do"),
+    ("な", "na", "na", "na"),
+    ("に", "ni", "ni", "ni"),
+    ("ぬ", "nu", "nu", "nu"),
+    ("ね", "ne", "ne", "ne"),
+    ("の", "no", "no", "no"),
+    ("は", "ha", "ha", "ha"),
+    ("ひ", "hi", "hi", "hi"),
+    ("ふ", "fu", "hu", "hu"),
+    ("へ", "he", "he", "he"),
+    ("ほ", "ho", "ho", "ho"),
+    ("ば", "ba", "ba", "ba"),
+    ("び", "bi", "bi", "bi"),
+    ("ぶ", "bu", "bu", "bu"),
+    ("べ", "be", "be", "be"),
+    ("ぼ", "bo", "bo", "bo"),
+    ("ぱ", "pa", "pa", "pa"),
+    ("ぴ", "pi", "pi", "pi"),
+    ("ぷ", "pu", "pu", "pu"),
+    ("ぺ", "pe", "pe", "pe"),
+    ("ぽ", "po", "po", "po"),
+    ("ま", "ma", "ma", "ma"),
+    ("み", "mi", "mi", "mi"),
+    ("む", "mu", "mu", "mu"),
+    ("め", "me", "me", "me"),
+    ("も", "mo", "mo", "mo"),
+    ("や", "ya", "ya", "ya"),
+    ("ゆ", "yu", "yu", "yu"),
+    ("よ", "yo", "yo", "yo"),
+    ("ら", "ra", "ra", "ra"),
+    ("り", "ri", "ri", "ri"),
+    ("る", "ru", "ru", "ru"),
+    ("れ", "re", "re", "re"),
+    ("ろ", "ro", "ro", "ro"),
+    ("わ", "wa", "wa", "wa"),
+    ("ゐ", "i", "i", "wi"),
+    ("ゑ", "e", "e", "we"),
+    ("を", "o", "o", "wo"),
+    ("ん", "n", "n", "n"),
+    ("ゔ", "vu", "vu", "vu"),
+    ("きゃ", "kya", "kya", "kya"),
+    ("きゅ", "kyu", "kyu", "kyu"),
+    ("きょ", "kyo", "kyo", "kyo"),
+    ("ぎゃ", "gya", "gya", "gya"),
+    ("ぎゅ", "gyu", "gyu", "gyu"),
+    ("ぎょ", "gyo", "gyo", "gyo"),
+    ("しゃ", "sha", "sya", "sya"),
+    ("しゅ", "shu", "syu", "syu"),
+    ("しょ", "sho", "syo", "syo"),
+    ("じゃ", "ja", "zya", "zya"),
+    ("じゅ", "ju", "zyu", "zyu"),
+    ("じょ", "jo", "zyo", "zyo"),
+    ("ちゃ", "cha", "tya", "tya"),
+    ("ちゅ", "chu", "tyu", "tyu"),
+    ("ちょ", "cho", "tyo", "tyo"),
+    ("ぢゃ", "ja", "zya", "dya"),
+    ("ぢゅ", "ju", "zyu", "dyu"),
+    ("ぢょ", "jo", "zyo", "dyo"),
+    ("にゃ", "nya", "nya", "nya"),
+    ("にゅ", "nyu", "nyu", "nyu"),
+    ("にょ", "nyo", "nyo", "nyo"),
+    ("ひゃ", "hya", "hya", "hya"),
+    ("ひゅ", "hyu", "hyu", "hyu"),
+    ("ひょ", "hyo", "hyo", "hyo"),
+    ("びゃ", "bya", "bya", "bya"),
+    ("びゅ", "byu", "byu", "byu"),
+    ("びょ", "byo", "byo", "byo"),
+    ("ぴゃ", "pya", "pya", "pya"),
+    ("ぴゅ", "pyu", "pyu", "pyu"),
+    ("ぴょ", "pyo", "pyo", "pyo"),
+    ("みゃ", "mya", "mya", "mya"),
+    ("みゅ", "myu", "myu", "myu"),
+    ("みょ", "myo", "myo", "myo"),
+    ("りゃ", "rya", "rya", "rya"),
+    ("りゅ", "ryu", "ryu", "ryu"),
+    ("りょ", "ryo", "ryo", "ryo"),
+    // Extended katakana combinations (not covered by Kunrei-/Nihon-shiki)
+    ("しぇ", "she", "sye", "sye"),
+    ("じぇ", "je", "zye", "zye"),
+    ("ちぇ", "che", "tye", "tye"),
+    ("つぁ", "tsa", "tsa", "tsa"),
+    ("つぇ", "tse", "tse", "tse"),
+    ("つぉ", "tso", "tso", "tso"),
+    ("てぃ", "ti", "ti", "ti"),
+    ("でぃ", "di", "di", "di"),
+    ("とぅ", "tu", "tu", "tu"),
+    ("どぅ", "du", "du", "du"),
+    ("ふぁ", "fa", "fa", "fa"),
+    ("ふぃ", "fi", "fi", "fi"),
+    ("ふぇ", "fe", "fe", "fe"),
+    ("ふぉ", "fo", "fo", "fo"),
+    ("うぃ", "wi", "wi", "wi"),
+    ("うぇ", "we", "we", "we"),
+    ("うぉ", "wo", "wo", "wo"),
+    ("ゔぁ", "va", "va", "va"),
+    ("ゔぃ", "vi", "vi", "vi"),
+    ("ゔぇ", "ve", "ve", "ve"),
+    ("ゔぉ", "vo", "vo", "vo"),
+    // Small kana
+    ("ぁ", "a", "a", "a"),
+    ("ぃ", "i", "i", "i"),
+    ("ぅ", "u", "u", "u"),
+    ("ぇ", "e", "e", "e"),
+    ("ぉ", "o", "o", "o"),
+    ("ゃ", "ya", "ya", "ya"),
+    ("ゅ", "yu", "yu", "yu"),
+    ("ょ", "yo", "yo", "yo"),
+    ("ゎ", "wa", "wa", "wa"),
+];
+
+/// Returns the romanization of a single (hiragana) syllable
+fn syllable(kana: &str, system: System) -> Option<&'static str> {
+    let (_, hepburn, kunrei, nihon) = SYLLABLES.iter().find(|i| i.0 == kana)?;
+
+    Some(match system {
+        System::Hepburn => hepburn,
+        System::Kunrei => kunrei,
+        System::Nihon => nihon,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("とうきょう", System::Hepburn, LongVowels::Macron, "tōkyō"; "Hepburn macron")]
+    #[test_case("とうきょう", System::Hepburn, LongVowels::Spelled, "toukyou"; "Hepburn spelled")]
+    #[test_case("とうきょう", System::Kunrei, LongVowels::Circumflex, "tôkyô"; "Kunrei circumflex")]
+    #[test_case("しんぶん", System::Hepburn, LongVowels::Macron, "shinbun"; "Shinbun")]
+    #[test_case("しんぶん", System::Kunrei, LongVowels::Macron, "sinbun"; "Shinbun kunrei")]
+    #[test_case("きんえん", System::Hepburn, LongVowels::Macron, "kin'en"; "N before vowel")]
+    #[test_case("こんや", System::Hepburn, LongVowels::Macron, "kon'ya"; "N before y")]
+    #[test_case("きって", System::Hepburn, LongVowels::Macron, "kitte"; "Sokuon")]
+    #[test_case("まっちゃ", System::Hepburn, LongVowels::Macron, "matcha"; "Sokuon ch")]
+    #[test_case("まっちゃ", System::Kunrei, LongVowels::Macron, "mattya"; "Sokuon kunrei")]
+    #[test_case("せんせい", System::Hepburn, LongVowels::Macron, "sensei"; "Ei")]
+    #[test_case("おかあさん", System::Hepburn, LongVowels::Macron, "okāsan"; "Long a")]
+    #[test_case("コーヒー", System::Hepburn, LongVowels::Macron, "kōhī"; "Katakana")]
+    #[test_case("ちぢむ", System::Nihon, LongVowels::Macron, "tidimu"; "Nihon")]
+    #[test_case("ちぢむ", System::Kunrei, LongVowels::Macron, "tizimu"; "Kunrei")]
+    #[test_case("あっ", System::Hepburn, LongVowels::Macron, "a'"; "Trailing sokuon")]
+    #[test_case("あっ！", System::Hepburn, LongVowels::Macron, "a'!"; "Sokuon before punctuation")]
+    fn test_romanize(inp: &str, system: System, long: LongVowels, expected: &str) {
+        assert_eq!(romanize(inp, Options::new(system, long)), expected);
+    }
+
+    #[test_case("おもう", "omou"; "Omou")]
+    #[test_case("すう", "suu"; "Suu")]
+    #[test_case("いう", "iu"; "Iu")]
+    #[test_case("ならう", "narau"; "Narau")]
+    #[test_case("する", "suru"; "No u ending")]
+    fn test_romanize_verb(inp: &str, expected: &str) {
+        assert_eq!(romanize_verb(inp, Options::default()), expected);
+    }
+
+    #[test]
+    fn test_romanize_segments() {
+        let options = Options::default();
+        assert_eq!(romanize_segments(["こう", "こう"], options), "kōkō");
+        assert_eq!(romanize_segments(["おも", "う"], options), "omou");
+        assert_eq!(romanize_segments(["きん", "えん"], options), "kin'en");
+        assert_eq!(romanize_segments(["きっ", "て"], options), "kitte");
+    }
+
+    #[test_case("tokyo", "とうきょう"; "Unmarked long vowels")]
+    #[test_case("kyoto", "きょうと"; "Kyoto")]
+    #[test_case("gakko", "がっこう"; "Sokuon")]
+    fn test_kana_variants_contain(inp: &str, expected: &str) {
+        let variants = kana_variants(inp);
+        assert!(variants.iter().any(|i| i.kana == expected));
+        assert_eq!(variants[0].lengthened, 0);
+    }
+
+    #[test_case("tōkyō", "とうきょう"; "Macron")]
+    #[test_case("tôkyô", "とうきょう"; "Circumflex")]
+    #[test_case("tyo", "ちょ"; "Kunrei")]
+    #[test_case("si", "し"; "Kunrei si")]
+    #[test_case("kin'en", "きんえん"; "N apostrophe")]
+    #[test_case("kinen", "きねん"; "N before vowel")]
+    #[test_case("konnichiwa", "こんにちわ"; "Double n")]
+    #[test_case("matcha", "まっちゃ"; "Tch")]
+    fn test_to_kana(inp: &str, expected: &str) {
+        assert_eq!(to_kana(inp), expected);
+    }
+
+    #[test]
+    fn test_capitalize() {
+        assert_eq!(capitalize("tōkyō eki"), "Tōkyō Eki");
+    }
+}
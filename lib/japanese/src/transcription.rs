@@ -0,0 +1,674 @@
+//! Transcription of names written in latin script into katakana, eg. "Schmidt" → シュミット.
+//!
+//! A name is first rewritten into a phonetic (romaji like) representation using pronunciation
+//! rules of a given language. This representation is then split into morae and converted to
+//! katakana, inserting vowels after consonants that don't have one.
+
+/// Max amount of candidates returned by [`transcribe`]
+pub const MAX_CANDIDATES: usize = 6;
+
+/// Language whose pronunciation rules are used to transcribe a name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SourceLanguage {
+    #[default]
+    English,
+    German,
+    French,
+    Spanish,
+}
+
+impl SourceLanguage {
+    pub const ALL: [SourceLanguage; 4] = [
+        SourceLanguage::English,
+        SourceLanguage::German,
+        SourceLanguage::French,
+        SourceLanguage::Spanish,
+    ];
+
+    #[inline]
+    fn rules(&self) -> &'static [Rule] {
+        match self {
+            SourceLanguage::English => ENGLISH,
+            SourceLanguage::German => GERMAN,
+            SourceLanguage::French => FRENCH,
+            SourceLanguage::Spanish => SPANISH,
+        }
+    }
+}
+
+/// A rule rewriting a group of letters into its pronunciation. The first pronunciation is the
+/// preferred one. Patterns may start with `^` (start of the word) and end with `$` (end of the
+/// word), `>` (followed by a vowel), `!` (not followed by a vowel) or `~` (followed by e, i or y)
+type Rule = (&'static str, &'static [&'static str]);
+
+/// Rules used for all languages if no language specific rule matches
+const BASE: &[Rule] = &[
+    ("a", &["a"]),
+    ("b", &["b"]),
+    ("c", &["k"]),
+    ("d", &["d"]),
+    ("e", &["e"]),
+    ("f", &["f"]),
+    ("g", &["g"]),
+    ("h", &["h"]),
+    ("i", &["i"]),
+    ("j", &["j"]),
+    ("k", &["k"]),
+    ("l", &["r"]),
+    ("m", &["m"]),
+    ("n", &["n"]),
+    ("o", &["o"]),
+    ("p", &["p"]),
+    ("q", &["k"]),
+    ("r", &["r"]),
+    ("s", &["s"]),
+    ("t", &["t"]),
+    ("u", &["u"]),
+    ("v", &["v", "b"]),
+    ("w", &["w"]),
+    ("x", &["ks"]),
+    ("y", &["i"]),
+    ("z", &["z"]),
+    ("á", &["a"]),
+    ("à", &["a"]),
+    ("â", &["a"]),
+    ("ä", &["e"]),
+    ("é", &["e"]),
+    ("è", &["e"]),
+    ("ê", &["e"]),
+    ("ë", &["e"]),
+    ("í", &["i"]),
+    ("ì", &["i"]),
+    ("î", &["i"]),
+    ("ï", &["i"]),
+    ("ó", &["o"]),
+    ("ò", &["o"]),
+    ("ô", &["o"]),
+    ("ö", &["e", "o"]),
+    ("ú", &["u"]),
+    ("ù", &["u"]),
+    ("û", &["u"]),
+    ("ü", &["yu"]),
+    ("ß", &["s"]),
+    ("ç", &["s"]),
+    ("ñ", &["ny"]),
+    ("bb", &["b"]),
+    ("cc", &["k"]),
+    ("dd", &["d"]),
+    ("ff", &["f"]),
+    ("gg", &["g"]),
+    ("kk", &["k"]),
+    ("ll", &["r"]),
+    ("mm>", &["m"]),
+    ("mm!", &["nm"]),
+    ("pp", &["p"]),
+    ("rr", &["r"]),
+    ("ss", &["s"]),
+    ("tt", &["t"]),
+    ("zz", &["z"]),
+    ("h$", &[""]),
+];
+
+const ENGLISH: &[Rule] = &[
+    ("tion", &["shon"]),
+    ("sch", &["sh"]),
+    ("tch", &["ch"]),
+    ("dge", &["j"]),
+    ("sh", &["sh"]),
+    ("ch", &["ch", "k"]),
+    ("ph", &["f"]),
+    ("th", &["s", "z"]),
+    ("ck", &["k"]),
+    ("qu", &["kw"]),
+    ("wh", &["w"]),
+    ("gh", &[""]),
+    ("ge$", &["j"]),
+    ("g~", &["g", "j"]),
+    ("c~", &["s"]),
+    ("ee", &["i-"]),
+    ("ea", &["i-"]),
+    ("oo", &["u-"]),
+    ("ou", &["au"]),
+    ("ow$", &["o-"]),
+    ("ew$", &["yu-"]),
+    ("ay", &["ei"]),
+    ("ai", &["ei"]),
+    ("oa", &["o-"]),
+    ("ey$", &["i-"]),
+    ("er$", &["a-"]),
+    ("or$", &["a-"]),
+    ("ar!", &["a-"]),
+    ("ir!", &["a-"]),
+    ("ur!", &["a-"]),
+    ("^y", &["y"]),
+    ("y>", &["y"]),
+    ("y$", &["i-"]),
+    ("u", &["u", "a"]),
+    ("v", &["b", "v"]),
+    ("e$", &[""]),
+];
+
+const GERMAN: &[Rule] = &[
+    ("tsch", &["ch"]),
+    ("sch", &["sh"]),
+    ("chs", &["ks"]),
+    ("^chr", &["kr"]),
+    ("ach$", &["ahha"]),
+    ("och$", &["ohha"]),
+    ("uch$", &["uhha"]),
+    ("ich$", &["ihhi"]),
+    ("ech$", &["ehhi"]),
+    ("ch", &["hi"]),
+    ("ei", &["ai"]),
+    ("ey", &["ai"]),
+    ("ai", &["ai"]),
+    ("ay", &["ai"]),
+    ("ie", &["i-"]),
+    ("eu", &["oi"]),
+    ("äu", &["oi"]),
+    ("aa", &["a-"]),
+    ("ee", &["e-"]),
+    ("oo", &["o-"]),
+    ("ah!", &["a-"]),
+    ("eh!", &["e-"]),
+    ("ih!", &["i-"]),
+    ("oh!", &["o-"]),
+    ("uh!", &["u-"]),
+    ("^sp", &["shp"]),
+    ("^st", &["sht"]),
+    ("s>", &["z", "s"]),
+    ("tz", &["ts"]),
+    ("z", &["ts"]),
+    ("w", &["v", "b"]),
+    ("v", &["f", "v"]),
+    ("j", &["y"]),
+    ("qu", &["kv"]),
+    ("ck", &["kk"]),
+    ("dt", &["t"]),
+    ("th", &["t"]),
+    ("ph", &["f"]),
+    ("tt", &["tt"]),
+    ("pp", &["pp"]),
+    ("er$", &["a-"]),
+    ("y", &["yu"]),
+];
+
+const FRENCH: &[Rule] = &[
+    ("eaux", &["o-"]),
+    ("eau", &["o-"]),
+    ("ault$", &["o-"]),
+    ("aux$", &["o-"]),
+    ("au", &["o-"]),
+    ("ou", &["u"]),
+    ("oi", &["wa"]),
+    ("ai", &["e"]),
+    ("ei", &["e"]),
+    ("eu", &["u"]),
+    ("ille$", &["iyu"]),
+    ("ie$", &["i-"]),
+    ("ch", &["sh"]),
+    ("ph", &["f"]),
+    ("th", &["t"]),
+    ("gn", &["ny"]),
+    ("qu", &["k"]),
+    ("gu~", &["g"]),
+    ("c~", &["s"]),
+    ("g~", &["j"]),
+    ("ean!", &["an"]),
+    ("ain!", &["an"]),
+    ("ein!", &["an"]),
+    ("on!", &["on"]),
+    ("om!", &["on"]),
+    ("an!", &["an"]),
+    ("am!", &["an"]),
+    ("en!", &["an"]),
+    ("em!", &["an"]),
+    ("in!", &["an"]),
+    ("un!", &["an"]),
+    ("er$", &["e"]),
+    ("ez$", &["e"]),
+    ("et$", &["e"]),
+    ("es$", &[""]),
+    ("e$", &[""]),
+    ("t$", &[""]),
+    ("d$", &[""]),
+    ("s$", &[""]),
+    ("x$", &[""]),
+    ("z$", &[""]),
+    ("p$", &[""]),
+    ("h", &[""]),
+    ("u", &["yu"]),
+    ("e", &["e", "u"]),
+    ("w", &["v"]),
+];
+
+const SPANISH: &[Rule] = &[
+    ("ll", &["y"]),
+    ("ch", &["ch"]),
+    ("güe", &["gwe"]),
+    ("güi", &["gwi"]),
+    ("gu~", &["g"]),
+    ("g~", &["h"]),
+    ("qu", &["k"]),
+    ("c~", &["s"]),
+    ("cc", &["ks"]),
+    ("j", &["h"]),
+    ("z", &["s"]),
+    ("h", &[""]),
+    ("v", &["b"]),
+    ("x", &["ks", "h"]),
+    ("y$", &["i"]),
+    ("y", &["y"]),
+];
+
+/// Returns `true` if `s` can be transcribed. This is the case for names consisting of
+/// (space separated) latin letters only
+pub fn is_transcribable(s: &str) -> bool {
+    let mut has_letter = false;
+    for c in s.chars() {
+        if c.is_whitespace() || c == '-' {
+            continue;
+        }
+        if !c.is_alphabetic() || !is_latin(c) {
+            return false;
+        }
+        has_letter = true;
+    }
+    has_letter
+}
+
+/// Transcribes a latin name into katakana. Returns up to [`MAX_CANDIDATES`] plausible
+/// transcriptions with the most likely one at first. Multiple words are separated by `・`
+pub fn transcribe(name: &str, lang: SourceLanguage) -> Vec<String> {
+    if !is_transcribable(name) {
+        return vec![];
+    }
+
+    let words = name
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|i| !i.is_empty())
+        .map(|i| transcribe_word(&i.to_lowercase(), lang))
+        .collect::<Vec<_>>();
+
+    if words.iter().any(|i| i.is_empty()) {
+        return vec![];
+    }
+
+    // Most likely transcription of each word
+    let mut out = vec![words
+        .iter()
+        .map(|i| i[0].as_str())
+        .collect::<Vec<_>>()
+        .join("・")];
+
+    // Variations of a single word
+    for (pos, word) in words.iter().enumerate() {
+        for variant in word.iter().skip(1) {
+            let candidate = words
+                .iter()
+                .enumerate()
+                .map(|(p, w)| {
+                    if p == pos {
+                        variant.as_str()
+                    } else {
+                        w[0].as_str()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("・");
+
+            if !out.contains(&candidate) {
+                out.push(candidate);
+            }
+        }
+    }
+
+    out.truncate(MAX_CANDIDATES);
+    out
+}
+
+/// Transcribes a single lowercase word into all of its candidates
+fn transcribe_word(word: &str, lang: SourceLanguage) -> Vec<String> {
+    let segments = segments(word, lang);
+
+    let default = segments.iter().map(|i| i[0]).collect::<String>();
+
+    let mut out = vec![];
+    out.extend(to_katakana(&default));
+
+    for (pos, segment) in segments.iter().enumerate() {
+        for alt in segment.iter().skip(1) {
+            let phonetic = segments
+                .iter()
+                .enumerate()
+                .map(|(p, s)| if p == pos { *alt } else { s[0] })
+                .collect::<String>();
+
+            if let Some(kana) = to_katakana(&phonetic) {
+                if !out.contains(&kana) {
+                    out.push(kana);
+                }
+            }
+        }
+    }
+
+    out
+}
+
+/// Splits a word into segments of letters with their possible pronunciations
+fn segments(word: &str, lang: SourceLanguage) -> Vec<&'static [&'static str]> {
+    let chars = word.chars().collect::<Vec<_>>();
+    let mut out = vec![];
+
+    let mut pos = 0;
+    while pos < chars.len() {
+        let lang_rule = longest_match(lang.rules(), &chars, pos);
+        let base_rule = longest_match(BASE, &chars, pos);
+
+        let (len, pron) = match (lang_rule, base_rule) {
+            (Some(l), Some(b)) if b.0 > l.0 => b,
+            (Some(l), _) => l,
+            (None, Some(b)) => b,
+            // Unknown letter
+            (None, None) => (1, &[""][..]),
+        };
+
+        out.push(pron);
+        pos += len;
+    }
+
+    out
+}
+
+/// Returns the length and pronunciations of the longest rule matching `chars` at `pos`
+fn longest_match(
+    rules: &'static [Rule],
+    chars: &[char],
+    pos: usize,
+) -> Option<(usize, &'static [&'static str])> {
+    rules
+        .iter()
+        .filter_map(|(pat, pron)| rule_matches(pat, chars, pos).map(|len| (len, *pron)))
+        .fold(None, |best, cur| match best {
+            Some(b) if b.0 >= cur.0 => Some(b),
+            _ => Some(cur),
+        })
+}
+
+/// Returns the amount of letters the pattern consumes if it matches at `pos`
+fn rule_matches(pat: &str, chars: &[char], pos: usize) -> Option<usize> {
+    let mut pat = pat;
+
+    if let Some(p) = pat.strip_prefix('^') {
+        if pos != 0 {
+            return None;
+        }
+        pat = p;
+    }
+
+    let cond = pat
+        .chars()
+        .last()
+        .filter(|c| matches!(c, '$' | '>' | '!' | '~'));
+    if cond.is_some() {
+        pat = &pat[..pat.len() - 1];
+    }
+
+    let len = pat.chars().count();
+    if pos + len > chars.len() || !pat.chars().eq(chars[pos..pos + len].iter().copied()) {
+        return None;
+    }
+
+    let next = chars.get(pos + len).copied();
+    let ok = match cond {
+        Some('$') => next.is_none(),
+        Some('>') => next.map_or(false, is_vowel),
+        Some('!') => !next.map_or(false, is_vowel),
+        Some('~') => next.map_or(false, is_front_vowel),
+        _ => true,
+    };
+
+    ok.then(|| len)
+}
+
+/// Converts a phonetic representation into katakana. Returns `None` if it can't be represented
+fn to_katakana(phonetic: &str) -> Option<String> {
+    let tokens = tokenize(phonetic)?;
+    let mut out = String::new();
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let tok = tokens[i];
+        let next = tokens.get(i + 1).copied();
+
+        if tok == "-" {
+            out.push('ー');
+            i += 1;
+            continue;
+        }
+
+        if let Some(v) = vowel(tok) {
+            out.push_str(mora("", v)?);
+            i += 1;
+            continue;
+        }
+
+        // Doubled consonant
+        if next == Some(tok) && tok != "n" && tok != "m" {
+            out.push('ッ');
+            i += 1;
+            continue;
+        }
+
+        let next_vowel = next.and_then(vowel);
+
+        // Syllable final nasal
+        if next_vowel.is_none() && next != Some("y") && tok == "n"
+            || tok == "m" && matches!(next, Some("b") | Some("p") | Some("m"))
+        {
+            out.push('ン');
+            i += 1;
+            // Skip a doubled nasal without vowel following
+            if next == Some(tok) && tokens.get(i + 1).and_then(|t| vowel(t)).is_none() {
+                i += 1;
+            }
+            continue;
+        }
+
+        // Consonant + y + vowel
+        if tok != "y" && next == Some("y") {
+            if let Some(v) = tokens.get(i + 2).and_then(|t| vowel(t)) {
+                out.push_str(&glide(tok, v)?);
+                i += 3;
+                continue;
+            }
+        }
+
+        if let Some(v) = next_vowel {
+            out.push_str(mora(tok, v)?);
+            i += 2;
+            continue;
+        }
+
+        // Consonant without vowel
+        if is_final_plosive(&tokens, i) {
+            out.push('ッ');
+        }
+        out.push_str(mora(tok, epenthetic_vowel(tok))?);
+        i += 1;
+    }
+
+    (!out.is_empty()).then(|| out)
+}
+
+/// Returns `true` if the consonant at `pos` is a plosive which starts the final consonant
+/// cluster of a word and follows a single short vowel. Those get doubled, eg. Max → マックス
+fn is_final_plosive(tokens: &[&str], pos: usize) -> bool {
+    if !matches!(tokens[pos], "k" | "t" | "p" | "d" | "g" | "ch" | "j") {
+        return false;
+    }
+
+    let rest_consonants = tokens[pos..]
+        .iter()
+        .all(|i| vowel(i).is_none() && *i != "-");
+    let short_vowel = pos >= 2
+        && vowel(tokens[pos - 1]).is_some()
+        && vowel(tokens[pos - 2]).is_none()
+        && tokens[pos - 2] != "-"
+        && tokens[pos - 2] != tokens[pos];
+
+    rest_consonants && short_vowel
+}
+
+/// Splits a phonetic representation into consonants, vowels and long vowel marks
+fn tokenize(phonetic: &str) -> Option<Vec<&str>> {
+    const TOKENS: &[&str] = &[
+        "sh", "ch", "ts", "a", "i", "u", "e", "o", "k", "g", "s", "z", "j", "t", "d", "n", "h",
+        "b", "p", "m", "y", "r", "w", "f", "v", "-",
+    ];
+
+    let mut out = vec![];
+    let mut rest = phonetic;
+    while !rest.is_empty() {
+        let tok = TOKENS.iter().find(|t| rest.starts_with(*t))?;
+        out.push(*tok);
+        rest = &rest[tok.len()..];
+    }
+    Some(out)
+}
+
+/// Vowel inserted after a consonant not followed by a vowel
+#[inline]
+fn epenthetic_vowel(consonant: &str) -> usize {
+    match consonant {
+        "t" | "d" => 4,
+        "ch" | "j" => 1,
+        _ => 2,
+    }
+}
+
+/// Returns the index of a vowel token in the order a, i, u, e, o
+#[inline]
+fn vowel(tok: &str) -> Option<usize> {
+    ["a", "i", "u", "e", "o"].iter().position(|i| *i == tok)
+}
+
+/// Returns the katakana of a consonant followed by the vowel with index `v`
+fn mora(consonant: &str, v: usize) -> Option<&'static str> {
+    let row: [&str; 5] = match consonant {
+        "" => ["ア", "イ", "ウ", "エ", "オ"],
+        "k" => ["カ", "キ", "ク", "ケ", "コ"],
+        "g" => ["ガ", "ギ", "グ", "ゲ", "ゴ"],
+        "s" => ["サ", "シ", "ス", "セ", "ソ"],
+        "z" => ["ザ", "ジ", "ズ", "ゼ", "ゾ"],
+        "sh" => ["シャ", "シ", "シュ", "シェ", "ショ"],
+        "j" => ["ジャ", "ジ", "ジュ", "ジェ", "ジョ"],
+        "t" => ["タ", "ティ", "トゥ", "テ", "ト"],
+        "d" => ["ダ", "ディ", "ドゥ", "デ", "ド"],
+        "ch" => ["チャ", "チ", "チュ", "チェ", "チョ"],
+        "ts" => ["ツァ", "ツィ", "ツ", "ツェ", "ツォ"],
+        "n" => ["ナ", "ニ", "ヌ", "ネ", "ノ"],
+        "h" => ["ハ", "ヒ", "フ", "ヘ", "ホ"],
+        "f" => ["ファ", "フィ", "フ", "フェ", "フォ"],
+        "b" => ["バ", "ビ", "ブ", "ベ", "ボ"],
+        "p" => ["パ", "ピ", "プ", "ペ", "ポ"],
+        "m" => ["マ", "ミ", "ム", "メ", "モ"],
+        "y" => ["ヤ", "イ", "ユ", "イェ", "ヨ"],
+        "r" => ["ラ", "リ", "ル", "レ", "ロ"],
+        "w" => ["ワ", "ウィ", "ウ", "ウェ", "ウォ"],
+        "v" => ["ヴァ", "ヴィ", "ヴ", "ヴェ", "ヴォ"],
+        _ => return None,
+    };
+    Some(row[v])
+}
+
+/// Returns the katakana of a consonant followed by y and the vowel with index `v`, eg. ミュ
+fn glide(consonant: &str, v: usize) -> Option<String> {
+    // Already palatalized consonants
+    if matches!(consonant, "sh" | "ch" | "j") {
+        return mora(consonant, v).map(|i| i.to_string());
+    }
+
+    let base = match consonant {
+        "t" => "テ",
+        "d" => "デ",
+        "f" => "フ",
+        "v" => "ヴ",
+        _ => mora(consonant, 1)?,
+    };
+
+    let small = match v {
+        0 => "ャ",
+        2 => "ュ",
+        3 => "ェ",
+        4 => "ョ",
+        _ => return Some(mora(consonant, 1)?.to_string()),
+    };
+
+    Some(format!("{base}{small}"))
+}
+
+#[inline]
+fn is_vowel(c: char) -> bool {
+    "aeiouyäöüáàâéèêëíìîïóòôúùû".contains(c)
+}
+
+#[inline]
+fn is_front_vowel(c: char) -> bool {
+    "eiyéèêëíìîï".contains(c)
+}
+
+#[inline]
+fn is_latin(c: char) -> bool {
+    c.is_ascii_alphabetic() || "äöüßáàâéèêëíìîïóòôúùûçñÄÖÜÁÀÂÉÈÊËÍÌÎÏÓÒÔÚÙÛÇÑ".contains(c)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("Schmidt", SourceLanguage::German, "シュミット")]
+    #[test_case("Maximilian", SourceLanguage::German, "マクシミリアン")]
+    #[test_case("Müller", SourceLanguage::German, "ミュラー")]
+    #[test_case("Bach", SourceLanguage::German, "バッハ")]
+    #[test_case("Johann", SourceLanguage::German, "ヨハン")]
+    #[test_case("Stefan", SourceLanguage::German, "シュテファン")]
+    #[test_case("Wilhelm", SourceLanguage::German, "ヴィルヘルム")]
+    #[test_case("Smith", SourceLanguage::English, "スミス")]
+    #[test_case("Max", SourceLanguage::English, "マックス")]
+    #[test_case("Henry", SourceLanguage::English, "ヘンリー")]
+    #[test_case("Taylor", SourceLanguage::English, "テイラー")]
+    #[test_case("Andrew", SourceLanguage::English, "アンドリュー")]
+    #[test_case("Laurent", SourceLanguage::French, "ローラン")]
+    #[test_case("Dupont", SourceLanguage::French, "デュポン")]
+    #[test_case("Michel", SourceLanguage::French, "ミシェル")]
+    #[test_case("García", SourceLanguage::Spanish, "ガルシア")]
+    #[test_case("José", SourceLanguage::Spanish, "ホセ")]
+    #[test_case("Juan", SourceLanguage::Spanish, "フアン")]
+    #[test_case("Max Müller", SourceLanguage::German, "マックス・ミュラー")]
+    #[test_case("Emma", SourceLanguage::English, "エマ")]
+    #[test_case("Hammer", SourceLanguage::German, "ハマー")]
+    fn test_transcribe(name: &str, lang: SourceLanguage, exp: &str) {
+        assert_eq!(
+            transcribe(name, lang).first().map(|i| i.as_str()),
+            Some(exp)
+        );
+    }
+
+    #[test]
+    fn test_transcribe_candidates() {
+        let candidates = transcribe("Wilhelm", SourceLanguage::German);
+        assert!(candidates.contains(&"ビルヘルム".to_string()));
+        assert!(candidates.len() <= MAX_CANDIDATES);
+    }
+
+    #[test_case("Schmidt", true)]
+    #[test_case("Max Müller", true)]
+    #[test_case("シュミット", false)]
+    #[test_case("r2d2", false)]
+    #[test_case(" ", false)]
+    fn test_is_transcribable(inp: &str, exp: bool) {
+        assert_eq!(is_transcribable(inp), exp);
+    }
+}
@@ -1,9 +1,10 @@
-use strum_macros::{AsRefStr, Display, EnumString};
+use strum::IntoEnumIterator;
+use strum_macros::{AsRefStr, Display, EnumIter, EnumString};
 
 use crate::traits::Translatable;
 
 /// Supported languages for translation
-#[derive(Copy, Clone, AsRefStr, EnumString, Display, Eq, PartialEq, Hash, Debug)]
+#[derive(Copy, Clone, AsRefStr, EnumString, EnumIter, Display, Eq, PartialEq, Hash, Debug)]
 #[repr(u8)]
 pub enum Language {
     #[strum(serialize = "en", serialize = "en-US")]
@@ -28,6 +29,13 @@ pub enum Language {
     Japanese,
 }
 
+impl Language {
+    #[inline]
+    pub fn iter() -> impl Iterator<Item = Language> {
+        <Language as IntoEnumIterator>::iter()
+    }
+}
+
 impl Default for Language {
     #[inline]
     fn default() -> Self {
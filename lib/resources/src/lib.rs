@@ -9,6 +9,7 @@ use std::{
     fs::File,
     io::{BufReader, Write},
     path::Path,
+    sync::atomic::{AtomicUsize, Ordering},
 };
 
 /// Static git hash of current build
@@ -27,19 +28,27 @@ pub const REQUIRED_FEATURES: &[Feature] = &[
 /// InMemory storage for all data
 static STORAGE: OnceCell<ResourceStorage> = OnceCell::new();
 
+/// Amount of times the storage was (re)loaded
+static GENERATION: AtomicUsize = AtomicUsize::new(0);
+
 /// Lazy resource storage for tests
 pub static LAZY_STORAGE: Lazy<ResourceStorage> = Lazy::new(|| {
     let path = std::env::var("STORAGE_DATA").expect("missing STORAGE_DATA");
     load_raw(&path).expect("Failed to load test resources")
 });
 
-/// Get loaded storage data
+/// Get loaded storage data. Panics if the storage isn't loaded yet, so it may only be used where
+/// loading is guaranteed, eg. by tools loading the storage first or code running behind an
+/// `is_loaded` check. Request handlers have to use `try_get` and report the missing data
 #[inline(always)]
 pub fn get() -> &'static ResourceStorage {
-    // Safety:
-    // The STORAGE cell gets initialized once at the beginning which is absolutely necessary for
-    // the program to work. It won't be unset so its always safe
-    unsafe { STORAGE.get_unchecked() }
+    try_get().expect("Resource storage not loaded")
+}
+
+/// Get loaded storage data or `None` if the storage isn't loaded yet
+#[inline(always)]
+pub fn try_get() -> Option<&'static ResourceStorage> {
+    STORAGE.get()
 }
 
 /// Returns `true` if the storage is loaded
@@ -48,10 +57,25 @@ pub fn is_loaded() -> bool {
     STORAGE.get().is_some()
 }
 
+/// Returns 1 once the storage is loaded and 0 before. The storage can't be replaced after it was
+/// loaded, so this only changes once. Can be used to invalidate data derived while loading
+#[inline]
+pub fn generation() -> usize {
+    GENERATION.load(Ordering::Acquire)
+}
+
 /// Load the resource storage and returns it
 pub fn load_raw<P: AsRef<Path>>(path: P) -> Result<ResourceStorage, Box<dyn Error>> {
     let mut reader = BufReader::new(File::open(path)?);
-    Ok(bincode::deserialize_from(&mut reader)?)
+    let mut storage: ResourceStorage = bincode::deserialize_from(&mut reader)?;
+    storage.words.build_sense_maps();
+    storage.words.resolve_references();
+    storage.words.build_katakana_map();
+    storage.names.build_maps();
+    storage.kanji.build_phonetic_series();
+    storage.kanji.build_visual_similarity();
+    storage.words.build_irregular_readings(&storage.kanji);
+    Ok(storage)
 }
 
 /// Load the resource storage from a file. Returns `true` if it wasn't loaded before
@@ -59,7 +83,11 @@ pub fn load<P: AsRef<Path>>(path: P) -> Result<bool, Box<dyn Error>> {
     if is_loaded() {
         return Ok(true);
     }
-    Ok(STORAGE.set(load_raw(path)?).is_ok())
+    let set = STORAGE.set(load_raw(path)?).is_ok();
+    if set {
+        GENERATION.fetch_add(1, Ordering::AcqRel);
+    }
+    Ok(set)
 }
 
 /// Serializes a ResourceStorage into `output`
@@ -69,7 +97,9 @@ pub fn store<W: Write>(output: W, storage: &ResourceStorage) -> Result<(), Box<d
 }
 
 pub fn set(res_storage: ResourceStorage) {
-    STORAGE.set(res_storage).ok();
+    if STORAGE.set(res_storage).is_ok() {
+        GENERATION.fetch_add(1, Ordering::AcqRel);
+    }
 }
 
 pub fn wait() {
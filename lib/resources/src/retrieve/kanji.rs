@@ -1,6 +1,11 @@
 use ids_parser::IDS;
 use sorted_intersection::SortedIntersection;
-use types::jotoba::kanji::{radical::DetailedRadical, Kanji};
+use types::jotoba::{
+    kanji::{
+        phonetic::PhoneticSeries, radical::DetailedRadical, similarity::MIN_CONFUSABLE, Kanji,
+    },
+    words::{reading_breakdown::ReadingBreakdown, Word},
+};
 
 use super::super::storage::kanji::KanjiStorage;
 
@@ -21,6 +26,12 @@ impl<'a> KanjiRetrieve<'a> {
         self.storage.literal_index.get(literal as u32)
     }
 
+    /// Returns the per kanji reading breakdown of a word written with kanji
+    #[inline]
+    pub fn reading_breakdown(&self, word: &Word) -> Option<ReadingBreakdown> {
+        word.reading_breakdown(|lit| self.by_literal(lit))
+    }
+
     /// Returns `true` if the index has the literal
     #[inline]
     pub fn has_literal(&self, literal: char) -> bool {
@@ -79,6 +90,57 @@ impl<'a> KanjiRetrieve<'a> {
         self.storage.ids_index.get(&kanji_lit)
     }
 
+    /// Returns the phonetic series of the given component
+    #[inline]
+    pub fn phonetic_series(&self, component: char) -> Option<&'a PhoneticSeries> {
+        self.storage.phonetic_series.get(&component)
+    }
+
+    /// Returns all phonetic series `kanji_lit` is a member of
+    pub fn phonetic_series_of(&self, kanji_lit: char) -> impl Iterator<Item = &'a PhoneticSeries> {
+        let storage = self.storage;
+        storage
+            .phonetic_members
+            .get(&kanji_lit)
+            .into_iter()
+            .flatten()
+            .filter_map(move |i| storage.phonetic_series.get(i))
+    }
+
+    /// Returns all kanji visually similar to `kanji_lit`, along with their similarity score
+    #[inline]
+    pub fn visually_similar(&self, kanji_lit: char) -> &'a [(char, f32)] {
+        self.storage
+            .visual_similar
+            .get(&kanji_lit)
+            .map(|i| i.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Returns all kanji which are similar enough to `kanji_lit` to be confused with it
+    #[inline]
+    pub fn confusables(&self, kanji_lit: char) -> impl Iterator<Item = &'a (char, f32)> {
+        self.visually_similar(kanji_lit)
+            .iter()
+            .take_while(|i| i.1 >= MIN_CONFUSABLE)
+    }
+
+    /// Returns the similar kanji of the static list, followed by the generated ones
+    pub fn similar_kanji(&self, kanji_lit: char) -> Vec<char> {
+        let static_similar = self
+            .by_literal(kanji_lit)
+            .map(|i| i.similar_kanji.as_slice())
+            .unwrap_or_default();
+
+        let mut out = static_similar.to_vec();
+        for (literal, _) in self.visually_similar(kanji_lit) {
+            if !out.contains(literal) {
+                out.push(*literal);
+            }
+        }
+        out
+    }
+
     /// Returns the count of kanji
     #[inline]
     pub fn count(&self) -> usize {
@@ -1,5 +1,5 @@
 use super::super::storage::name::NameStorage;
-use types::jotoba::names::Name;
+use types::jotoba::names::{name_type::NameType, Name};
 
 #[derive(Clone, Copy)]
 pub struct NameRetrieve<'a> {
@@ -18,6 +18,40 @@ impl<'a> NameRetrieve<'a> {
         self.storage.names.get(&seq_id)
     }
 
+    /// Returns an iterator over all names of the given `name_type`
+    #[inline]
+    pub fn by_name_type<'b>(
+        &'b self,
+        name_type: NameType,
+    ) -> impl Iterator<Item = &'a Name> + 'b + DoubleEndedIterator {
+        self.storage
+            .name_type_map
+            .get(&(name_type as u8))
+            .into_iter()
+            .flatten()
+            .filter_map(move |i| self.by_sequence(*i))
+    }
+
+    /// Returns the amount of names indexed for `name_type`
+    #[inline]
+    pub fn name_type_len(&self, name_type: &NameType) -> Option<usize> {
+        self.storage
+            .name_type_map
+            .get(&(*name_type as u8))
+            .map(|i| i.len())
+    }
+
+    /// Returns an iterator over all names with the given katakana reading
+    #[inline]
+    pub fn by_katakana<'b>(&'b self, kana: &str) -> impl Iterator<Item = &'a Name> + 'b {
+        self.storage
+            .katakana_map
+            .get(kana)
+            .into_iter()
+            .flatten()
+            .filter_map(move |i| self.by_sequence(*i))
+    }
+
     /// Returns the amount of names
     #[inline]
     pub fn count(&self) -> usize {
@@ -1,5 +1,8 @@
 use super::super::storage::word::WordStorage;
-use types::jotoba::words::{misc::Misc, part_of_speech::PosSimple, Word};
+use types::jotoba::words::{
+    dialect::Dialect, field::Field, foreign_language::ForeignLanguage, misc::Misc,
+    part_of_speech::PosSimple, sense::normalize_original, Word,
+};
 
 #[derive(Clone, Copy)]
 pub struct WordRetrieve<'a> {
@@ -41,6 +44,34 @@ impl<'a> WordRetrieve<'a> {
         self.storage.irregular_ichidan.len()
     }
 
+    /// Returns an iterator over all words with a reading that can't be aligned with the readings
+    /// of their kanji
+    #[inline]
+    pub fn irregular_readings<'b>(
+        &'b self,
+    ) -> impl Iterator<Item = &'a Word> + 'b + DoubleEndedIterator {
+        self.storage
+            .irregular_readings
+            .iter()
+            .copied()
+            .filter_map(|seq| self.by_sequence(seq))
+    }
+
+    /// Returns `true` if the word with the given sequence has an irregular reading
+    #[inline]
+    pub fn has_irregular_reading(&self, sequence: u32) -> bool {
+        self.storage
+            .irregular_readings
+            .binary_search(&sequence)
+            .is_ok()
+    }
+
+    /// Returns the amount of words with irregular readings
+    #[inline]
+    pub fn irregular_readings_len(&self) -> usize {
+        self.storage.irregular_readings.len()
+    }
+
     /// Returns an iterator over all words with given `jlpt` level
     #[inline]
     pub fn by_jlpt<'b>(
@@ -101,6 +132,118 @@ impl<'a> WordRetrieve<'a> {
         self.storage.misc_map.get(&(*misc as u8)).map(|i| i.len())
     }
 
+    /// Returns an iterator over all words with a sense of the given `field`
+    #[inline]
+    pub fn by_field<'b>(
+        &'b self,
+        field: Field,
+    ) -> impl Iterator<Item = &'a Word> + 'b + DoubleEndedIterator {
+        self.storage
+            .field_map
+            .get(&(field as u8))
+            .into_iter()
+            .flatten()
+            .filter_map(move |i| self.by_sequence(*i))
+    }
+
+    /// Returns the amount of words indexed for `field`
+    #[inline]
+    pub fn field_len(&self, field: &Field) -> Option<usize> {
+        self.storage.field_map.get(&(*field as u8)).map(|i| i.len())
+    }
+
+    /// Returns an iterator over all words with a sense of the given `dialect`
+    #[inline]
+    pub fn by_dialect<'b>(
+        &'b self,
+        dialect: Dialect,
+    ) -> impl Iterator<Item = &'a Word> + 'b + DoubleEndedIterator {
+        self.storage
+            .dialect_map
+            .get(&(dialect as u8))
+            .into_iter()
+            .flatten()
+            .filter_map(move |i| self.by_sequence(*i))
+    }
+
+    /// Returns the amount of words indexed for `dialect`
+    #[inline]
+    pub fn dialect_len(&self, dialect: &Dialect) -> Option<usize> {
+        self.storage
+            .dialect_map
+            .get(&(*dialect as u8))
+            .map(|i| i.len())
+    }
+
+    /// Returns an iterator over all loanwords derived from `original`, eg. "Arbeit"
+    #[inline]
+    pub fn by_gairaigo_original<'b>(
+        &'b self,
+        original: &str,
+    ) -> impl Iterator<Item = &'a Word> + 'b + DoubleEndedIterator {
+        self.storage
+            .gairaigo_map
+            .get(&normalize_original(original))
+            .into_iter()
+            .flatten()
+            .filter_map(move |i| self.by_sequence(*i))
+    }
+
+    /// Returns an iterator over all loanwords from `lang` or all loanwords if `lang` is `None`
+    #[inline]
+    pub fn by_foreign_lang<'b>(
+        &'b self,
+        lang: Option<ForeignLanguage>,
+    ) -> impl Iterator<Item = &'a Word> + 'b + DoubleEndedIterator {
+        let seqs = match lang {
+            Some(lang) => self.storage.foreign_lang_map.get(&(lang as u8)),
+            None => Some(&self.storage.gairaigo_words),
+        };
+
+        seqs.into_iter()
+            .flatten()
+            .filter_map(move |i| self.by_sequence(*i))
+    }
+
+    /// Returns the amount of loanwords indexed for `lang` or all loanwords if `lang` is `None`
+    #[inline]
+    pub fn foreign_lang_len(&self, lang: Option<ForeignLanguage>) -> Option<usize> {
+        match lang {
+            Some(lang) => self
+                .storage
+                .foreign_lang_map
+                .get(&(lang as u8))
+                .map(|i| i.len()),
+            None => Some(self.storage.gairaigo_words.len()),
+        }
+    }
+
+    /// Returns an iterator over all words referencing the word with the given `seq_id` in an
+    /// `xref` or `antonym`
+    #[inline]
+    pub fn referenced_by<'b>(
+        &'b self,
+        seq_id: u32,
+    ) -> impl Iterator<Item = &'a Word> + 'b + DoubleEndedIterator {
+        self.storage
+            .referenced_by
+            .get(&seq_id)
+            .into_iter()
+            .flatten()
+            .filter_map(move |i| self.by_sequence(*i))
+    }
+
+    /// Returns an iterator over all words with the given katakana reading
+    #[inline]
+    pub fn by_katakana<'b>(&'b self, kana: &str) -> impl Iterator<Item = &'a Word> + 'b {
+        self.storage
+            .katakana_map
+            .get(kana)
+            .into_iter()
+            .flatten()
+            .filter_map(move |i| self.by_sequence(*i))
+    }
+
     /// Returns the total count of words
     #[inline]
     pub fn count(&self) -> usize {
@@ -1,10 +1,21 @@
-use ids_parser::IDS;
+use ids_parser::{Origin, IDS};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use types::jotoba::kanji::{radical::DetailedRadical, Kanji};
+use std::collections::{HashMap, HashSet};
+use types::jotoba::kanji::{
+    phonetic::{self, PhoneticSeries},
+    radical::DetailedRadical,
+    similarity::{self, Shape},
+    Kanji,
+};
 
 use super::feature::Feature;
 
+/// Max amount of generated similar kanji per kanji
+pub const MAX_SIMILAR_KANJI: usize = 10;
+
+/// Max amount of kanji sharing a component to compare each kanji with
+const MAX_SIMILARITY_CANDIDATES: usize = 200;
+
 /// Storage containing all data related to kanji
 #[derive(Serialize, Deserialize, Default, Clone)]
 pub struct KanjiStorage {
@@ -26,6 +37,19 @@ pub struct KanjiStorage {
     /// IDS index for kanji decomposition graph
     pub ids_index: HashMap<char, IDS>,
 
+    /// Phonetic component mapped to its phonetic series.
+    /// Built after loading, see [`KanjiStorage::build_phonetic_series`]
+    #[serde(skip)]
+    pub phonetic_series: HashMap<char, PhoneticSeries>,
+    /// Kanji mapped to the components of all phonetic series it is a member of
+    #[serde(skip)]
+    pub phonetic_members: HashMap<char, Vec<char>>,
+
+    /// Kanji mapped to visually similar kanji along with their similarity score, most similar
+    /// first. Built after loading, see [`KanjiStorage::build_visual_similarity`]
+    #[serde(skip)]
+    pub visual_similar: HashMap<char, Vec<(char, f32)>>,
+
     has_similar_kanji: bool,
 }
 
@@ -59,6 +83,143 @@ impl KanjiStorage {
         }
     }
 
+    /// Derives all phonetic series from the kanjis onyomi and their components
+    pub fn build_phonetic_series(&mut self) {
+        self.phonetic_series.clear();
+        self.phonetic_members.clear();
+
+        let kanji = self.literal_index.iter().map(|(_, kanji)| {
+            let components = self.components(kanji.literal);
+            (kanji.literal, kanji.onyomi.as_slice(), components)
+        });
+
+        for series in phonetic::derive_series(kanji) {
+            for member in series.members.iter() {
+                let components = self.phonetic_members.entry(*member).or_default();
+                components.push(series.component);
+            }
+            self.phonetic_series.insert(series.component, series);
+        }
+    }
+
+    /// Computes the visual similarity between kanji sharing a component and keeps the most
+    /// similar ones for each kanji
+    pub fn build_visual_similarity(&mut self) {
+        self.visual_similar.clear();
+
+        let shapes: HashMap<char, Shape> = self
+            .literal_index
+            .iter()
+            .map(|(_, kanji)| (kanji.literal, self.shape(kanji)))
+            .collect();
+
+        let mut by_component: HashMap<char, Vec<char>> = HashMap::new();
+        for (literal, shape) in shapes.iter() {
+            for component in shape.distinct_components() {
+                by_component.entry(component).or_default().push(*literal);
+            }
+        }
+
+        // Sorted by strokes so only kanji with a similar amount of strokes have to be compared
+        let strokes = |literal: &char| shapes[literal].strokes;
+        for group in by_component.values_mut() {
+            group.sort_unstable_by_key(|i| (strokes(i), *i));
+        }
+
+        for (literal, shape) in shapes.iter() {
+            let min_strokes = shape.strokes.saturating_sub(similarity::MAX_STROKE_DIFF);
+            let max_strokes = shape.strokes.saturating_add(similarity::MAX_STROKE_DIFF);
+
+            // Kanji mapped to the amount of direct components they share with `literal`
+            let mut shared: HashMap<char, usize> = HashMap::new();
+            let components = shape.distinct_components();
+            for group in components.iter().filter_map(|i| by_component.get(i)) {
+                let start = group.partition_point(|i| strokes(i) < min_strokes);
+                let others = group[start..]
+                    .iter()
+                    .take_while(|i| strokes(i) <= max_strokes)
+                    .filter(|i| *i != literal);
+                for other in others {
+                    *shared.entry(*other).or_default() += 1;
+                }
+            }
+
+            // Components like 口 are part of thousands of kanji, so only the kanji sharing
+            // the most components get compared
+            let mut candidates: Vec<(char, usize)> = shared.into_iter().collect();
+            candidates.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+            candidates.truncate(MAX_SIMILARITY_CANDIDATES);
+
+            let mut similar: Vec<(char, f32)> = candidates
+                .into_iter()
+                .filter_map(|(other, _)| {
+                    let score = similarity::score(shape, shapes.get(&other)?);
+                    (score >= similarity::MIN_SIMILARITY).then(|| (other, score))
+                })
+                .collect();
+
+            if similar.is_empty() {
+                continue;
+            }
+
+            similar.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+            similar.truncate(MAX_SIMILAR_KANJI);
+            self.visual_similar.insert(*literal, similar);
+        }
+    }
+
+    /// Returns the visual shape of a kanji based on its decomposition tree
+    fn shape(&self, kanji: &Kanji) -> Shape {
+        let (layout, components) = self.decomposition(kanji.literal);
+
+        let mut nested = HashSet::new();
+        let mut stack = components.clone();
+        while let Some(component) = stack.pop() {
+            if nested.insert(component) {
+                stack.extend(self.components(component));
+            }
+        }
+
+        Shape::new(kanji.stroke_count, layout, components, nested)
+    }
+
+    /// Returns the direct components of a kanji or component. Uses the japanese IDS decomposition
+    /// if available and falls back to the kanjis parts
+    #[inline]
+    fn components(&self, literal: char) -> Vec<char> {
+        self.decomposition(literal).1
+    }
+
+    /// Returns the IDS operator arranging the direct components of a kanji or component
+    /// (eg. ⿰) along with the components. Kanji without IDS decomposition fall back to their
+    /// parts and have no operator
+    fn decomposition(&self, literal: char) -> (Option<char>, Vec<char>) {
+        let comp = self.ids_index.get(&literal).and_then(|ids| {
+            ids.comp_by_lang(Origin::Japan)
+                .or_else(|| (ids.compositions.len() == 1).then(|| &ids.compositions[0]))
+        });
+
+        let (layout, components) = match comp {
+            // The top level operator is the first one within the composition
+            Some(comp) => (
+                format!("{comp:?}")
+                    .chars()
+                    .find(|c| similarity::is_layout_operator(*c)),
+                comp.get_radicals(),
+            ),
+            None => {
+                let parts = self
+                    .literal_index
+                    .get(literal as u32)
+                    .map(|i| i.parts.clone());
+                (None, parts.unwrap_or_default())
+            }
+        };
+
+        let components = components.into_iter().filter(|i| *i != literal).collect();
+        (layout, components)
+    }
+
     pub fn get_features(&self) -> Vec<Feature> {
         let mut out = vec![];
 
@@ -78,7 +239,7 @@ impl KanjiStorage {
             out.push(Feature::RadicalKanjiMap);
         }
 
-        if self.has_similar_kanji {
+        if self.has_similar_kanji || !self.visual_similar.is_empty() {
             out.push(Feature::SimilarKanji);
         }
 
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use super::feature::Feature;
+use japanese::JapaneseExt;
 use serde::{Deserialize, Serialize};
 use types::jotoba::names::Name;
 
@@ -9,6 +10,13 @@ use types::jotoba::names::Name;
 pub struct NameStorage {
     /// Index mapping name id to its `Name` value
     pub names: HashMap<u32, Name>,
+    /// Name types mapped to all names of this type. Built after loading,
+    /// see [`NameStorage::build_maps`]
+    #[serde(skip)]
+    pub name_type_map: HashMap<u8, Vec<u32>>,
+    /// Katakana readings of names mapped to all names having them
+    #[serde(skip)]
+    pub katakana_map: HashMap<String, Vec<u32>>,
 }
 
 impl NameStorage {
@@ -23,6 +31,35 @@ impl NameStorage {
         for name in names {
             self.names.insert(name.sequence, name);
         }
+
+        self.build_maps();
+    }
+
+    /// Builds the name type and katakana maps out of all names
+    pub fn build_maps(&mut self) {
+        self.name_type_map.clear();
+        self.katakana_map.clear();
+
+        for (seq, name) in self.names.iter() {
+            for name_type in name.name_type.iter().flatten() {
+                let entry = self.name_type_map.entry(*name_type as u8).or_default();
+                entry.push(*seq);
+            }
+
+            if name.kana.is_katakana() {
+                let entry = self.katakana_map.entry(name.kana.clone()).or_default();
+                entry.push(*seq);
+            }
+        }
+
+        for v in self
+            .name_type_map
+            .values_mut()
+            .chain(self.katakana_map.values_mut())
+        {
+            v.sort_unstable();
+            v.dedup();
+        }
     }
 
     pub fn get_features(&self) -> Vec<Feature> {
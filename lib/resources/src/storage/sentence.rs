@@ -22,6 +22,29 @@ impl SentenceStorage {
         Self::default()
     }
 
+    /// Replaces the JLPT guesses of all sentences with `guesses` and rebuilds the JLPT map.
+    /// Sentences without a guess won't have a JLPT level afterwards
+    pub fn set_jlpt_guesses(&mut self, guesses: &HashMap<u32, u8>) {
+        let ids: Vec<u32> = self.sentences.iter().map(|i| i.1.id).collect();
+        for id in ids {
+            let sentence = self.sentences.get_mut(id).unwrap();
+            sentence.jlpt_guess = None;
+            if let Some(guess) = guesses.get(&id) {
+                sentence.set_jlpt_guess(*guess);
+            }
+        }
+
+        self.jlpt_map.clear();
+        for (_, sentence) in self.sentences.iter() {
+            if let Some(guess) = sentence.jlpt_guess {
+                self.jlpt_map
+                    .entry(guess.get())
+                    .or_default()
+                    .push(sentence.id);
+            }
+        }
+    }
+
     pub fn get_features(&self) -> Vec<Feature> {
         let mut out = vec![];
 
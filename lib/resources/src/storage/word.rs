@@ -1,9 +1,16 @@
 use intmap::IntMap;
+use japanese::JapaneseExt;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use types::jotoba::words::Word;
+use types::jotoba::{
+    languages::Language,
+    words::{
+        sense::{RawRef, SenseRef},
+        Word,
+    },
+};
 
-use super::feature::Feature;
+use super::{feature::Feature, kanji::KanjiStorage};
 
 /// Storage containing all data related to words
 #[derive(Serialize, Deserialize, Default, Clone)]
@@ -16,6 +23,34 @@ pub struct WordStorage {
     pub irregular_ichidan: Vec<u32>,
     pub pos_map: HashMap<u8, Vec<u32>>,
     pub misc_map: HashMap<u8, Vec<u32>>,
+    /// Built from the words senses after loading, see [`WordStorage::build_sense_maps`]
+    #[serde(skip)]
+    pub field_map: HashMap<u8, Vec<u32>>,
+    #[serde(skip)]
+    pub dialect_map: HashMap<u8, Vec<u32>>,
+
+    // Loanwords
+    /// Normalized original word of a loanword mapped to all words derived from it
+    #[serde(skip)]
+    pub gairaigo_map: HashMap<String, Vec<u32>>,
+    #[serde(skip)]
+    pub foreign_lang_map: HashMap<u8, Vec<u32>>,
+    #[serde(skip)]
+    pub gairaigo_words: Vec<u32>,
+
+    /// Words mapped to all words referencing them in an `xref` or `antonym`.
+    /// Built after loading, see [`WordStorage::resolve_references`]
+    #[serde(skip)]
+    pub referenced_by: HashMap<u32, Vec<u32>>,
+
+    /// Katakana readings mapped to all words having them
+    #[serde(skip)]
+    pub katakana_map: HashMap<String, Vec<u32>>,
+
+    /// Words whose reading can't be aligned with the readings of their kanji (jukujikun, ateji).
+    /// Built after loading, see [`WordStorage::build_irregular_readings`]
+    #[serde(skip)]
+    pub irregular_readings: Vec<u32>,
 
     // Feature information
     has_accents: bool,
@@ -57,6 +92,134 @@ impl WordStorage {
         for (_, v) in self.jlpt_word_map.iter_mut() {
             v.sort();
         }
+
+        self.build_sense_maps();
+        self.resolve_references();
+        self.build_katakana_map();
+    }
+
+    /// Builds the katakana map out of all words kana readings
+    pub fn build_katakana_map(&mut self) {
+        self.katakana_map.clear();
+
+        for (_, word) in self.words.iter() {
+            let kana = &word.reading.kana.reading;
+            if kana.is_katakana() {
+                let entry = self.katakana_map.entry(kana.clone()).or_default();
+                entry.push(word.sequence);
+            }
+        }
+    }
+
+    /// Collects all words with a reading that can't be aligned with the readings of their kanji
+    pub fn build_irregular_readings(&mut self, kanji: &KanjiStorage) {
+        self.irregular_readings.clear();
+
+        for (_, word) in self.words.iter() {
+            let breakdown = word.reading_breakdown(|lit| kanji.literal_index.get(lit as u32));
+            if breakdown.map_or(false, |i| i.irregular.is_some()) {
+                self.irregular_readings.push(word.sequence);
+            }
+        }
+
+        self.irregular_readings.sort_unstable();
+    }
+
+    /// Builds the field, dialect and loanword maps out of all words senses
+    pub fn build_sense_maps(&mut self) {
+        self.field_map.clear();
+        self.dialect_map.clear();
+        self.gairaigo_map.clear();
+        self.foreign_lang_map.clear();
+        self.gairaigo_words.clear();
+
+        for (_, word) in self.words.iter() {
+            for field in word.senses.iter().filter_map(|i| i.field) {
+                let entry = self.field_map.entry(field as u8).or_default();
+                entry.push(word.sequence);
+            }
+
+            for dialect in word.senses.iter().filter_map(|i| i.dialect) {
+                let entry = self.dialect_map.entry(dialect as u8).or_default();
+                entry.push(word.sequence);
+            }
+
+            for gairaigo in word.gairaigo() {
+                let entry = self.foreign_lang_map.entry(gairaigo.language as u8);
+                entry.or_default().push(word.sequence);
+
+                let original = gairaigo.normalized_original();
+                if !original.is_empty() {
+                    self.gairaigo_map
+                        .entry(original)
+                        .or_default()
+                        .push(word.sequence);
+                }
+
+                self.gairaigo_words.push(word.sequence);
+            }
+        }
+
+        // Words can have multiple senses of the same field/dialect/origin
+        for v in self
+            .field_map
+            .values_mut()
+            .chain(self.dialect_map.values_mut())
+            .chain(self.foreign_lang_map.values_mut())
+            .chain(self.gairaigo_map.values_mut())
+            .chain(std::iter::once(&mut self.gairaigo_words))
+        {
+            v.sort_unstable();
+            v.dedup();
+        }
+    }
+
+    /// Resolves the `xref` and `antonym` of all senses to the words (and senses) they reference
+    pub fn resolve_references(&mut self) {
+        self.referenced_by.clear();
+
+        let mut by_reading: HashMap<&str, Vec<&Word>> = HashMap::new();
+        for (_, word) in self.words.iter() {
+            for reading in word.reading.iter(true) {
+                by_reading
+                    .entry(reading.reading.as_str())
+                    .or_default()
+                    .push(word);
+            }
+        }
+
+        let mut resolved = vec![];
+        for (_, word) in self.words.iter() {
+            for (pos, sense) in word.senses.iter().enumerate() {
+                let resolve = |s: &Option<String>| {
+                    let raw = RawRef::parse(s.as_ref()?)?;
+                    resolve_ref(&by_reading, raw, word.sequence)
+                };
+
+                let xref = resolve(&sense.xref);
+                let antonym = resolve(&sense.antonym);
+                if xref.is_some() || antonym.is_some() {
+                    resolved.push((word.sequence, pos, xref, antonym));
+                }
+            }
+        }
+        drop(by_reading);
+
+        for (seq, pos, xref, antonym) in resolved {
+            let sense = &mut self.words.get_mut(seq).unwrap().senses[pos];
+            sense.xref_target = xref;
+            sense.antonym_target = antonym;
+
+            for target in xref.into_iter().chain(antonym) {
+                let entry = self.referenced_by.entry(target.sequence).or_default();
+                entry.push(seq);
+            }
+        }
+
+        for v in self.referenced_by.values_mut() {
+            v.sort_unstable();
+            v.dedup();
+        }
     }
 
     pub fn update_sentence_mapping(&mut self) {
@@ -92,7 +255,50 @@ impl WordStorage {
     fn clear(&mut self) {
         self.words.clear();
         self.jlpt_word_map.clear();
+        self.field_map.clear();
+        self.dialect_map.clear();
+        self.gairaigo_map.clear();
+        self.foreign_lang_map.clear();
+        self.gairaigo_words.clear();
+        self.referenced_by.clear();
+        self.katakana_map.clear();
+        self.irregular_readings.clear();
         self.has_accents = false;
         self.has_sentence_mapping = false;
     }
 }
+
+/// Finds the word (and sense) a raw reference points to. Words whose main reading matches are
+/// preferred over those only having it as alternative reading, followed by common words
+fn resolve_ref(
+    by_reading: &HashMap<&str, Vec<&Word>>,
+    raw: RawRef,
+    src_seq: u32,
+) -> Option<SenseRef> {
+    let has_reading = |word: &Word, r: &str| word.reading.iter(true).any(|i| i.reading == r);
+
+    let word = by_reading
+        .get(raw.text)?
+        .iter()
+        .filter(|w| w.sequence != src_seq)
+        .filter(|w| raw.kana.map_or(true, |kana| has_reading(w, kana)))
+        .min_by_key(|w| {
+            let is_main = w.get_reading().reading == raw.text;
+            (!is_main, !w.is_common(), w.sequence)
+        })?;
+
+    // Sense numbers count the english senses only
+    let sense_id = match raw.sense_nr {
+        Some(nr) => {
+            let sense = word
+                .senses
+                .iter()
+                .filter(|i| i.language == Language::English)
+                .nth((nr as usize).checked_sub(1)?)?;
+            Some(sense.id)
+        }
+        None => None,
+    };
+
+    Some(SenseRef::new(word.sequence, sense_id))
+}
@@ -0,0 +1,365 @@
+use crate::{kanji::result::KanjiResult, name, query::Query, result::SearchResult, sentence, word};
+use once_cell::sync::{Lazy, OnceCell};
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+use types::jotoba::{
+    names::Name,
+    search::{help::SearchHelp, SearchTarget},
+    words::Word,
+};
+
+/// Default amount of cached results per search type
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// Default time a cached result stays valid
+pub const DEFAULT_TTL: Duration = Duration::from_secs(600);
+
+/// Size and TTL of all caches
+static SETTINGS: OnceCell<(usize, Duration)> = OnceCell::new();
+
+/// Cache for the results of a search executed by the `SearchExecutor`
+pub type SearchCache<T, O> = ResultCache<CacheKey, SearchResult<T, O>>;
+
+pub type WordCache = SearchCache<Word, word::result::AddResData>;
+pub type NameCache = SearchCache<&'static Name, name::result::AddResData>;
+pub type SentenceCache = SearchCache<sentence::result::Sentence, sentence::result::ResData>;
+pub type KanjiCache = ResultCache<CacheKey, KanjiResult>;
+pub type HelpCache = ResultCache<(SearchTarget, CacheKey), Option<SearchHelp>>;
+/// Example words of kanji readings, see [`ReadingUsage::examples`](crate::kanji::result::ReadingUsage::examples)
+pub type ReadingExampleCache = ResultCache<(char, String), Vec<&'static Word>>;
+
+pub static WORDS: Lazy<WordCache> = Lazy::new(new_cache);
+pub static NAMES: Lazy<NameCache> = Lazy::new(new_cache);
+pub static SENTENCES: Lazy<SentenceCache> = Lazy::new(new_cache);
+pub static KANJI: Lazy<KanjiCache> = Lazy::new(new_cache);
+pub static HELP: Lazy<HelpCache> = Lazy::new(new_cache);
+pub static READING_EXAMPLES: Lazy<ReadingExampleCache> = Lazy::new(new_cache);
+
+/// Sets the size and TTL of all caches. Has to be called before the first search in order to
+/// take effect. A size of 0 disables caching. Returns `false` if the caches were already set up
+pub fn init(size: usize, ttl: Duration) -> bool {
+    SETTINGS.set((size, ttl)).is_ok()
+}
+
+/// Returns the hit/miss statistics of all caches
+pub fn stats() -> Vec<(&'static str, CacheStats)> {
+    vec![
+        ("words", WORDS.stats()),
+        ("names", NAMES.stats()),
+        ("sentences", SENTENCES.stats()),
+        ("kanji", KANJI.stats()),
+        ("help", HELP.stats()),
+        ("reading_examples", READING_EXAMPLES.stats()),
+    ]
+}
+
+/// Removes all cached results
+pub fn clear() {
+    WORDS.clear();
+    NAMES.clear();
+    SENTENCES.clear();
+    KANJI.clear();
+    HELP.clear();
+    READING_EXAMPLES.clear();
+}
+
+fn new_cache<K: Hash + Eq + Clone, V>() -> ResultCache<K, V> {
+    let (size, ttl) = SETTINGS
+        .get()
+        .copied()
+        .unwrap_or((DEFAULT_SIZE, DEFAULT_TTL));
+    ResultCache::new(size, ttl)
+}
+
+/// Returns a value which changes once resources or indexes finished loading. Both get loaded in
+/// the background, so results computed before might be based on incomplete data
+#[inline]
+fn data_generation() -> usize {
+    resources::generation() + indexes::storage::generation()
+}
+
+/// Key of a cached search. Next to the query it holds all settings affecting the result which
+/// aren't part of the queries equality
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    query: Query,
+    page_size: u32,
+    kanji_page_size: u32,
+}
+
+impl CacheKey {
+    #[inline]
+    pub fn new(query: &Query) -> Self {
+        Self {
+            query: query.clone(),
+            page_size: query.settings.page_size,
+            kanji_page_size: query.settings.kanji_page_size,
+        }
+    }
+}
+
+/// Hit/miss statistics of a cache
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+}
+
+impl CacheStats {
+    /// Returns the share of lookups which were served from the cache, between 0 and 1
+    #[inline]
+    pub fn hit_rate(&self) -> f32 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            return 0.0;
+        }
+        self.hits as f32 / total as f32
+    }
+}
+
+/// A bounded cache with entries expiring after a given time. If full, the oldest entry gets
+/// evicted. All entries get dropped once resources or indexes finished loading. Values are shared
+/// so a hit doesn't have to copy the cached value
+pub struct ResultCache<K, V> {
+    inner: Mutex<Inner<K, V>>,
+    capacity: usize,
+    ttl: Duration,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+struct Inner<K, V> {
+    entries: HashMap<K, Entry<V>>,
+    /// Keys in insertion order
+    order: VecDeque<K>,
+    /// Data generation the entries were computed with
+    generation: usize,
+}
+
+struct Entry<V> {
+    value: Arc<V>,
+    inserted: Instant,
+}
+
+impl<K: Hash + Eq + Clone, V> ResultCache<K, V> {
+    /// Creates a new cache holding up to `capacity` entries for `ttl` each
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        let inner = Inner {
+            entries: HashMap::with_capacity(capacity.min(DEFAULT_SIZE)),
+            order: VecDeque::with_capacity(capacity.min(DEFAULT_SIZE)),
+            generation: 0,
+        };
+
+        Self {
+            inner: Mutex::new(inner),
+            capacity,
+            ttl,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns `true` if the cache can hold any entry
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    /// Returns the cached value for `key` if it exists and hasn't expired yet
+    #[inline]
+    pub fn get(&self, key: &K) -> Option<Arc<V>> {
+        self.get_at(key, data_generation())
+    }
+
+    /// Caches `value` for `key`. Evicts the oldest entry if the cache is full
+    #[inline]
+    pub fn insert(&self, key: K, value: Arc<V>) {
+        self.insert_at(key, value, data_generation());
+    }
+
+    /// Returns the cached value for `key` or computes and caches it using `f`
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&self, key: K, f: F) -> Arc<V> {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+
+        let value = Arc::new(f());
+        self.insert(key, Arc::clone(&value));
+        value
+    }
+
+    /// Removes all entries
+    pub fn clear(&self) {
+        let mut inner = self.lock();
+        inner.entries.clear();
+        inner.order.clear();
+    }
+
+    /// Returns the hit/miss statistics of the cache
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.lock().entries.len(),
+        }
+    }
+
+    /// Locks the entries. A panic while holding the lock can't leave them in an inconsistent
+    /// state, so a poisoned lock gets recovered
+    #[inline]
+    fn lock(&self) -> MutexGuard<Inner<K, V>> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn get_at(&self, key: &K, generation: usize) -> Option<Arc<V>> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let value = {
+            let mut inner = self.lock();
+            inner.check_generation(generation);
+            inner
+                .entries
+                .get(key)
+                .filter(|entry| entry.inserted.elapsed() < self.ttl)
+                .map(|entry| Arc::clone(&entry.value))
+        };
+
+        let counter = if value.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        value
+    }
+
+    fn insert_at(&self, key: K, value: Arc<V>, generation: usize) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let mut inner = self.lock();
+        inner.check_generation(generation);
+
+        // Expired entries stay until they get overwritten or evicted
+        if let Some(entry) = inner.entries.get_mut(&key) {
+            *entry = Entry::new(value);
+            return;
+        }
+
+        while inner.entries.len() >= self.capacity {
+            match inner.order.pop_front() {
+                Some(oldest) => inner.entries.remove(&oldest),
+                None => break,
+            };
+        }
+
+        inner.order.push_back(key.clone());
+        inner.entries.insert(key, Entry::new(value));
+    }
+}
+
+impl<K, V> Inner<K, V> {
+    /// Drops all entries if they were computed with an other data generation
+    #[inline]
+    fn check_generation(&mut self, generation: usize) {
+        if self.generation != generation {
+            self.entries.clear();
+            self.order.clear();
+            self.generation = generation;
+        }
+    }
+}
+
+impl<V> Entry<V> {
+    #[inline]
+    fn new(value: Arc<V>) -> Self {
+        Self {
+            value,
+            inserted: Instant::now(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn cache(capacity: usize) -> ResultCache<u32, &'static str> {
+        ResultCache::new(capacity, Duration::from_secs(60))
+    }
+
+    #[test]
+    fn test_get_insert() {
+        let cache = cache(2);
+        assert_eq!(cache.get_at(&1, 0), None);
+
+        cache.insert_at(1, "a".into(), 0);
+        assert_eq!(cache.get_at(&1, 0).as_deref(), Some(&"a"));
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+        assert_eq!(stats.hit_rate(), 0.5);
+    }
+
+    #[test]
+    fn test_shared_value() {
+        let cache = cache(2);
+        let value = Arc::new("a");
+        cache.insert_at(1, Arc::clone(&value), 0);
+
+        let cached = cache.get_at(&1, 0).unwrap();
+        assert!(Arc::ptr_eq(&cached, &value));
+    }
+
+    #[test]
+    fn test_evict_oldest() {
+        let cache = cache(2);
+        cache.insert_at(1, "a".into(), 0);
+        cache.insert_at(2, "b".into(), 0);
+        cache.insert_at(1, "c".into(), 0);
+        cache.insert_at(3, "d".into(), 0);
+
+        assert_eq!(cache.get_at(&1, 0), None);
+        assert_eq!(cache.get_at(&2, 0).as_deref(), Some(&"b"));
+        assert_eq!(cache.get_at(&3, 0).as_deref(), Some(&"d"));
+        assert_eq!(cache.stats().entries, 2);
+    }
+
+    #[test]
+    fn test_expired() {
+        let cache: ResultCache<u32, &str> = ResultCache::new(2, Duration::ZERO);
+        cache.insert_at(1, "a".into(), 0);
+        assert_eq!(cache.get_at(&1, 0), None);
+
+        cache.insert_at(1, "b".into(), 0);
+        assert_eq!(cache.stats().entries, 1);
+    }
+
+    #[test]
+    fn test_invalidate_on_load() {
+        let cache = cache(2);
+        cache.insert_at(1, "a".into(), 0);
+        assert_eq!(cache.get_at(&1, 1), None);
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[test]
+    fn test_disabled() {
+        let cache = cache(0);
+        cache.insert_at(1, "a".into(), 0);
+        assert_eq!(cache.get_at(&1, 0), None);
+        assert_eq!(cache.stats(), CacheStats::default());
+    }
+}
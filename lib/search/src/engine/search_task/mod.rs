@@ -1,7 +1,9 @@
 use super::{Index, SearchEngine};
 use engine::{
+    deadline::Deadline,
     pushable::{MaxCounter, PushMod, Pushable},
     relevance::data::SortData,
+    relevance::explain::Explain,
     relevance::item::RelItem,
     result::SearchResult,
     utils::page_from_pqueue,
@@ -25,7 +27,7 @@ pub struct SearchTask<T: SearchEngine> {
     /// Filter out results
     res_filter: Option<Box<dyn Fn(&T::Output) -> bool>>,
     /// Custom result order function
-    cust_order: Option<Box<dyn Fn(SortData<T::Output, Vector, Vector>) -> usize>>,
+    cust_order: Option<Box<dyn Fn(SortData<T::Output, Vector, Vector>, &mut Explain) -> usize>>,
     /// Min relevance returned from vector space algo
     threshold: usize,
     vector_limit: usize,
@@ -34,6 +36,10 @@ pub struct SearchTask<T: SearchEngine> {
     allow_align: bool,
     est_limit: usize,
     score_multiplier: f32,
+    /// Attach an explanation of the score to each result
+    explain: bool,
+    /// Stop loading documents once passed
+    deadline: Deadline,
     phantom: PhantomData<T>,
 }
 
@@ -111,6 +117,12 @@ impl<T: SearchEngine> SearchTask<T> {
         self
     }
 
+    /// Stops the search once `deadline` has passed, keeping the results found until then
+    pub fn with_deadline(mut self, deadline: Deadline) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
     /// Set the search task's vector filter.
     pub fn set_vector_filter<F: 'static>(&mut self, vec_filter: F)
     where
@@ -132,7 +144,21 @@ impl<T: SearchEngine> SearchTask<T> {
     where
         F: Fn(SortData<T::Output, Vector, Vector>) -> usize,
     {
-        self.cust_order = Some(Box::new(res_filter));
+        self.cust_order = Some(Box::new(move |si, _| res_filter(si)));
+    }
+
+    /// Set the search task's custom order function which records the factors of the score
+    /// in the passed `Explain`
+    pub fn with_explained_order<F: 'static>(&mut self, order: F)
+    where
+        F: Fn(SortData<T::Output, Vector, Vector>, &mut Explain) -> usize,
+    {
+        self.cust_order = Some(Box::new(order));
+    }
+
+    /// Attaches an itemised explanation of the score to each result if `explain` is `true`
+    pub fn set_explain(&mut self, explain: bool) {
+        self.explain = explain;
     }
 
     fn gen_query_vec(&self) -> Option<Vector> {
@@ -216,7 +242,11 @@ impl<T: SearchEngine> SearchTask<T> {
         P: Pushable<Item = RelItem<T::Output>>,
         I: Iterator<Item = DocumentVector<T::Document>>,
     {
-        for dvec in dvec_iter {
+        for (pos, dvec) in dvec_iter.enumerate() {
+            if self.deadline.expired_at(pos) {
+                break;
+            }
+
             if !self.filter_vector(&dvec, &q_vec) {
                 continue;
             }
@@ -235,12 +265,18 @@ impl<T: SearchEngine> SearchTask<T> {
                     self.query_lang,
                 );
 
-                let score = self.calc_score(sort_item);
+                let mut explain = Explain::new(self.explain);
+                if explain.is_enabled() {
+                    explain.relevance(sort_item.vec_similarity());
+                }
+
+                let score = self.calc_score(sort_item, &mut explain);
                 if score < self.threshold as usize {
                     continue;
                 }
 
-                out.push(RelItem::new(res_doc, score as f32));
+                let score = score as f32;
+                out.push(RelItem::new(res_doc, score).with_explanation(explain.finish(score)));
             }
         }
     }
@@ -301,7 +337,11 @@ impl<T: SearchEngine> SearchTask<T> {
 
         let mut unique = HashSet::with_capacity(50);
 
-        'o: for dvec in document_vectors {
+        'o: for (pos, dvec) in document_vectors.enumerate() {
+            if self.deadline.expired_at(pos) {
+                break;
+            }
+
             if !self.filter_vector(&dvec, &q_vec) {
                 continue;
             }
@@ -322,7 +362,7 @@ impl<T: SearchEngine> SearchTask<T> {
                         self.query_lang,
                     );
 
-                    let score = self.calc_score(sort_item);
+                    let score = self.calc_score(sort_item, &mut Explain::disabled());
                     if score < self.threshold as usize {
                         continue;
                     }
@@ -340,11 +380,17 @@ impl<T: SearchEngine> SearchTask<T> {
 
     /// Calculates the score using a custom function if provided or just `rel` otherwise
     #[inline]
-    fn calc_score(&self, si: SortData<T::Output, Vector, Vector>) -> usize {
-        match self.cust_order.as_ref() {
-            Some(cust_sort) => (cust_sort(si) as f32 * self.score_multiplier) as usize,
-            None => (T::score(si) as f32 * self.score_multiplier) as usize,
+    fn calc_score(&self, si: SortData<T::Output, Vector, Vector>, explain: &mut Explain) -> usize {
+        let score = match self.cust_order.as_ref() {
+            Some(cust_sort) => cust_sort(si, explain),
+            None => T::score(si),
+        };
+
+        if self.score_multiplier != 1.0 {
+            explain.mul("score multiplier", self.score_multiplier);
         }
+
+        (score as f32 * self.score_multiplier) as usize
     }
 
     #[inline]
@@ -378,6 +424,8 @@ impl<T: SearchEngine> Default for SearchTask<T> {
             phantom: PhantomData,
             cust_order: None,
             score_multiplier: 1.0,
+            explain: false,
+            deadline: Deadline::NONE,
         }
     }
 }
@@ -5,6 +5,7 @@ use indexes::{
     metadata::Metadata,
     words::{document::FWordDoc, ForeignIndex},
 };
+use log::debug;
 use types::jotoba::languages::Language;
 use utils::to_option;
 use vector_space_model2::{build::weights::TFIDF, Vector};
@@ -60,7 +61,7 @@ impl SearchEngine for Engine {
             for term in terms.iter_mut() {
                 if let Some(aligned) = Self::align_query(term, index, language) {
                     *term = aligned.to_string();
-                    println!("Aligned: {} to {}", &query, term);
+                    debug!("Aligned: {} to {}", &query, term);
                 }
             }
         }
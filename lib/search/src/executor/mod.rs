@@ -2,56 +2,217 @@ pub mod out_builder;
 pub mod producer;
 pub mod searchable;
 
-use std::time::Instant;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use crate::result::SearchResult;
-use engine::{pushable::FilteredMaxCounter, utils::page_from_pqueue};
+use crate::{cache::CacheKey, metrics, result::SearchResult};
+use engine::{deadline::Deadline, pushable::FilteredMaxCounter, utils::page_from_pqueue};
 use log::debug;
 use out_builder::OutputBuilder;
+use producer::Producer;
+use rayon::prelude::*;
 use searchable::Searchable;
 use types::jotoba::search::guess::{Guess, GuessType};
 
 /// Max items to count for estimation
 pub const MAX_ESTIMATE: usize = 100;
 
+/// Statistics of a single producer run
+#[derive(Debug, Clone)]
+pub struct ProducerRun {
+    pub name: String,
+    /// Amount of items the producer pushed
+    pub found: usize,
+    pub duration: Duration,
+}
+
 /// Executes a search
 pub struct SearchExecutor<S: Searchable> {
     search: S,
+    timeout: Option<Duration>,
+    parallel: bool,
 }
 
 impl<S: Searchable> SearchExecutor<S> {
     /// Creates a new SearchExecutor
     pub fn new(search: S) -> Self {
-        Self { search }
+        Self {
+            search,
+            timeout: None,
+            parallel: true,
+        }
+    }
+
+    /// Sets whether independent producers may run in parallel. Enabled by default
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Limits the time producers have to find results. Once passed, no further producers run
+    /// and the result is marked as truncated
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
     }
 
-    /// Executes the search
-    pub fn run(self) -> SearchResult<S::OutItem, S::ResAdd> {
+    /// Executes the search. Returns the cached result if the same search was run before
+    #[inline]
+    pub fn run(self) -> Arc<SearchResult<S::OutItem, S::ResAdd>> {
+        self.run_with_hit().0
+    }
+
+    /// Executes the search like [`Self::run`] and additionally returns `true` if the result was
+    /// served from the cache
+    pub fn run_with_hit(self) -> (Arc<SearchResult<S::OutItem, S::ResAdd>>, bool) {
+        let start = Instant::now();
+        let target = self.search.get_query().target;
+        let res = self.run_cached();
+        metrics::observe_search(target, start.elapsed());
+        res
+    }
+
+    fn run_cached(self) -> (Arc<SearchResult<S::OutItem, S::ResAdd>>, bool) {
+        let cache = match self.search.cache().filter(|i| i.is_enabled()) {
+            Some(cache) => cache,
+            None => return (Arc::new(self.run_uncached()), false),
+        };
+
+        let key = CacheKey::new(self.search.get_query());
+        if let Some(res) = cache.get(&key) {
+            debug!("Serving cached result");
+            return (res, true);
+        }
+
+        let res = Arc::new(self.run_uncached());
+        // Incomplete results could be served in full later on
+        if !res.truncated {
+            cache.insert(key, Arc::clone(&res));
+        }
+        (res, false)
+    }
+
+    /// Executes the search without looking up or caching the result
+    pub fn run_uncached(self) -> SearchResult<S::OutItem, S::ResAdd> {
         let query = self.search.get_query();
         let limit = query.settings.page_size as usize;
         let offset = query.page_offset;
 
-        let mut out = OutputBuilder::new(|i| self.search.filter(i), limit + offset);
+        let cap = limit + offset;
+        let mut out = OutputBuilder::new(|i| self.search.filter(i), cap);
+        out.explain = query.explain;
+        out.deadline = Deadline::from_timeout(self.timeout);
 
-        for prod in self.search.get_producer() {
-            if !prod.should_run(out.p.total_pushed()) {
+        let producer = self.search.get_producer();
+        let stages = stages(producer, |p| self.parallel && p.is_independent());
+
+        let mut truncated = false;
+        let mut producers = vec![];
+        for stage in stages {
+            let found = out.total_pushed();
+            let stage: Vec<_> = stage
+                .iter()
+                .filter(|p| p.should_run(found))
+                .map(|p| p.as_ref())
+                .collect();
+
+            if stage.is_empty() {
                 continue;
             }
-            let before = out.p.total_pushed();
-            prod.produce(&mut out);
-            let after = out.p.total_pushed();
-            let name = prod.name();
-            debug!("{name}: {}", after - before);
+
+            if out.deadline.is_expired() {
+                debug!("Deadline passed, skipping remaining producers");
+                truncated = true;
+                break;
+            }
+
+            if stage.len() > 1 {
+                producers.extend(self.run_parallel(&stage, &mut out, cap));
+            } else if let Some(prod) = stage.first() {
+                producers.push(self.run_producer(*prod, &mut out));
+            }
         }
 
+        out.producer = None;
+        // Producers finishing after the deadline passed still delivered all their items
+        truncated |= out.deadline.was_hit();
         self.search.mod_output(&mut out);
 
-        let len = out.p.total_pushed();
+        let len = out.total_pushed();
+        let mut explanations = vec![];
         let items: Vec<_> = page_from_pqueue(limit, offset, out.p)
             .into_iter()
-            .map(|i| self.search.to_output_item(i.item))
+            .map(|mut i| {
+                if query.explain {
+                    explanations.push(i.explanation_mut().clone());
+                }
+                self.search.to_output_item(i.item)
+            })
             .collect();
         SearchResult::with_other_data(items, len, out.output_add)
+            .with_explanations(explanations)
+            .with_producers(producers)
+            .with_truncated(truncated)
+    }
+
+    /// Runs a single producer into `out`
+    fn run_producer(
+        &self,
+        prod: &dyn Producer<Target = S>,
+        out: &mut OutputBuilder<S::Item, S::ResAdd>,
+    ) -> ProducerRun {
+        let name = prod.name();
+        if out.explain {
+            out.producer = Some(name.clone());
+        }
+
+        let start = Instant::now();
+        let before = out.total_pushed();
+        prod.produce(out);
+        let found = out.total_pushed() - before;
+        let duration = start.elapsed();
+        debug!("{name}: {found}");
+
+        metrics::observe_producer(self.search.get_query().target, &name, duration);
+        ProducerRun {
+            name,
+            found,
+            duration,
+        }
+    }
+
+    /// Runs the producers of a stage in parallel, each into its own container, and merges their
+    /// output into `out` in the order of the producers
+    fn run_parallel(
+        &self,
+        stage: &[&dyn Producer<Target = S>],
+        out: &mut OutputBuilder<S::Item, S::ResAdd>,
+        cap: usize,
+    ) -> Vec<ProducerRun> {
+        let explain = out.explain;
+        let deadline = &out.deadline;
+
+        let results: Vec<_> = stage
+            .par_iter()
+            .map(|prod| {
+                let mut local = OutputBuilder::new(|i| self.search.filter(i), cap);
+                local.explain = explain;
+                local.deadline = deadline.clone();
+                let run = self.run_producer(*prod, &mut local);
+                (run, local.p, local.output_add)
+            })
+            .collect();
+
+        results
+            .into_iter()
+            .map(|(run, p, output_add)| {
+                out.merge(p);
+                self.search.merge_res_add(&mut out.output_add, output_add);
+                run
+            })
+            .collect()
     }
 
     pub fn guess(&self) -> Option<Guess> {
@@ -91,3 +252,237 @@ impl<S: Searchable> SearchExecutor<S> {
         Some(Guess::new(sum.min(MAX_ESTIMATE) as u32, gt))
     }
 }
+
+/// Splits `producers` into stages which run one after another. Consecutive independent
+/// producers share a stage, all others run on their own
+fn stages<T, F>(producers: &[T], independent: F) -> Vec<&[T]>
+where
+    F: Fn(&T) -> bool,
+{
+    let mut stages = vec![];
+    let mut start = 0;
+
+    for (pos, producer) in producers.iter().enumerate() {
+        if independent(producer) {
+            continue;
+        }
+
+        if start < pos {
+            stages.push(&producers[start..pos]);
+        }
+        stages.push(&producers[pos..=pos]);
+        start = pos + 1;
+    }
+
+    if start < producers.len() {
+        stages.push(&producers[start..]);
+    }
+
+    stages
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        cache::{ResultCache, SearchCache},
+        query::Query,
+    };
+    use engine::relevance::item::RelItem;
+    use once_cell::sync::Lazy;
+    use std::thread;
+
+    static CACHE: Lazy<SearchCache<u32, ()>> =
+        Lazy::new(|| ResultCache::new(100, Duration::from_secs(60)));
+
+    struct TestSearch {
+        query: Query,
+        producers: Vec<Box<dyn Producer<Target = TestSearch>>>,
+    }
+
+    impl TestSearch {
+        fn new(query_str: &str, producers: Vec<TestProducer>) -> Self {
+            let query = Query {
+                query_str: query_str.to_string(),
+                ..Default::default()
+            };
+            let producers = producers
+                .into_iter()
+                .map(|i| Box::new(i) as Box<dyn Producer<Target = TestSearch>>)
+                .collect();
+            Self { query, producers }
+        }
+    }
+
+    impl Searchable for TestSearch {
+        type Item = u32;
+        type OutItem = u32;
+        type ResAdd = ();
+
+        fn get_producer<'s>(&'s self) -> &Vec<Box<dyn Producer<Target = Self> + 's>> {
+            &self.producers
+        }
+
+        fn get_query(&self) -> &Query {
+            &self.query
+        }
+
+        fn to_output_item(&self, item: u32) -> u32 {
+            item
+        }
+
+        fn cache(&self) -> Option<&'static SearchCache<u32, ()>> {
+            Some(&CACHE)
+        }
+    }
+
+    /// Pushes `items` after waiting for `delay`. Stops at the deadline if `check_deadline` is set
+    /// and only runs if less than `run_below` items were found before
+    struct TestProducer {
+        items: Vec<u32>,
+        delay: Duration,
+        check_deadline: bool,
+        independent: bool,
+        run_below: usize,
+    }
+
+    impl TestProducer {
+        fn new(items: Vec<u32>) -> Self {
+            Self {
+                items,
+                delay: Duration::ZERO,
+                check_deadline: true,
+                independent: true,
+                run_below: usize::MAX,
+            }
+        }
+    }
+
+    impl Producer for TestProducer {
+        type Target = TestSearch;
+
+        fn produce(&self, out: &mut OutputBuilder<u32, ()>) {
+            thread::sleep(self.delay);
+            let deadline = out.deadline();
+            for (pos, item) in self.items.iter().enumerate() {
+                if self.check_deadline && deadline.expired_at(pos) {
+                    break;
+                }
+                out.push(RelItem::new(*item, *item as f32));
+            }
+        }
+
+        fn should_run(&self, already_found: usize) -> bool {
+            already_found < self.run_below
+        }
+
+        fn is_independent(&self) -> bool {
+            self.independent
+        }
+    }
+
+    fn cached(search: &TestSearch) -> bool {
+        CACHE.get(&CacheKey::new(search.get_query())).is_some()
+    }
+
+    #[test]
+    fn test_truncated() {
+        let delayed = TestProducer {
+            delay: Duration::from_millis(50),
+            ..TestProducer::new(vec![1, 2, 3])
+        };
+        let search = TestSearch::new("truncated", vec![delayed]);
+        let res = SearchExecutor::new(search)
+            .with_timeout(Some(Duration::from_millis(10)))
+            .run();
+        assert!(res.truncated);
+        assert!(res.items.is_empty());
+
+        // Skipping producers because the deadline passed truncates the result as well
+
+        let search = TestSearch::new("truncated", vec![TestProducer::new(vec![1, 2, 3])]);
+        let executor = SearchExecutor::new(search).with_timeout(Some(Duration::ZERO));
+        let (res, hit) = executor.run_with_hit();
+        assert!(res.truncated);
+        assert!(!hit);
+
+        let search = TestSearch::new("truncated", vec![]);
+        assert!(!cached(&search));
+    }
+
+    #[test]
+    fn test_deadline_passed_after_last_producer() {
+        // Producers which don't stop early deliver all their items even if they finish late
+        let delayed = TestProducer {
+            delay: Duration::from_millis(50),
+            check_deadline: false,
+            ..TestProducer::new(vec![1, 2, 3])
+        };
+        let search = TestSearch::new("complete", vec![delayed]);
+        let (res, hit) = SearchExecutor::new(search)
+            .with_timeout(Some(Duration::from_millis(10)))
+            .run_with_hit();
+        assert!(!res.truncated);
+        assert!(!hit);
+        assert_eq!(res.items, vec![3, 2, 1]);
+
+        let search = TestSearch::new("complete", vec![]);
+        assert!(cached(&search));
+        let (res, hit) = SearchExecutor::new(search).run_with_hit();
+        assert!(hit);
+        assert_eq!(res.items, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_parallel_matches_sequential() {
+        let producers = || {
+            vec![
+                TestProducer::new(vec![1, 2]),
+                TestProducer {
+                    delay: Duration::from_millis(10),
+                    ..TestProducer::new(vec![3, 4])
+                },
+                // Depends on the items of both producers above
+                TestProducer {
+                    independent: false,
+                    run_below: 4,
+                    ..TestProducer::new(vec![5])
+                },
+                TestProducer {
+                    independent: false,
+                    run_below: 5,
+                    ..TestProducer::new(vec![6])
+                },
+                TestProducer::new(vec![7, 1]),
+                TestProducer::new(vec![8]),
+            ]
+        };
+
+        let search = TestSearch::new("sequential", producers());
+        let sequential = SearchExecutor::new(search).with_parallel(false).run();
+        let search = TestSearch::new("parallel", producers());
+        let parallel = SearchExecutor::new(search).with_parallel(true).run();
+
+        assert_eq!(sequential.items, vec![8, 7, 6, 4, 3, 2, 1]);
+        assert_eq!(sequential.items, parallel.items);
+        assert_eq!(sequential.total, parallel.total);
+    }
+
+    #[test]
+    fn test_stages() {
+        let producers = [true, true, false, true, false, false, true];
+        let stages = stages(&producers, |i| *i);
+        let expected = vec![
+            &[true, true][..],
+            &[false],
+            &[true],
+            &[false],
+            &[false],
+            &[true],
+        ];
+        assert_eq!(stages, expected);
+
+        assert!(super::stages(&[] as &[bool], |i| *i).is_empty());
+        assert_eq!(super::stages(&[false], |i| *i), vec![&[false][..]]);
+    }
+}
@@ -1,4 +1,4 @@
-use engine::{pushable::Pushable, relevance::item::RelItem};
+use engine::{deadline::Deadline, pushable::Pushable, relevance::item::RelItem};
 use priority_container::StableUniquePrioContainerMax;
 use std::hash::Hash;
 
@@ -6,6 +6,14 @@ pub struct OutputBuilder<'a, I, OA> {
     pub(crate) p: StableUniquePrioContainerMax<RelItem<I>>,
     pub(crate) filter: Box<dyn Fn(&I) -> bool + 'a>,
     pub(crate) output_add: OA,
+    /// Attach the producer to the explanation of each pushed item
+    pub(crate) explain: bool,
+    /// Name of the producer currently pushing items
+    pub(crate) producer: Option<String>,
+    /// Point in time after which producers should stop searching
+    pub(crate) deadline: Deadline,
+    /// Items pushed by producers which ran separately that didn't fit into `p` when merging
+    pub(crate) dropped: usize,
 }
 
 impl<'a, I: Eq + Hash + Clone, OA: Default> OutputBuilder<'a, I, OA> {
@@ -18,13 +26,49 @@ impl<'a, I: Eq + Hash + Clone, OA: Default> OutputBuilder<'a, I, OA> {
             p,
             filter,
             output_add,
+            explain: false,
+            producer: None,
+            deadline: Deadline::NONE,
+            dropped: 0,
         }
     }
 
+    /// Returns the amount of items pushed so far, including those which didn't fit into the
+    /// output
+    #[inline]
+    pub fn total_pushed(&self) -> usize {
+        self.p.total_pushed() + self.dropped
+    }
+
+    /// Adds the items of a producer which ran into its own container. Items are inserted best
+    /// first so equally ranked ones keep the order they were pushed in
+    pub(crate) fn merge(&mut self, p: StableUniquePrioContainerMax<RelItem<I>>) {
+        let total = p.total_pushed();
+        let mut items: Vec<_> = p.into_iter().collect();
+        self.dropped += total.saturating_sub(items.len());
+
+        items.reverse();
+        for item in items {
+            self.p.insert(item);
+        }
+    }
+
+    /// Returns the deadline of the search. Producers should pass it to their search tasks
+    #[inline]
+    pub fn deadline(&self) -> Deadline {
+        self.deadline.clone()
+    }
+
     /// Pushes an element into the output and  returns `true` if it was not filtered out
     #[inline]
-    pub fn push(&mut self, item: RelItem<I>) -> bool {
+    pub fn push(&mut self, mut item: RelItem<I>) -> bool {
         if !(self.filter)(&item.item) {
+            if self.explain {
+                let explanation = item.explanation_mut();
+                if explanation.producer.is_none() {
+                    explanation.producer = self.producer.clone();
+                }
+            }
             self.p.insert(item);
             return true;
         }
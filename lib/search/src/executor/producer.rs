@@ -2,7 +2,7 @@ use super::{out_builder::OutputBuilder, searchable::Searchable};
 use engine::pushable::FilteredMaxCounter;
 use std::any::type_name;
 
-pub trait Producer {
+pub trait Producer: Sync {
     type Target: Searchable;
 
     fn produce(
@@ -17,6 +17,13 @@ pub trait Producer {
         true
     }
 
+    /// Returns `true` if the producer doesn't depend on the items found by the independent
+    /// producers right before it, allowing them to run in parallel. Its `should_run` gets the
+    /// amount of items found before all of them
+    fn is_independent(&self) -> bool {
+        false
+    }
+
     fn estimate_to(&self, _out: &mut FilteredMaxCounter<<Self::Target as Searchable>::Item>) {}
 
     fn name(&self) -> String {
@@ -1,11 +1,11 @@
 use super::{out_builder::OutputBuilder, producer::Producer};
-use crate::query::Query;
+use crate::{cache::SearchCache, query::Query};
 use std::{fmt::Debug, hash::Hash};
 
-pub trait Searchable {
-    type Item: Clone + Eq + Hash + Debug;
-    type OutItem;
-    type ResAdd: Default;
+pub trait Searchable: Sync {
+    type Item: Clone + Eq + Hash + Debug + Send;
+    type OutItem: Clone;
+    type ResAdd: Default + Clone + Send;
 
     fn get_producer<'s>(&'s self) -> &Vec<Box<dyn Producer<Target = Self> + 's>>;
 
@@ -17,8 +17,17 @@ pub trait Searchable {
     /// Allows modifying the collected producers output before converting it to a SearchResult
     fn mod_output(&self, _out: &mut OutputBuilder<Self::Item, Self::ResAdd>) {}
 
+    /// Merges the additional data of a producer which ran in parallel to others into `into`.
+    /// Such producers start with a default `ResAdd`
+    fn merge_res_add(&self, _into: &mut Self::ResAdd, _from: Self::ResAdd) {}
+
     /// Should return `true` if the passed item should be ignored / filtered
     fn filter(&self, _item: &Self::Item) -> bool {
         false
     }
+
+    /// Cache for the results of the search. Results don't get cached if `None`
+    fn cache(&self) -> Option<&'static SearchCache<Self::OutItem, Self::ResAdd>> {
+        None
+    }
 }
@@ -4,22 +4,57 @@ mod tag_only;
 
 use self::result::KanjiResult;
 use super::query::Query;
-use crate::{engine::words::native::Engine, query::QueryLang, word::order::native::NativeOrder};
+use crate::{
+    cache::{self, CacheKey},
+    engine::words::native::Engine,
+    metrics,
+    query::QueryLang,
+    word::order::native::NativeOrder,
+};
 use engine::task::SearchTask;
 use error::Error;
 use japanese::JapaneseExt;
 use result::Item;
+use std::{sync::Arc, time::Instant};
 use types::jotoba::{
     kanji::Kanji,
     search::guess::{Guess, GuessType},
 };
 
 /// The entry of a kanji search
-pub fn search(query: &Query) -> Result<KanjiResult, Error> {
-    if query.form.is_tag_only() {
-        return tag_only::search(query);
+pub fn search(query: &Query) -> Result<Arc<KanjiResult>, Error> {
+    let start = Instant::now();
+    let res = search_cached(query);
+    metrics::observe_search(query.target, start.elapsed());
+    res
+}
+
+fn search_cached(query: &Query) -> Result<Arc<KanjiResult>, Error> {
+    let key = CacheKey::new(query);
+    if let Some(res) = cache::KANJI.get(&key) {
+        return Ok(res);
     }
 
+    let res = Arc::new(search_uncached(query)?);
+    cache::KANJI.insert(key, Arc::clone(&res));
+    Ok(res)
+}
+
+fn search_uncached(query: &Query) -> Result<KanjiResult, Error> {
+    let mut res = if query.form.is_tag_only() {
+        tag_only::search(query)?
+    } else {
+        search_kanji(query)
+    };
+
+    for item in res.items.iter_mut() {
+        item.load_reading_usage();
+    }
+
+    Ok(res)
+}
+
+fn search_kanji(query: &Query) -> KanjiResult {
     let query_str = format_query(&query.query_str);
 
     let res = match query.q_lang {
@@ -42,7 +77,7 @@ pub fn search(query: &Query) -> Result<KanjiResult, Error> {
         .take(query.settings.kanji_page_size as usize)
         .collect::<Vec<_>>();
 
-    Ok(KanjiResult { items, total_len })
+    KanjiResult { items, total_len }
 }
 
 /// Find a kanji by its literal
@@ -1,12 +1,23 @@
-use std::fs::read_to_string;
+use crate::{
+    cache,
+    engine::{words::native::k_reading, SearchTask},
+    word::order,
+};
+use std::{cmp::Ordering, fs::read_to_string};
 use types::jotoba::{
-    kanji::Kanji,
+    kanji::{phonetic::PhoneticSeries, reading::ReadingType, Kanji},
     languages::Language,
     words::{filter_languages, Word},
 };
 
+/// Readings used by less words than this share are considered rare
+pub const RARE_READING_SHARE: f32 = 0.01;
+
+/// Max amount of example words per reading
+const MAX_READING_EXAMPLES: usize = 3;
+
 // The final result of a Kanji search
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct KanjiResult {
     pub items: Vec<Item>,
     pub total_len: usize,
@@ -18,22 +29,151 @@ pub struct Item {
     pub kun_dicts: Option<Vec<Word>>,
     pub on_dicts: Option<Vec<Word>>,
     pub has_compositions: bool,
+    /// Usage of the kanjis readings, sorted by usage. Empty if there is no usage data
+    pub reading_usage: Vec<ReadingUsage>,
+    /// Phonetic series the kanji is a member of
+    pub phonetic_series: Vec<&'static PhoneticSeries>,
+}
+
+/// Usage statistics of a single kanji reading
+#[derive(Debug, PartialEq, Clone)]
+pub struct ReadingUsage {
+    pub literal: char,
+    /// The reading as listed for the kanji, eg. い.きる
+    pub reading: String,
+    pub r_type: ReadingType,
+    /// Amount of words using the reading
+    pub count: u32,
+    /// Share of words using the reading, between 0 and 1
+    pub share: f32,
 }
 
 impl Item {
-    pub fn load_words(k: Kanji, lang: Language, show_english: bool) -> Self {
+    pub fn load_words(mut k: Kanji, lang: Language, show_english: bool) -> Self {
         let kun_dicts = load_dicts(&k.kun_dicts, lang, show_english);
         let on_dicts = load_dicts(&k.on_dicts, lang, show_english);
 
-        let has_compositions = resources::get().kanji().ids(k.literal).is_some();
+        let kanji_retrieve = resources::get().kanji();
+        k.similar_kanji = kanji_retrieve.similar_kanji(k.literal);
+        let has_compositions = kanji_retrieve.ids(k.literal).is_some();
+        let phonetic_series = kanji_retrieve.phonetic_series_of(k.literal).collect();
 
         Self {
             kun_dicts,
             on_dicts,
             kanji: k,
             has_compositions,
+            reading_usage: vec![],
+            phonetic_series,
         }
     }
+
+    /// Loads the usage statistics of the kanjis readings. Their example words are only
+    /// looked up when requested
+    pub fn load_reading_usage(&mut self) {
+        let freq_data = match indexes::get().kanji().reading_fre().get(self.kanji.literal) {
+            Some(f) => f,
+            None => return,
+        };
+
+        let on = self.kanji.onyomi.iter().map(|i| (i, ReadingType::Onyomi));
+        let kun = self.kanji.kunyomi.iter().map(|i| (i, ReadingType::Kunyomi));
+
+        let mut usage: Vec<_> = on
+            .chain(kun)
+            .filter_map(|(reading, r_type)| {
+                let count = freq_data.get_reading(reading)?;
+                let share = freq_data.reading_share(reading)?;
+                Some(ReadingUsage {
+                    literal: self.kanji.literal,
+                    reading: reading.clone(),
+                    r_type,
+                    count,
+                    share,
+                })
+            })
+            .collect();
+
+        usage.sort_by(|a, b| b.count.cmp(&a.count));
+        self.reading_usage = usage;
+    }
+
+    /// Returns all members of `series` other than the kanji itself, along with whether they
+    /// have the series reading
+    pub fn phonetic_members(&self, series: &PhoneticSeries) -> Vec<(char, bool)> {
+        let kanji_retrieve = resources::get().kanji();
+        series
+            .members
+            .iter()
+            .filter(|i| **i != self.kanji.literal)
+            .map(|i| {
+                let regular = kanji_retrieve
+                    .by_literal(*i)
+                    .map_or(false, |k| k.in_on_reading(&series.reading));
+                (*i, regular)
+            })
+            .collect()
+    }
+
+    /// Returns the usage of `reading`
+    #[inline]
+    pub fn get_reading_usage(&self, reading: &str) -> Option<&ReadingUsage> {
+        self.reading_usage.iter().find(|i| i.reading == reading)
+    }
+
+    /// Returns all readings of the given type, sorted by usage. Readings without usage data are
+    /// kept in their original order behind all others
+    pub fn readings_by_usage(&self, r_type: ReadingType) -> Vec<(&String, Option<&ReadingUsage>)> {
+        let readings = match r_type {
+            ReadingType::Kunyomi => &self.kanji.kunyomi,
+            ReadingType::Onyomi => &self.kanji.onyomi,
+        };
+
+        let mut readings: Vec<_> = readings
+            .iter()
+            .map(|i| (i, self.get_reading_usage(i)))
+            .collect();
+
+        readings.sort_by(|a, b| match (a.1, b.1) {
+            (Some(a), Some(b)) => b.count.cmp(&a.count),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+
+        readings
+    }
+}
+
+impl ReadingUsage {
+    /// Returns the share of words using the reading in percent
+    #[inline]
+    pub fn percent(&self) -> f32 {
+        self.share * 100.0
+    }
+
+    /// Returns `true` if the reading is only used by very few words
+    #[inline]
+    pub fn is_rare(&self) -> bool {
+        self.share < RARE_READING_SHARE
+    }
+
+    /// Returns the most relevant words using the reading. They're searched on the first call
+    /// and cached for following ones
+    pub fn examples(&self) -> Vec<&'static Word> {
+        let key = (self.literal, self.reading.clone());
+        let examples = cache::READING_EXAMPLES
+            .get_or_insert_with(key, || reading_examples(self.literal, &self.reading));
+        examples.to_vec()
+    }
+}
+
+/// Returns the most relevant words using `reading` of the kanji `literal`
+fn reading_examples(literal: char, reading: &str) -> Vec<&'static Word> {
+    let query = format!("{literal}{reading}");
+    let mut search_task = SearchTask::<k_reading::Engine>::new(&query).limit(MAX_READING_EXAMPLES);
+    search_task.with_explained_order(order::kanji_reading_search);
+    search_task.find().into_iter().collect()
 }
 
 fn load_dicts(dicts: &Vec<u32>, lang: Language, show_english: bool) -> Option<Vec<Word>> {
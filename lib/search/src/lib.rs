@@ -1,9 +1,12 @@
+pub mod cache;
 pub mod engine;
 pub mod executor;
 pub mod kanji;
+pub mod metrics;
 pub mod name;
 pub mod query;
 pub mod radical;
+pub mod reader;
 pub mod result;
 pub mod sentence;
 pub mod word;
@@ -50,8 +53,19 @@ impl SearchMode {
     }
 }
 
+/// Returns `true` if all data required for searching is loaded
+pub fn is_loaded() -> bool {
+    resources::is_loaded() && indexes::storage::is_loaded() && sentence_reader::is_loaded()
+}
+
 /// Build a [`SearchHelp`] in for cases without any search results
 pub fn build_help(querytype: SearchTarget, query: &Query) -> Option<SearchHelp> {
+    let key = (querytype, cache::CacheKey::new(query));
+    let help = cache::HELP.get_or_insert_with(key, || build_help_uncached(querytype, query));
+    help.as_ref().clone()
+}
+
+fn build_help_uncached(querytype: SearchTarget, query: &Query) -> Option<SearchHelp> {
     let mut help = SearchHelp::default();
 
     for qt in SearchTarget::iterate().filter(|i| *i != querytype) {
@@ -0,0 +1,285 @@
+use crate::cache;
+use spin::Mutex;
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+use types::jotoba::search::SearchTarget;
+
+/// Upper bounds of the latency histogram buckets in seconds
+pub const BUCKETS: [f64; 12] = [
+    0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0,
+];
+
+/// Duration of searches by search target
+pub static SEARCHES: Family<Histogram> = Family::new();
+
+/// Duration of producer runs by search target and producer
+pub static PRODUCERS: Family<Histogram> = Family::new();
+
+/// Records the duration of a search, including cached ones
+pub fn observe_search(target: SearchTarget, duration: Duration) {
+    let labels = labels(&[("target", &target_label(target))]);
+    SEARCHES.with(&labels, |h| h.observe(duration));
+}
+
+/// Records the duration of a single producer run
+pub fn observe_producer(target: SearchTarget, producer: &str, duration: Duration) {
+    let labels = labels(&[("target", &target_label(target)), ("producer", producer)]);
+    PRODUCERS.with(&labels, |h| h.observe(duration));
+}
+
+/// Writes all search related metrics in the prometheus text format into `out`
+pub fn write(out: &mut String) {
+    SEARCHES.write(
+        out,
+        "jotoba_search_duration_seconds",
+        "Duration of searches by search target",
+    );
+    PRODUCERS.write(
+        out,
+        "jotoba_producer_duration_seconds",
+        "Duration of producer runs by search target and producer",
+    );
+
+    let stats = cache::stats();
+    let caches = || {
+        stats
+            .iter()
+            .map(|(name, stats)| (labels(&[("cache", name)]), stats))
+    };
+
+    write_header(
+        out,
+        "jotoba_cache_hits_total",
+        "Lookups served from the cache",
+        "counter",
+    );
+    for (labels, stats) in caches() {
+        write_sample(out, "jotoba_cache_hits_total", &labels, stats.hits);
+    }
+
+    write_header(
+        out,
+        "jotoba_cache_misses_total",
+        "Lookups not served from the cache",
+        "counter",
+    );
+    for (labels, stats) in caches() {
+        write_sample(out, "jotoba_cache_misses_total", &labels, stats.misses);
+    }
+
+    write_header(
+        out,
+        "jotoba_cache_entries",
+        "Amount of cached results",
+        "gauge",
+    );
+    for (labels, stats) in caches() {
+        write_sample(out, "jotoba_cache_entries", &labels, stats.entries);
+    }
+}
+
+/// Name of a search target used as label value
+#[inline]
+pub fn target_label(target: SearchTarget) -> String {
+    format!("{target:?}").to_lowercase()
+}
+
+/// Formats label pairs as `name="value",..`, escaping the values
+pub fn labels(pairs: &[(&str, &str)]) -> String {
+    let mut out = String::new();
+    for (pos, (name, value)) in pairs.iter().enumerate() {
+        if pos > 0 {
+            out.push(',');
+        }
+        out.push_str(name);
+        out.push_str("=\"");
+        for c in value.chars() {
+            match c {
+                '\\' => out.push_str("\\\\"),
+                '"' => out.push_str("\\\""),
+                '\n' => out.push_str("\\n"),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+    out
+}
+
+/// Writes the `HELP` and `TYPE` lines of a metric
+pub fn write_header(out: &mut String, name: &str, help: &str, kind: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+/// Writes a single sample of a metric
+pub fn write_sample<V: std::fmt::Display>(out: &mut String, name: &str, labels: &str, value: V) {
+    if labels.is_empty() {
+        let _ = writeln!(out, "{name} {value}");
+    } else {
+        let _ = writeln!(out, "{name}{{{labels}}} {value}");
+    }
+}
+
+/// A set of metrics of the same kind, distinguished by their labels
+pub struct Family<T> {
+    inner: Mutex<BTreeMap<String, T>>,
+}
+
+impl<T: Default> Family<T> {
+    pub const fn new() -> Self {
+        Self {
+            inner: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Calls `f` with the metric for `labels`, creating it if it doesn't exist yet
+    pub fn with<F: FnOnce(&T) -> R, R>(&self, labels: &str, f: F) -> R {
+        let mut inner = self.inner.lock();
+        if !inner.contains_key(labels) {
+            inner.insert(labels.to_string(), T::default());
+        }
+        f(&inner[labels])
+    }
+}
+
+impl<T: Default> Default for Family<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Family<Counter> {
+    /// Writes all counters of the family
+    pub fn write(&self, out: &mut String, name: &str, help: &str) {
+        write_header(out, name, help, "counter");
+        for (labels, counter) in self.inner.lock().iter() {
+            write_sample(out, name, labels, counter.get());
+        }
+    }
+}
+
+impl Family<Histogram> {
+    /// Writes all histograms of the family
+    pub fn write(&self, out: &mut String, name: &str, help: &str) {
+        write_header(out, name, help, "histogram");
+        for (labels, histogram) in self.inner.lock().iter() {
+            histogram.write(out, name, labels);
+        }
+    }
+}
+
+/// A monotonically increasing counter
+#[derive(Default)]
+pub struct Counter(AtomicU64);
+
+impl Counter {
+    #[inline]
+    pub fn inc(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Latency histogram with the buckets of `BUCKETS`
+#[derive(Default)]
+pub struct Histogram {
+    /// Non-cumulative amount of observations per bucket
+    buckets: [AtomicU64; BUCKETS.len()],
+    count: AtomicU64,
+    sum_micros: AtomicU64,
+}
+
+impl Histogram {
+    /// Records a single observation
+    pub fn observe(&self, duration: Duration) {
+        let secs = duration.as_secs_f64();
+        if let Some(pos) = BUCKETS.iter().position(|bound| secs <= *bound) {
+            self.buckets[pos].fetch_add(1, Ordering::Relaxed);
+        }
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_micros
+            .fetch_add(duration.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    /// Returns the amount of observations
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    fn write(&self, out: &mut String, name: &str, labels: &str) {
+        let sep = if labels.is_empty() { "" } else { "," };
+
+        let mut cumulative = 0;
+        for (bound, bucket) in BUCKETS.iter().zip(self.buckets.iter()) {
+            cumulative += bucket.load(Ordering::Relaxed);
+            let labels = format!("{labels}{sep}le=\"{bound}\"");
+            write_sample(out, &format!("{name}_bucket"), &labels, cumulative);
+        }
+
+        let count = self.count();
+        let labels_inf = format!("{labels}{sep}le=\"+Inf\"");
+        write_sample(out, &format!("{name}_bucket"), &labels_inf, count);
+
+        let sum = self.sum_micros.load(Ordering::Relaxed) as f64 / 1_000_000.0;
+        write_sample(out, &format!("{name}_sum"), labels, sum);
+        write_sample(out, &format!("{name}_count"), labels, count);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_labels() {
+        assert_eq!(labels(&[]), "");
+        assert_eq!(
+            labels(&[("route", "/search/{query}"), ("q", "a\"b\\")]),
+            r#"route="/search/{query}",q="a\"b\\""#
+        );
+    }
+
+    #[test]
+    fn test_histogram() {
+        let family: Family<Histogram> = Family::new();
+        family.with("t=\"a\"", |h| {
+            h.observe(Duration::from_micros(500));
+            h.observe(Duration::from_millis(20));
+            h.observe(Duration::from_secs(10));
+        });
+
+        let mut out = String::new();
+        family.write(&mut out, "m", "help");
+
+        assert!(out.contains("# TYPE m histogram\n"));
+        assert!(out.contains("m_bucket{t=\"a\",le=\"0.001\"} 1\n"));
+        assert!(out.contains("m_bucket{t=\"a\",le=\"0.01\"} 1\n"));
+        assert!(out.contains("m_bucket{t=\"a\",le=\"0.025\"} 2\n"));
+        assert!(out.contains("m_bucket{t=\"a\",le=\"5\"} 2\n"));
+        assert!(out.contains("m_bucket{t=\"a\",le=\"+Inf\"} 3\n"));
+        assert!(out.contains("m_sum{t=\"a\"} 10.0205\n"));
+        assert!(out.contains("m_count{t=\"a\"} 3\n"));
+    }
+
+    #[test]
+    fn test_counter() {
+        let family: Family<Counter> = Family::new();
+        family.with("", |c| c.inc());
+        family.with("", |c| c.inc());
+
+        let mut out = String::new();
+        family.write(&mut out, "c", "help");
+        assert!(out.ends_with("c 2\n"));
+    }
+}
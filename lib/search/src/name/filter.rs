@@ -0,0 +1,20 @@
+use crate::query::Query;
+use types::jotoba::names::{name_type::NameType, Name};
+
+/// Filter for name search results
+pub struct NameFilter {
+    name_types: Vec<NameType>,
+}
+
+impl NameFilter {
+    pub fn new(query: &Query) -> Self {
+        let name_types = query.get_name_type_tags().copied().collect();
+        Self { name_types }
+    }
+
+    /// Returns `true` if the name should be filtered out of the results
+    #[inline]
+    pub fn filter_name(&self, name: &Name) -> bool {
+        !self.name_types.iter().all(|nt| name.has_name_type(nt))
+    }
+}
@@ -1,39 +1,51 @@
+mod filter;
 mod order;
 mod producer;
+pub mod result;
+pub mod transcription;
 
 use crate::{
-    executor::{producer::Producer, searchable::Searchable},
+    cache,
+    executor::{out_builder::OutputBuilder, producer::Producer, searchable::Searchable},
     query::Query,
 };
 use producer::{
     foreign::ForeignProducer, kanji_reading::KreadingProducer, native::NativeProducer,
-    sequence::SeqProducer,
+    romaji::RomajiProducer, sequence::SeqProducer, tag::TagProducer,
 };
 use types::jotoba::names::Name;
 
-use self::producer::native::split::SplitProducer;
+use self::{filter::NameFilter, producer::native::split::SplitProducer};
 
 pub struct Search<'a> {
     query: &'a Query,
     producer: Vec<Box<dyn Producer<Target = Self> + 'a>>,
+    filter: NameFilter,
 }
 
 impl<'a> Search<'a> {
     pub fn new(query: &'a Query) -> Self {
         let mut producer: Vec<Box<dyn Producer<Target = Self>>> = vec![];
         producer.push(Box::new(SeqProducer::new(query)));
+        producer.push(Box::new(TagProducer::new(query)));
         producer.push(Box::new(KreadingProducer::new(query)));
         producer.push(Box::new(ForeignProducer::new(query)));
         producer.push(Box::new(NativeProducer::new(query)));
+        producer.push(Box::new(RomajiProducer::new(query)));
         producer.push(Box::new(SplitProducer::new(query)));
-        Self { query, producer }
+        let filter = NameFilter::new(query);
+        Self {
+            query,
+            producer,
+            filter,
+        }
     }
 }
 
 impl<'a> Searchable for Search<'a> {
     type Item = &'static Name;
     type OutItem = &'static Name;
-    type ResAdd = ();
+    type ResAdd = result::AddResData;
 
     #[inline]
     fn to_output_item(&self, item: Self::Item) -> Self::OutItem {
@@ -49,4 +61,20 @@ impl<'a> Searchable for Search<'a> {
     fn get_query(&self) -> &Query {
         self.query
     }
+
+    #[inline]
+    fn cache(&self) -> Option<&'static cache::NameCache> {
+        Some(&cache::NAMES)
+    }
+
+    fn mod_output(&self, out: &mut OutputBuilder<Self::Item, Self::ResAdd>) {
+        if transcription::should_transcribe(self.query) {
+            out.output_add.transcriptions = transcription::transcriptions(self.query);
+        }
+    }
+
+    #[inline]
+    fn filter(&self, item: &Self::Item) -> bool {
+        self.filter.filter_name(item)
+    }
 }
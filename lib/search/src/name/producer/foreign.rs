@@ -34,13 +34,19 @@ impl<'a> Producer for ForeignProducer<'a> {
             <Self::Target as Searchable>::ResAdd,
         >,
     ) {
-        self.foreign_task().find_to(out);
+        self.foreign_task()
+            .with_deadline(out.deadline())
+            .find_to(out);
     }
 
     fn should_run(&self, _already_found: usize) -> bool {
         self.query.q_lang != QueryLang::Japanese
     }
 
+    fn is_independent(&self) -> bool {
+        true
+    }
+
     fn estimate_to(&self, out: &mut FilteredMaxCounter<<Self::Target as Searchable>::Item>) {
         self.foreign_task().estimate_to(out);
     }
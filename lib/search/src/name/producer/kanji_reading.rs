@@ -42,7 +42,7 @@ impl<'a> Producer for KreadingProducer<'a> {
         >,
     ) {
         if let Some(task) = self.search_task() {
-            task.find_to(out);
+            task.with_deadline(out.deadline()).find_to(out);
         }
     }
 
@@ -50,6 +50,10 @@ impl<'a> Producer for KreadingProducer<'a> {
         self.query.form.is_kanji_reading()
     }
 
+    fn is_independent(&self) -> bool {
+        true
+    }
+
     fn estimate_to(&self, out: &mut FilteredMaxCounter<<Self::Target as Searchable>::Item>) {
         if let Some(task) = self.search_task() {
             task.estimate_to(out);
@@ -1,4 +1,6 @@
 pub mod foreign;
 pub mod kanji_reading;
 pub mod native;
+pub mod romaji;
 pub mod sequence;
+pub mod tag;
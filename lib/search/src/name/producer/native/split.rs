@@ -1,4 +1,5 @@
 use engine::{
+    deadline::Deadline,
     pushable::{FilteredMaxCounter, Pushable},
     relevance::{data::SortData, item::RelItem, RelevanceEngine},
     task::SearchTask,
@@ -32,7 +33,7 @@ impl<'a> SplitProducer<'a> {
         }
     }
 
-    fn run<C, P, O>(&self, cb: C, out: &mut P)
+    fn run<C, P, O>(&self, cb: C, out: &mut P, deadline: Deadline)
     where
         C: Fn(&SearchTask<'static, Engine>, &mut P),
         P: Pushable<Item = O>,
@@ -42,13 +43,14 @@ impl<'a> SplitProducer<'a> {
         for (pos, query) in queries.into_iter().enumerate() {
             let task = SearchTask::<Engine>::new(&query)
                 .with_limit(1)
-                .with_custom_order(SplitOrder::new(query_count, pos));
+                .with_custom_order(SplitOrder::new(query_count, pos))
+                .with_deadline(deadline.clone());
 
             (cb)(&task, out);
         }
     }
 
-    fn find_to<P>(&self, out: &mut P)
+    fn find_to<P>(&self, out: &mut P, deadline: Deadline)
     where
         P: Pushable<Item = RelItem<&'static Name>>,
     {
@@ -57,6 +59,7 @@ impl<'a> SplitProducer<'a> {
                 engine.find_to(out);
             },
             out,
+            deadline,
         );
     }
 }
@@ -71,7 +74,8 @@ impl<'a> Producer for SplitProducer<'a> {
             <Self::Target as Searchable>::ResAdd,
         >,
     ) {
-        self.find_to(out)
+        let deadline = out.deadline();
+        self.find_to(out, deadline)
     }
 
     fn should_run(&self, already_found: usize) -> bool {
@@ -80,7 +84,7 @@ impl<'a> Producer for SplitProducer<'a> {
     }
 
     fn estimate_to(&self, out: &mut FilteredMaxCounter<<Self::Target as Searchable>::Item>) {
-        self.run(|engine, out| engine.estimate_to(out), out);
+        self.run(|engine, out| engine.estimate_to(out), out, Deadline::NONE);
     }
 }
 
@@ -0,0 +1,76 @@
+use crate::{
+    engine::names::native::Engine,
+    executor::{out_builder::OutputBuilder, producer::Producer, searchable::Searchable},
+    name::{order::japanese::NativeOrder, Search},
+    query::{Query, QueryLang},
+};
+use engine::{
+    pushable::{FilteredMaxCounter, PushMod},
+    relevance::item::RelItem,
+    task::SearchTask,
+};
+use japanese::{
+    guessing::could_be_romaji,
+    romaji::{self, KanaVariant},
+};
+use types::jotoba::names::Name;
+
+/// Producer for names searched by their romanized reading
+pub struct RomajiProducer<'a> {
+    query: &'a Query,
+    variants: Vec<KanaVariant>,
+}
+
+impl<'a> RomajiProducer<'a> {
+    #[inline]
+    pub fn new(query: &'a Query) -> Self {
+        let variants = romaji::kana_variants(&query.query_str);
+        Self { query, variants }
+    }
+
+    #[inline]
+    fn kana_task(&self, variant: &KanaVariant) -> SearchTask<'static, Engine> {
+        SearchTask::<Engine>::new(&variant.kana)
+            .with_custom_order(NativeOrder)
+            .with_threshold(0.3)
+    }
+}
+
+impl<'a> Producer for RomajiProducer<'a> {
+    type Target = Search<'a>;
+
+    fn produce(
+        &self,
+        out: &mut OutputBuilder<
+            <Self::Target as Searchable>::Item,
+            <Self::Target as Searchable>::ResAdd,
+        >,
+    ) {
+        let deadline = out.deadline();
+        for variant in &self.variants {
+            // Rank exact-length matches above lengthened ones
+            let weight = variant.weight();
+            let mut out = PushMod::new(out, |mut i: RelItem<&'static Name>| {
+                i.relevance *= weight;
+                i
+            });
+            self.kana_task(variant)
+                .with_deadline(deadline.clone())
+                .find_to(&mut out);
+        }
+    }
+
+    fn should_run(&self, _already_found: usize) -> bool {
+        self.query.q_lang == QueryLang::Foreign && could_be_romaji(&self.query.query_str)
+    }
+
+    fn is_independent(&self) -> bool {
+        true
+    }
+
+    fn estimate_to(&self, out: &mut FilteredMaxCounter<<Self::Target as Searchable>::Item>) {
+        for variant in &self.variants {
+            self.kana_task(variant).estimate_to(out);
+        }
+    }
+}
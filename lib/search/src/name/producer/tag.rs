@@ -0,0 +1,86 @@
+use crate::{
+    executor::{out_builder::OutputBuilder, producer::Producer, searchable::Searchable},
+    name::Search,
+    query::Query,
+};
+use engine::{
+    deadline::Deadline,
+    pushable::{FilteredMaxCounter, PushMod, Pushable},
+    relevance::item::RelItem,
+};
+use types::jotoba::names::{name_type::NameType, Name};
+
+/// Producer for names by name type tags without a query, eg. "#female #given"
+pub struct TagProducer<'a> {
+    query: &'a Query,
+}
+
+impl<'a> TagProducer<'a> {
+    pub fn new(query: &'a Query) -> Self {
+        Self { query }
+    }
+
+    /// Returns the name type tag with the fewest names. All other tags are applied as filter
+    fn get_producer_tag(&self) -> Option<NameType> {
+        let names = resources::get().names();
+        self.query
+            .get_name_type_tags()
+            .min_by_key(|i| names.name_type_len(i).unwrap_or(0))
+            .copied()
+    }
+
+    fn find_to<P>(&self, out: &mut P, deadline: Deadline)
+    where
+        P: Pushable<Item = RelItem<&'static Name>>,
+    {
+        let name_type = match self.get_producer_tag() {
+            Some(n) => n,
+            None => return,
+        };
+
+        let names = resources::get().names();
+
+        let mut c = 0;
+        for (pos, name) in names.by_name_type(name_type).enumerate() {
+            if deadline.expired_at(pos) {
+                break;
+            }
+
+            let item = RelItem::new(name, (1000 - c) as f32);
+            if out.push(item) {
+                c += 1;
+                if c >= 1000 {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Producer for TagProducer<'a> {
+    type Target = Search<'a>;
+
+    fn produce(
+        &self,
+        out: &mut OutputBuilder<
+            <Self::Target as Searchable>::Item,
+            <Self::Target as Searchable>::ResAdd,
+        >,
+    ) {
+        let deadline = out.deadline();
+        self.find_to(out, deadline);
+    }
+
+    fn should_run(&self, _already_found: usize) -> bool {
+        self.query.query_str.is_empty() && self.get_producer_tag().is_some()
+    }
+
+    fn is_independent(&self) -> bool {
+        true
+    }
+
+    fn estimate_to(&self, out: &mut FilteredMaxCounter<<Self::Target as Searchable>::Item>) {
+        let mut mid = PushMod::new(out, |i: RelItem<&'static Name>| i.item);
+        self.find_to(&mut mid, Deadline::NONE);
+    }
+}
@@ -0,0 +1,14 @@
+use super::transcription::Transcription;
+
+#[derive(Default, Clone)]
+pub struct AddResData {
+    /// Katakana transcriptions of a latin name
+    pub transcriptions: Vec<Transcription>,
+}
+
+impl AddResData {
+    #[inline]
+    pub fn has_transcriptions(&self) -> bool {
+        !self.transcriptions.is_empty()
+    }
+}
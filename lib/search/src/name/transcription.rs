@@ -0,0 +1,85 @@
+use crate::query::{Query, QueryLang};
+use itertools::Itertools;
+use japanese::transcription::{self, SourceLanguage, MAX_CANDIDATES};
+use resources::ResourceStorage;
+use types::jotoba::languages::Language;
+
+/// A katakana rendering of a name written in latin script
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transcription {
+    pub kana: String,
+    /// Whether the transcription is the reading of a known name
+    pub known_name: bool,
+    /// Whether the transcription is the reading of a known word
+    pub known_word: bool,
+}
+
+impl Transcription {
+    fn new(kana: String, storage: &ResourceStorage) -> Self {
+        let known_name = all_parts(&kana, |part| {
+            storage.names().by_katakana(part).next().is_some()
+        });
+        let known_word = all_parts(&kana, |part| {
+            storage.words().by_katakana(part).next().is_some()
+        });
+        Self {
+            kana,
+            known_name,
+            known_word,
+        }
+    }
+}
+
+/// Returns `true` if the query should show transcriptions
+pub(crate) fn should_transcribe(query: &Query) -> bool {
+    query.page == 0
+        && !query.query_str.is_empty()
+        && query.q_lang != QueryLang::Japanese
+        && transcription::is_transcribable(&query.query_str)
+}
+
+/// Returns katakana transcriptions of the query, ranked by whether they're readings of known
+/// names or words. Candidates of the users language come first, followed by the preferred
+/// candidates of all other languages. Empty if the resources aren't loaded yet
+pub fn transcriptions(query: &Query) -> Vec<Transcription> {
+    let storage = match resources::try_get() {
+        Some(storage) => storage,
+        None => return vec![],
+    };
+
+    let primary = source_language(query.get_search_lang());
+
+    let others = SourceLanguage::ALL
+        .into_iter()
+        .filter(|i| *i != primary)
+        .filter_map(|lang| {
+            transcription::transcribe(&query.query_str, lang)
+                .into_iter()
+                .next()
+        });
+
+    transcription::transcribe(&query.query_str, primary)
+        .into_iter()
+        .chain(others)
+        .unique()
+        .take(MAX_CANDIDATES)
+        .map(|kana| Transcription::new(kana, storage))
+        // Stable sort keeps the generation order within equally ranked candidates
+        .sorted_by_key(|i| (!i.known_name, !i.known_word))
+        .collect()
+}
+
+/// Returns the language whose pronunciation rules should be used for `lang`
+fn source_language(lang: Language) -> SourceLanguage {
+    match lang {
+        Language::German => SourceLanguage::German,
+        Language::French => SourceLanguage::French,
+        Language::Spanish => SourceLanguage::Spanish,
+        _ => SourceLanguage::English,
+    }
+}
+
+/// Returns `true` if `f` holds for each word of a transcribed name
+fn all_parts<F: Fn(&str) -> bool>(kana: &str, f: F) -> bool {
+    kana.split('・').all(f)
+}
@@ -14,8 +14,12 @@ use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use std::hash::Hash;
 use types::jotoba::{
     languages::Language,
+    names::name_type::NameType,
     search::SearchTarget,
-    words::{misc::Misc, part_of_speech::PosSimple},
+    words::{
+        dialect::Dialect, field::Field, foreign_language::ForeignLanguage, misc::Misc,
+        part_of_speech::PosSimple,
+    },
 };
 
 const QUERY_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC.add(b'/');
@@ -49,8 +53,13 @@ pub struct Query {
     pub cust_lang: Option<Language>,
     /// Regex query (for jp)
     pub regex: Option<RegexSQuery>,
+    /// Attach an explanation of the ranking to each result
+    pub explain: bool,
 }
 
+// All fields compare reflexively
+impl Eq for Query {}
+
 /// The language of the query content itself
 #[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
 pub enum QueryLang {
@@ -86,6 +95,52 @@ impl Query {
         self.tags.iter().filter_map(|i| i.as_misc())
     }
 
+    /// Returns an iterator over all Field tags
+    #[inline]
+    pub fn get_field_tags(&self) -> impl Iterator<Item = &Field> + '_ {
+        self.tags.iter().filter_map(|i| i.as_field())
+    }
+
+    /// Returns an iterator over all Dialect tags
+    #[inline]
+    pub fn get_dialect_tags(&self) -> impl Iterator<Item = &Dialect> + '_ {
+        self.tags.iter().filter_map(|i| i.as_dialect())
+    }
+
+    /// Returns an iterator over all Gairaigo tags. `None` items stand for loanwords of any language
+    #[inline]
+    pub fn get_gairaigo_tags(&self) -> impl Iterator<Item = Option<ForeignLanguage>> + '_ {
+        self.tags.iter().filter_map(|i| i.as_gairaigo())
+    }
+
+    /// Returns `Some(fully_derived)` if the query filters loanwords by how they're derived
+    #[inline]
+    pub fn get_fully_derived(&self) -> Option<bool> {
+        self.tags.iter().find_map(|i| i.as_fully_derived())
+    }
+
+    /// Returns `true` if the query is a loanword search by the original word, eg. "Arbeit #gairaigo"
+    #[inline]
+    pub fn is_gairaigo_search(&self) -> bool {
+        !self.query_str.is_empty()
+            && self
+                .tags
+                .iter()
+                .any(|i| i.is_gairaigo() || i.is_fully_derived())
+    }
+
+    /// Returns `true` if the query only wants words with irregular readings
+    #[inline]
+    pub fn has_irregular_reading_tag(&self) -> bool {
+        self.tags.iter().any(|i| i.is_irregular_reading())
+    }
+
+    /// Returns an iterator over all NameType tags
+    #[inline]
+    pub fn get_name_type_tags(&self) -> impl Iterator<Item = &NameType> + '_ {
+        self.tags.iter().filter_map(|i| i.as_name_type())
+    }
+
     /// Returns the result offset by a given page
     #[inline]
     pub fn page_offset(&self, page_size: usize) -> usize {
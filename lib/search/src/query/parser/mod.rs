@@ -30,6 +30,8 @@ pub struct QueryParser {
     word_index: usize,
     /// Overwrite the users settings language
     language_override: Option<ContentLanguage>,
+    /// Explain the ranking of the results
+    explain: bool,
 }
 
 impl QueryParser {
@@ -47,6 +49,7 @@ impl QueryParser {
             page: 0,
             word_index: 0,
             language_override: None,
+            explain: false,
         }
     }
 
@@ -62,6 +65,12 @@ impl QueryParser {
         self
     }
 
+    #[inline]
+    pub fn with_explain(mut self, explain: bool) -> Self {
+        self.explain = explain;
+        self
+    }
+
     #[inline]
     pub fn with_page(mut self, page: usize) -> Self {
         self.page = page;
@@ -76,7 +85,7 @@ impl QueryParser {
             self.language_override = Some(lang_overwrite);
         }
 
-        let (new_query, tags) = Self::extract_tags(&stripped);
+        let (new_query, mut tags) = Self::extract_tags(&stripped);
         let (new_query, must_contain) = req_terms::parse(&new_query);
         let query_str: String = new_query
             .trim()
@@ -85,16 +94,23 @@ impl QueryParser {
             .take(MAX_QUERY_LEN)
             .collect();
 
+        // Many name types share their code with misc tags, eg. #place, so they only apply to
+        // name searches
+        let target = self.get_search_target(&tags);
+        if target != SearchTarget::Names {
+            tags.retain(|i| !i.is_name_type());
+        }
+
         // Don't allow empty queries
         if query_str.is_empty() && !tags.iter().any(|i| i.is_producer()) {
             return None;
         }
 
         let q_lang = lang::parse(&query_str);
-        let target = self.get_search_target(&tags);
         let form = self.parse_form(&query_str, &tags, s_prefix);
 
         let regex = RegexSQuery::new(&query_str);
+        let explain = self.explain || tags.iter().any(|i| i.is_explain());
 
         Some(Query {
             q_lang,
@@ -110,6 +126,7 @@ impl QueryParser {
             cust_lang: self.language_override,
             must_contain,
             regex,
+            explain,
         })
     }
 
@@ -200,5 +217,29 @@ pub fn format_kanji_reading(s: &str) -> String {
 }
 
 pub fn calc_page_offset(page: usize, page_size: usize) -> usize {
-    page.saturating_sub(1) * page_size
+    page.saturating_sub(1).saturating_mul(page_size)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("#place", SearchTarget::Names, true; "names")]
+    #[test_case("#place #names", SearchTarget::Words, true; "names tag")]
+    #[test_case("#place", SearchTarget::Words, false; "words")]
+    #[test_case("tokyo #station", SearchTarget::Sentences, false; "sentences")]
+    fn test_name_type_tags(query: &str, target: SearchTarget, expected: bool) {
+        let query = QueryParser::new(query.to_string(), target, UserSettings::default()).parse();
+        let has_name_type = query.map_or(false, |i| i.tags.iter().any(|i| i.is_name_type()));
+        assert_eq!(has_name_type, expected);
+    }
+
+    #[test_case(0, 10, 0; "page zero")]
+    #[test_case(1, 10, 0; "first page")]
+    #[test_case(3, 10, 20; "third page")]
+    #[test_case(usize::MAX, 10, usize::MAX; "overflow")]
+    fn test_calc_page_offset(page: usize, page_size: usize, expected: usize) {
+        assert_eq!(calc_page_offset(page, page_size), expected);
+    }
 }
@@ -1,11 +1,16 @@
 use crate::query::Tag;
+use localization::traits::Translatable;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use std::str::FromStr;
 use types::jotoba::{
+    names::name_type::NameType,
     search::SearchTarget,
     sentences,
-    words::{misc::Misc, part_of_speech::PosSimple},
+    words::{
+        dialect::Dialect, field::Field, foreign_language::ForeignLanguage, misc::Misc,
+        part_of_speech::PosSimple,
+    },
 };
 use utils::trim_string_end;
 
@@ -64,6 +69,8 @@ pub fn parse(s: &str) -> Vec<Tag> {
             "irrichidan" | "irregularichidan" | "irregular-ichidan" => {
                 tags.push(Tag::IrregularIruEru);
             }
+            "jukujikun" | "irregular-reading" => tags.push(Tag::IrregularReading),
+            "explain" if cfg!(debug_assertions) => tags.push(Tag::Explain),
             _ => (),
         }
     }
@@ -86,10 +93,96 @@ pub fn parse(s: &str) -> Vec<Tag> {
     if let Some(sentence_tag) = sentences::Tag::from_str(&s[1..]).ok() {
         tags.push(Tag::SentenceTag(sentence_tag));
     }
+    if let Some(tag) = parse_field_tag(s) {
+        tags.push(tag);
+    }
+    if let Some(tag) = parse_dialect_tag(s) {
+        tags.push(tag);
+    }
+    if let Some(tag) = parse_gairaigo_tag(s) {
+        tags.push(tag);
+    }
+    if let Some(tag) = parse_name_type_tag(s) {
+        tags.push(tag);
+    }
 
     tags
 }
 
+/// Parses a field tag from its code (`#med`) or name (`#medicine`, `#martial-arts`)
+fn parse_field_tag(s: &str) -> Option<Tag> {
+    let tag = tag_name(s.strip_prefix('#')?);
+    Field::iter()
+        .find(|f| f.as_ref().to_lowercase() == tag || tag_name(f.get_id()) == tag)
+        .map(Tag::Field)
+}
+
+/// Parses a dialect tag from its code (`#ksb`) or name (`#kansai`, `#kansai-ben`)
+fn parse_dialect_tag(s: &str) -> Option<Tag> {
+    let tag = tag_name(s.strip_prefix('#')?);
+    let name = tag
+        .strip_suffix("-ben")
+        .or_else(|| tag.strip_suffix("ben"))
+        .or_else(|| tag.strip_suffix("-dialect"))
+        .unwrap_or(&tag);
+
+    Dialect::iter()
+        .find(|d| d.as_ref() == tag || tag_name((*d).into()) == name)
+        .map(Tag::Dialect)
+}
+
+/// Parses loanword tags like `#gairaigo`, `#wasei`, `#from-german` or `#gairaigo-fr`
+fn parse_gairaigo_tag(s: &str) -> Option<Tag> {
+    let tag = s.strip_prefix('#')?.to_lowercase();
+    match tag.as_str() {
+        "gairaigo" | "loanword" | "loan" => return Some(Tag::Gairaigo(None)),
+        "fully-derived" | "derived" => return Some(Tag::FullyDerived(true)),
+        "wasei" | "wasei-eigo" | "waseieigo" | "partial" => return Some(Tag::FullyDerived(false)),
+        _ => (),
+    }
+
+    let lang = tag
+        .strip_prefix("from-")
+        .or_else(|| tag.strip_prefix("gairaigo-"))
+        .or_else(|| tag.strip_prefix("loan-"))?;
+
+    parse_foreign_language(lang).map(|i| Tag::Gairaigo(Some(i)))
+}
+
+/// Parses a name type tag like `#surname`, `#female` or `#station`
+fn parse_name_type_tag(s: &str) -> Option<Tag> {
+    let tag = s.strip_prefix('#')?.to_lowercase();
+    let name_type = match tag.as_str() {
+        "female" | "feminine" => NameType::Female,
+        "male" | "masculine" => NameType::Male,
+        "given-name" | "firstname" | "first-name" => NameType::Given,
+        "stations" | "railway-station" => NameType::RailwayStation,
+        "places" => NameType::Place,
+        "companies" => NameType::Company,
+        _ => NameType::from_str(&tag).ok()?,
+    };
+    Some(Tag::NameType(name_type))
+}
+
+/// Finds a foreign language by its name (`german`), code (`ger`) or an unambiguous
+/// two letter prefix of its code (`fr`)
+fn parse_foreign_language(lang: &str) -> Option<ForeignLanguage> {
+    let by_name = ForeignLanguage::iter()
+        .find(|l| l.as_ref() == lang || tag_name(l.get_id()) == tag_name(lang));
+    if by_name.is_some() || lang.len() != 2 {
+        return by_name;
+    }
+
+    let mut by_prefix = ForeignLanguage::iter().filter(|l| l.as_ref().starts_with(lang));
+    let first = by_prefix.next()?;
+    by_prefix.next().is_none().then(|| first)
+}
+
+/// Formats the name of a tag so it can be matched by [`TAG_REGEX`]
+fn tag_name(name: &str) -> String {
+    crate::query::tags::tag_name(name).to_lowercase()
+}
+
 /// Returns `Some(u8)` if `s` is a valid N/jlpt-tag
 fn parse_jlpt_tag(s: &str) -> Option<Tag> {
     let jlpt = s
@@ -124,12 +217,82 @@ fn parse_search_type(s: &str) -> Option<Tag> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use test_case::test_case;
 
     #[test]
     fn test_parse_jlpt_tag_parsing() {
         assert_eq!(parse_jlpt_tag("#n4"), Some(Tag::Jlpt(4)));
     }
 
+    #[test]
+    fn test_parse_field_tag() {
+        assert_eq!(parse_field_tag("#med"), Some(Tag::Field(Field::Medicine)));
+        assert_eq!(parse_field_tag("#Comp"), Some(Tag::Field(Field::Computing)));
+        assert_eq!(
+            parse_field_tag("#medicine"),
+            Some(Tag::Field(Field::Medicine))
+        );
+        assert_eq!(parse_field_tag("#buddh"), Some(Tag::Field(Field::Buddhism)));
+        assert_eq!(parse_field_tag("#Go-game"), Some(Tag::Field(Field::GoGame)));
+        assert_eq!(
+            parse_field_tag("#go--game-"),
+            Some(Tag::Field(Field::GoGame))
+        );
+        assert_eq!(parse_field_tag("#nothing"), None);
+    }
+
+    #[test]
+    fn test_parse_extra_hashtags() {
+        for tag in crate::query::tags::extra_hashtag_names() {
+            assert!(TAG_REGEX.is_match(&tag), "{tag}");
+            assert!(!parse(&tag).is_empty(), "{tag}");
+        }
+    }
+
+    #[test]
+    fn test_parse_dialect_tag() {
+        assert_eq!(
+            parse_dialect_tag("#ksb"),
+            Some(Tag::Dialect(Dialect::Kansai))
+        );
+        assert_eq!(
+            parse_dialect_tag("#kansai"),
+            Some(Tag::Dialect(Dialect::Kansai))
+        );
+        assert_eq!(
+            parse_dialect_tag("#osaka-ben"),
+            Some(Tag::Dialect(Dialect::Osaka))
+        );
+        assert_eq!(
+            parse_dialect_tag("#osakaben"),
+            Some(Tag::Dialect(Dialect::Osaka))
+        );
+        assert_eq!(parse_dialect_tag("#nothing"), None);
+    }
+
+    #[test_case("#gairaigo", Some(Tag::Gairaigo(None)); "any language")]
+    #[test_case("#from-german", Some(Tag::Gairaigo(Some(ForeignLanguage::German))); "by name")]
+    #[test_case("#gairaigo-ger", Some(Tag::Gairaigo(Some(ForeignLanguage::German))); "by code")]
+    #[test_case("#gairaigo-fr", Some(Tag::Gairaigo(Some(ForeignLanguage::French))); "by code prefix")]
+    #[test_case("#from-ge", None; "ambiguous prefix")]
+    #[test_case("#wasei", Some(Tag::FullyDerived(false)); "wasei")]
+    #[test_case("#fully-derived", Some(Tag::FullyDerived(true)); "fully derived")]
+    #[test_case("#from-nothing", None; "unknown")]
+    fn test_parse_gairaigo_tag(tag: &str, exp: Option<Tag>) {
+        assert_eq!(parse_gairaigo_tag(tag), exp);
+    }
+
+    #[test_case("#surname", Some(NameType::Surname); "surname")]
+    #[test_case("#given", Some(NameType::Given); "given")]
+    #[test_case("#female", Some(NameType::Female); "female")]
+    #[test_case("#fem", Some(NameType::Female); "fem code")]
+    #[test_case("#Station", Some(NameType::RailwayStation); "station")]
+    #[test_case("#company", Some(NameType::Company); "company")]
+    #[test_case("#noun", None; "no name type")]
+    fn test_parse_name_type_tag(tag: &str, exp: Option<NameType>) {
+        assert_eq!(parse_name_type_tag(tag), exp.map(Tag::NameType));
+    }
+
     #[test]
     fn test_parse_genki_tag_parsing() {
         assert_eq!(parse_genki_tag("#genki3"), Some(Tag::GenkiLesson(3)));
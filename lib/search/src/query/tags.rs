@@ -1,7 +1,13 @@
+use localization::{language::Language, traits::Translatable, TranslationDict};
 use types::jotoba::{
+    indexes::hashtag::RawHashtag,
+    names::name_type::NameType,
     search::SearchTarget,
     sentences,
-    words::{misc::Misc, part_of_speech::PosSimple},
+    words::{
+        dialect::Dialect, field::Field, foreign_language::ForeignLanguage, misc::Misc,
+        part_of_speech::PosSimple,
+    },
 };
 
 /// Hashtag based search tags
@@ -10,21 +16,34 @@ pub enum Tag {
     // Producer tags
     PartOfSpeech(PosSimple),
     Misc(Misc),
+    Field(Field),
+    Dialect(Dialect),
+    /// Loanwords from a given language or any language if `None`
+    Gairaigo(Option<ForeignLanguage>),
+    /// Loanwords that are fully derived from the original word (`true`)
+    /// or only partially like wasei-eigo (`false`)
+    FullyDerived(bool),
+    /// Name type or gender of a name
+    NameType(NameType),
     Jlpt(u8),
     GenkiLesson(u8),
     SentenceTag(sentences::Tag),
     IrregularIruEru,
+    /// Words with a reading that can't be aligned with their kanji (jukujikun, ateji)
+    IrregularReading,
 
     // Non producer
     SearchType(SearchTarget),
     Hidden,
+    /// Explain the ranking of the results. Only available in debug builds
+    Explain,
 }
 
 impl Tag {
     /// Returns true if the tag can be used without a query
     #[inline]
     pub fn is_producer(&self) -> bool {
-        !self.is_search_type() && !self.is_hidden()
+        !self.is_search_type() && !self.is_hidden() && !self.is_explain()
     }
 
     /// Returns `true` if the tag is [`SearchType`].
@@ -74,6 +93,91 @@ impl Tag {
         }
     }
 
+    /// Returns `true` if the tag is [`Field`].
+    ///
+    /// [`Field`]: Tag::Field
+    #[inline]
+    pub fn is_field(&self) -> bool {
+        matches!(self, Self::Field(..))
+    }
+
+    #[inline]
+    pub fn as_field(&self) -> Option<&Field> {
+        if let Self::Field(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the tag is [`Dialect`].
+    ///
+    /// [`Dialect`]: Tag::Dialect
+    #[inline]
+    pub fn is_dialect(&self) -> bool {
+        matches!(self, Self::Dialect(..))
+    }
+
+    #[inline]
+    pub fn as_dialect(&self) -> Option<&Dialect> {
+        if let Self::Dialect(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the tag is [`Gairaigo`].
+    ///
+    /// [`Gairaigo`]: Tag::Gairaigo
+    #[inline]
+    pub fn is_gairaigo(&self) -> bool {
+        matches!(self, Self::Gairaigo(..))
+    }
+
+    #[inline]
+    pub fn as_gairaigo(&self) -> Option<Option<ForeignLanguage>> {
+        if let Self::Gairaigo(v) = self {
+            Some(*v)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the tag is [`FullyDerived`].
+    ///
+    /// [`FullyDerived`]: Tag::FullyDerived
+    #[inline]
+    pub fn is_fully_derived(&self) -> bool {
+        matches!(self, Self::FullyDerived(..))
+    }
+
+    #[inline]
+    pub fn as_fully_derived(&self) -> Option<bool> {
+        if let Self::FullyDerived(v) = self {
+            Some(*v)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the tag is [`NameType`].
+    ///
+    /// [`NameType`]: Tag::NameType
+    #[inline]
+    pub fn is_name_type(&self) -> bool {
+        matches!(self, Self::NameType(..))
+    }
+
+    #[inline]
+    pub fn as_name_type(&self) -> Option<&NameType> {
+        if let Self::NameType(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
     /// Returns `true` if the tag is [`Jlpt`].
     ///
     /// [`Jlpt`]: Tag::Jlpt
@@ -115,6 +219,13 @@ impl Tag {
         matches!(self, Self::IrregularIruEru)
     }
 
+    /// Returns `true` if the tag is [`IrregularReading`].
+    ///
+    /// [`IrregularReading`]: Tag::IrregularReading
+    pub fn is_irregular_reading(&self) -> bool {
+        matches!(self, Self::IrregularReading)
+    }
+
     /// Returns `true` if the tag is [`Hidden`].
     ///
     /// [`Hidden`]: Tag::Hidden
@@ -123,6 +234,14 @@ impl Tag {
         matches!(self, Self::Hidden)
     }
 
+    /// Returns `true` if the tag is [`Explain`].
+    ///
+    /// [`Explain`]: Tag::Explain
+    #[must_use]
+    pub fn is_explain(&self) -> bool {
+        matches!(self, Self::Explain)
+    }
+
     /// Returns `true` if the tag is [`SentenceTag`].
     ///
     /// [`SentenceTag`]: Tag::SentenceTag
@@ -141,3 +260,81 @@ impl Tag {
         }
     }
 }
+
+/// Name type hashtags which are suggested to users along with the name type they filter for
+const NAME_HASHTAGS: [(&str, NameType); 8] = [
+    ("surname", NameType::Surname),
+    ("given", NameType::Given),
+    ("female", NameType::Female),
+    ("male", NameType::Male),
+    ("person", NameType::Person),
+    ("place", NameType::Place),
+    ("station", NameType::RailwayStation),
+    ("company", NameType::Company),
+];
+
+/// Returns all hashtags which aren't part of the generated hashtag index, eg. field, dialect,
+/// loanword origin and name type tags. Each tag comes with the lowercase names it can be found
+/// by in all languages of `dict`
+pub fn extra_hashtags(dict: Option<&TranslationDict>) -> Vec<(RawHashtag, Vec<String>)> {
+    let fields = Field::iter().map(|i| (i.get_id(), i.get_id(), ""));
+    let dialects = Dialect::iter().map(|i| (i.get_id(), i.get_id(), ""));
+    let origins = ForeignLanguage::iter().map(|i| (i.get_id().trim(), i.get_id(), "from-"));
+    let sense_tags = fields
+        .chain(dialects)
+        .chain(origins)
+        .map(|(name, id, prefix)| (format!("{prefix}{name}"), id, prefix, SearchTarget::Words));
+
+    let name_tags = NAME_HASHTAGS
+        .iter()
+        .map(|(name, n_type)| (name.to_string(), n_type.get_id(), "", SearchTarget::Names));
+
+    sense_tags
+        .chain(name_tags)
+        .map(|(name, id, prefix, target)| {
+            let tag = hashtag(&name);
+            let aliases = dict
+                .map(|dict| tag_aliases(&tag, id, prefix, dict))
+                .unwrap_or_default();
+            (RawHashtag::new(tag, vec![target], 0.0), aliases)
+        })
+        .collect()
+}
+
+/// Returns the hashtags of [`extra_hashtags`] in the format they're suggested to users
+pub fn extra_hashtag_names() -> Vec<String> {
+    extra_hashtags(None).into_iter().map(|i| i.0.tag).collect()
+}
+
+/// Returns the names of the tag with the translation id `id` in all languages except the
+/// name `tag` already has
+fn tag_aliases(tag: &str, id: &'static str, prefix: &str, dict: &TranslationDict) -> Vec<String> {
+    let mut aliases: Vec<_> = Language::iter()
+        .map(|lang| {
+            let name = join_words(id.gettext(dict, Some(lang)), char::is_alphanumeric);
+            format!("{prefix}{}", name.to_lowercase())
+        })
+        .filter(|i| !i.is_empty() && !tag[1..].eq_ignore_ascii_case(i))
+        .collect();
+    aliases.sort();
+    aliases.dedup();
+    aliases
+}
+
+/// Formats `name` as hashtag, eg. `Go (game)` => `#Go-game`
+pub fn hashtag(name: &str) -> String {
+    format!("#{}", tag_name(name))
+}
+
+/// Formats `name` so it can be used as a hashtag by joining all of its words with `-`
+pub fn tag_name(name: &str) -> String {
+    join_words(name, |c| c.is_ascii_alphanumeric())
+}
+
+/// Joins all words of `s` with `-`. Words are separated by every character not matching `is_word`
+fn join_words<F: Fn(char) -> bool>(s: &str, is_word: F) -> String {
+    s.split(|c: char| !is_word(c))
+        .filter(|i| !i.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
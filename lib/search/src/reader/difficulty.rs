@@ -0,0 +1,184 @@
+use super::Token;
+use itertools::Itertools;
+use japanese::JapaneseExt;
+use sentence_reader::igo_unidic::WordClass;
+use std::collections::HashMap;
+use types::jotoba::{kanji::Kanji, words::Word};
+
+/// Share of known tokens (in percent) a JLPT level has to cover in order
+/// to be estimated as the level of a text
+pub const COVERAGE_THRESHOLD: f32 = 90.0;
+
+/// Difficulty report of a text
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    /// Amount of content tokens (no particles, symbols or spaces)
+    pub token_count: usize,
+    /// Content tokens per JLPT level. Index 0 holds tokens without a JLPT level
+    pub jlpt_tokens: [usize; 6],
+    /// All kanji of the text along with the amount of occurrences
+    pub kanji: Vec<(&'static Kanji, usize)>,
+    /// Tokens that couldn't be assigned to any word
+    pub unknown: Vec<String>,
+    /// Words which are neither common nor have a JLPT level assigned
+    pub rare: Vec<(&'static Word, usize)>,
+    /// Words worth learning before reading the text, most important first
+    pub vocabulary: Vec<(&'static Word, usize)>,
+    /// Estimated JLPT level of the text
+    pub estimated_jlpt: Option<u8>,
+}
+
+impl Report {
+    /// Returns the share of content tokens (in percent) for the given JLPT level. Level 0
+    /// represents tokens without a JLPT level
+    #[inline]
+    pub fn jlpt_share(&self, level: u8) -> f32 {
+        if self.token_count == 0 {
+            return 0.0;
+        }
+        let count = self.jlpt_tokens.get(level as usize).copied().unwrap_or(0);
+        count as f32 / self.token_count as f32 * 100.0
+    }
+}
+
+/// Reads `text` and builds a difficulty report with up to `top_n` words to learn
+pub fn analyze(text: &str, top_n: usize) -> Report {
+    let tokens: Vec<Token> = super::read_text(text).into_iter().flatten().collect();
+    analyze_tokens(&tokens, text, top_n)
+}
+
+/// Builds a difficulty report out of already read tokens
+pub fn analyze_tokens(tokens: &[Token], text: &str, top_n: usize) -> Report {
+    let mut report = Report::default();
+
+    let mut word_count: HashMap<u32, (&'static Word, usize)> = HashMap::new();
+
+    for token in tokens.iter().filter(|i| is_content_token(i)) {
+        let word = match token.words.first() {
+            Some(w) => *w,
+            None => {
+                report.unknown.push(token.part.get_normalized());
+                continue;
+            }
+        };
+
+        report.token_count += 1;
+        report.jlpt_tokens[jlpt_index(word)] += 1;
+
+        word_count.entry(word.sequence).or_insert((word, 0)).1 += 1;
+    }
+
+    report.unknown = report.unknown.into_iter().unique().collect();
+    report.estimated_jlpt = estimate_jlpt(&report.jlpt_tokens);
+    report.kanji = count_kanji(text);
+
+    let words = word_count
+        .into_values()
+        .sorted_by(|a, b| b.1.cmp(&a.1).then(a.0.sequence.cmp(&b.0.sequence)))
+        .collect::<Vec<_>>();
+
+    report.rare = words
+        .iter()
+        .filter(|(w, _)| !w.is_common() && w.get_jlpt_lvl().is_none())
+        .copied()
+        .collect();
+
+    let est = report.estimated_jlpt.unwrap_or(5);
+    report.vocabulary = words
+        .into_iter()
+        // Lower JLPT levels are harder
+        .filter(|(w, _)| w.get_jlpt_lvl().map(|i| i < est).unwrap_or(true))
+        .sorted_by(|a, b| b.1.cmp(&a.1).then(b.0.is_common().cmp(&a.0.is_common())))
+        .take(top_n)
+        .collect();
+
+    report
+}
+
+/// Estimates the JLPT level of a text based on the JLPT levels of its tokens. The estimated level
+/// is the easiest level which, along with all easier ones, covers at least [`COVERAGE_THRESHOLD`]
+/// percent of all tokens with a JLPT level. Index 0 of `jlpt_tokens` holds tokens without a JLPT
+/// level which are ignored as they can't be assigned to any level
+pub fn estimate_jlpt(jlpt_tokens: &[usize; 6]) -> Option<u8> {
+    let total: usize = jlpt_tokens[1..].iter().sum();
+    if total == 0 {
+        return None;
+    }
+
+    let mut covered = 0;
+    for lvl in (1..=5).rev() {
+        covered += jlpt_tokens[lvl];
+        if covered as f32 / total as f32 * 100.0 >= COVERAGE_THRESHOLD {
+            return Some(lvl as u8);
+        }
+    }
+
+    // Even N1 doesn't cover enough of the text
+    Some(1)
+}
+
+/// Estimates the JLPT level of a single sentence. Used to guess the JLPT level of example
+/// sentences, so only the JLPT levels of the tokens get counted instead of building a full report
+pub fn estimate_sentence_jlpt(sentence: &str) -> Option<u8> {
+    let mut jlpt_tokens = [0; 6];
+
+    let tokens = super::read_sentence(sentence);
+    for word in tokens
+        .iter()
+        .filter(|i| is_content_token(i))
+        .filter_map(|i| i.words.first())
+    {
+        jlpt_tokens[jlpt_index(word)] += 1;
+    }
+
+    estimate_jlpt(&jlpt_tokens)
+}
+
+/// Returns the index of the words JLPT level in [`Report::jlpt_tokens`]
+#[inline]
+fn jlpt_index(word: &Word) -> usize {
+    word.get_jlpt_lvl().unwrap_or(0).min(5) as usize
+}
+
+/// Returns `true` if the token holds actual content. Particles, symbols and spaces are ignored
+fn is_content_token(token: &Token) -> bool {
+    let normalized = token.part.get_normalized();
+    if !normalized.has_japanese() || normalized.is_symbol() {
+        return false;
+    }
+
+    !matches!(
+        token.part.word_class_raw(),
+        WordClass::Particle(_) | WordClass::Symbol | WordClass::Space
+    )
+}
+
+/// Returns all kanji of `text` ordered by their occurrences
+fn count_kanji(text: &str) -> Vec<(&'static Kanji, usize)> {
+    let kanji_retrieve = resources::get().kanji();
+
+    text.chars()
+        .filter(|i| i.is_kanji())
+        .counts()
+        .into_iter()
+        .filter_map(|(lit, count)| Some((kanji_retrieve.by_literal(lit)?, count)))
+        .sorted_by(|a, b| b.1.cmp(&a.1).then(a.0.literal.cmp(&b.0.literal)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_estimate_jlpt() {
+        assert_eq!(estimate_jlpt(&[0, 0, 0, 0, 0, 0]), None);
+        assert_eq!(estimate_jlpt(&[0, 0, 0, 0, 0, 10]), Some(5));
+        assert_eq!(estimate_jlpt(&[0, 0, 0, 1, 1, 8]), Some(4));
+        assert_eq!(estimate_jlpt(&[0, 0, 1, 0, 0, 9]), Some(5));
+        assert_eq!(estimate_jlpt(&[0, 1, 0, 0, 0, 5]), Some(1));
+        // Tokens without a JLPT level don't count
+        assert_eq!(estimate_jlpt(&[5, 0, 0, 0, 0, 5]), Some(5));
+        assert_eq!(estimate_jlpt(&[5, 0, 0, 0, 0, 0]), None);
+    }
+}
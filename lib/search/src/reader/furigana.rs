@@ -0,0 +1,58 @@
+use crate::word::producer::japanese::sentence_reader::set_part_furigana;
+use japanese::furigana::{self, SentencePart};
+use rayon::prelude::*;
+use sentence_reader::{Parser, Part};
+
+/// Tokenizes `text` and assigns readings to all of its kanji. Text which isn't covered by any
+/// token (whitespace, line breaks, unknown characters) is kept as is, so joining the kana of all
+/// returned parts results in the original text with kanji replaced by their readings
+pub fn annotate(text: &str) -> Vec<SentencePart> {
+    let sentences: Vec<_> = sentence_reader::split_sentences(text).collect();
+    let tokens: Vec<Vec<Part>> = sentences.into_par_iter().map(read_parts).collect();
+
+    let mut out = vec![];
+    let mut cursor = 0;
+
+    for part in tokens.into_iter().flatten() {
+        let inflected = part.get_inflected();
+        if inflected.is_empty() {
+            continue;
+        }
+
+        let pos = match text[cursor..].find(&inflected) {
+            Some(p) => cursor + p,
+            None => continue,
+        };
+
+        push_plain(&mut out, &text[cursor..pos]);
+        cursor = pos + inflected.len();
+
+        match part.furigana() {
+            Some(furi) => out.extend(furigana::parse::from_str(furi).map(|i| i.to_owned())),
+            None => push_plain(&mut out, &inflected),
+        }
+    }
+
+    push_plain(&mut out, &text[cursor..]);
+    out
+}
+
+/// Returns all parts of a sentence with furigana assigned
+pub(crate) fn read_parts(sentence: &str) -> Vec<Part> {
+    let mut parts = Parser::new(sentence).parts();
+    parts.iter_mut().for_each(set_part_furigana);
+    parts
+}
+
+/// Pushes `text` to `out` as kana only part, merging it with the previous part if it
+/// doesn't hold any kanji either
+fn push_plain(out: &mut Vec<SentencePart>, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+
+    match out.last_mut() {
+        Some(last) if !last.has_kanji() => last.kana.push_str(text),
+        _ => out.push(SentencePart::new(text.to_string())),
+    }
+}
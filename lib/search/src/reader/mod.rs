@@ -0,0 +1,70 @@
+pub mod difficulty;
+pub mod furigana;
+pub mod romaji;
+
+use crate::{
+    engine::words::native::Engine,
+    word::producer::japanese::sentence_reader::{set_part_furigana, WordFuriOrder},
+};
+use engine::task::SearchTask;
+use japanese::JapaneseExt;
+use rayon::prelude::*;
+use sentence_reader::{Parser, Part};
+use types::jotoba::words::Word;
+
+/// Max amount of words to match for a single token
+pub const MAX_TOKEN_WORDS: usize = 5;
+
+/// A single token of a read text along with the words it represents
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub part: Part,
+    pub words: Vec<&'static Word>,
+}
+
+impl Token {
+    /// Returns the JLPT level of the best matching word
+    #[inline]
+    pub fn jlpt_lvl(&self) -> Option<u8> {
+        self.words.iter().find_map(|i| i.get_jlpt_lvl())
+    }
+}
+
+/// Splits `text` into sentences and tokenizes each of them in parallel. The
+/// output is in the same order as the sentences appear in `text`
+pub fn read_text(text: &str) -> Vec<Vec<Token>> {
+    let sentences: Vec<_> = sentence_reader::split_sentences(text).collect();
+    sentences.into_par_iter().map(read_sentence).collect()
+}
+
+/// Tokenizes a single sentence and assigns furigana and matching words to each token
+pub fn read_sentence(sentence: &str) -> Vec<Token> {
+    Parser::new(sentence)
+        .parts()
+        .into_iter()
+        .map(|mut part| {
+            set_part_furigana(&mut part);
+            let words = find_words(&part);
+            Token { part, words }
+        })
+        .collect()
+}
+
+/// Returns all words which have the normalized form of `part` as reading, ordered by relevance
+pub fn find_words(part: &Part) -> Vec<&'static Word> {
+    let normalized = part.get_normalized();
+    if !normalized.has_japanese() || normalized.is_symbol() {
+        return vec![];
+    }
+
+    let pos = sentence_reader::part::wc_to_simple_pos(part.word_class_raw());
+    let reading = normalized.clone();
+
+    SearchTask::<Engine>::new(&normalized)
+        .with_limit(MAX_TOKEN_WORDS)
+        .with_custom_order(WordFuriOrder::new(pos, normalized))
+        .with_result_filter(move |i| i.has_reading(&reading))
+        .find()
+        .into_iter()
+        .collect()
+}
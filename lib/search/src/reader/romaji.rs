@@ -0,0 +1,73 @@
+use super::furigana::read_parts;
+use japanese::{
+    furigana,
+    romaji::{self, Options},
+    JapaneseExt,
+};
+use sentence_reader::{igo_unidic::WordClass, Part};
+
+/// Romanizes `text`. Kanji are romanized using the readings assigned by the sentence reader,
+/// words are separated by spaces
+pub fn romanize_text(text: &str, options: Options) -> String {
+    let mut out = String::new();
+
+    for sentence in sentence_reader::split_sentences(text) {
+        for part in read_parts(sentence) {
+            let inflected = part.get_inflected();
+            let reading = part_reading(&part);
+            let romanized = match verb_ending(&part, &reading) {
+                Some(end) => romaji::romanize_segments([&reading[..end], &reading[end..]], options),
+                None => romaji::romanize(&reading, options),
+            };
+            if romanized.trim().is_empty() {
+                continue;
+            }
+
+            let is_punctuation = inflected.chars().all(|c| c.is_symbol());
+            if !is_punctuation && !out.is_empty() && !out.ends_with(' ') {
+                out.push(' ');
+            }
+            out.push_str(romanized.trim());
+        }
+    }
+
+    out
+}
+
+/// Returns the byte position of the う ending of the verb in `reading` if `part` is a verb ending
+/// with う. The ending must not be merged with the vowel before (思う -> omou)
+fn verb_ending(part: &Part, reading: &str) -> Option<usize> {
+    let (verb, rest) = part.morphemes().split_first()?;
+    if !matches!(verb.word_class, WordClass::Verb(_)) || !verb.surface.ends_with('う') {
+        return None;
+    }
+
+    // Inflections are written in kana so they have the same length in the reading
+    let rest: String = rest.iter().map(|i| i.surface.as_str()).collect();
+    if rest.has_kanji() {
+        return None;
+    }
+
+    let end = reading.len().checked_sub(rest.len() + 'う'.len_utf8())?;
+    (reading.is_char_boundary(end) && reading[end..].starts_with('う')).then(|| end)
+}
+
+/// Returns the kana reading of `part`. Falls back to the inflected form if there are no
+/// readings available
+fn part_reading(part: &Part) -> String {
+    let inflected = part.get_inflected();
+
+    // Particles are pronounced differently than they're written
+    if matches!(part.word_class_raw(), WordClass::Particle(_)) {
+        match inflected.as_str() {
+            "は" => return "わ".to_string(),
+            "へ" => return "え".to_string(),
+            _ => (),
+        }
+    }
+
+    match part.furigana() {
+        Some(furi) => furigana::parse::from_str(furi).map(|i| i.kana).collect(),
+        None => inflected,
+    }
+}
@@ -1,4 +1,6 @@
+use crate::executor::ProducerRun;
 use std::ops::Deref;
+use types::jotoba::search::explain::Explanation;
 
 /// The final result of a search
 #[derive(Clone)]
@@ -6,6 +8,12 @@ pub struct SearchResult<T, O = ()> {
     pub items: Vec<T>,
     pub total: usize,
     pub other_data: O,
+    /// Ranking explanation of each item in `items`. Empty if the search wasn't asked to explain
+    pub explanations: Vec<Explanation>,
+    /// All producers which ran to find the items
+    pub producers: Vec<ProducerRun>,
+    /// Whether the search hit its deadline and `items` only contains the results found until then
+    pub truncated: bool,
 }
 
 impl<T> SearchResult<T, ()> {
@@ -16,6 +24,9 @@ impl<T> SearchResult<T, ()> {
             items,
             total,
             other_data: (),
+            explanations: vec![],
+            producers: vec![],
+            truncated: false,
         }
     }
 
@@ -25,6 +36,9 @@ impl<T> SearchResult<T, ()> {
             items,
             total,
             other_data: (),
+            explanations: vec![],
+            producers: vec![],
+            truncated: false,
         }
     }
 }
@@ -36,9 +50,33 @@ impl<T, O> SearchResult<T, O> {
             items,
             total,
             other_data,
+            explanations: vec![],
+            producers: vec![],
+            truncated: false,
         }
     }
 
+    /// Sets the ranking explanations of the items
+    #[inline]
+    pub fn with_explanations(mut self, explanations: Vec<Explanation>) -> Self {
+        self.explanations = explanations;
+        self
+    }
+
+    /// Sets the statistics of the producers which ran to find the items
+    #[inline]
+    pub fn with_producers(mut self, producers: Vec<ProducerRun>) -> Self {
+        self.producers = producers;
+        self
+    }
+
+    /// Marks the result as incomplete because the search hit its deadline
+    #[inline]
+    pub fn with_truncated(mut self, truncated: bool) -> Self {
+        self.truncated = truncated;
+        self
+    }
+
     #[inline]
     pub fn len(&self) -> usize {
         self.items.len()
@@ -61,6 +99,9 @@ impl<T, O: Default> SearchResult<T, O> {
             items,
             total,
             other_data: O::default(),
+            explanations: vec![],
+            producers: vec![],
+            truncated: false,
         }
     }
 }
@@ -3,6 +3,7 @@ pub mod result;
 
 use super::query::Query;
 use crate::{
+    cache,
     executor::{out_builder::OutputBuilder, producer::Producer, searchable::Searchable},
     query::Tag,
 };
@@ -58,6 +59,11 @@ impl<'a> Searchable for Search<'a> {
         self.query
     }
 
+    #[inline]
+    fn cache(&self) -> Option<&'static cache::SentenceCache> {
+        Some(&cache::SENTENCES)
+    }
+
     #[inline]
     fn filter(&self, item: &Self::Item) -> bool {
         !producer::filter::filter_sentence(self.query, item)
@@ -59,13 +59,17 @@ impl<'a> Producer for ForeignProducer<'a> {
             <Self::Target as Searchable>::ResAdd,
         >,
     ) {
-        self.task().find_to(out);
+        self.task().with_deadline(out.deadline()).find_to(out);
     }
 
     fn should_run(&self, _already_found: usize) -> bool {
         self.query.form.is_normal() && self.query.q_lang == QueryLang::Foreign
     }
 
+    fn is_independent(&self) -> bool {
+        true
+    }
+
     fn estimate_to(&self, out: &mut FilteredMaxCounter<<Self::Target as Searchable>::Item>) {
         self.task().estimate_to(out);
     }
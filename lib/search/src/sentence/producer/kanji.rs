@@ -1,5 +1,4 @@
 use japanese::JapaneseExt;
-use sentence_reader::JA_NL_PARSER;
 use types::jotoba::{
     kanji::reading::{Reading, ReadingSearch},
     sentences::Sentence,
@@ -32,8 +31,13 @@ pub(crate) fn sentence_matches(sentence: &Sentence, reading: &Reading) -> bool {
 
     // Kunyomi
 
+    let parser = match sentence_reader::parser() {
+        Some(parser) => parser,
+        None => return false,
+    };
+
     let formatted = reading.format_reading_with_literal();
-    for morph in JA_NL_PARSER.get().unwrap().parse(&sentence.japanese) {
+    for morph in parser.parse(&sentence.japanese) {
         let reading = morph.lexeme;
         if reading == formatted {
             return true;
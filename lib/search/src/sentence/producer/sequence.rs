@@ -49,4 +49,8 @@ impl<'a> Producer for SequenceProducer<'a> {
     fn should_run(&self, _already_found: usize) -> bool {
         self.query.form.is_sequence()
     }
+
+    fn is_independent(&self) -> bool {
+        true
+    }
 }
@@ -4,6 +4,7 @@ use crate::{
     sentence::Search,
 };
 use engine::{
+    deadline::Deadline,
     pushable::FilteredMaxCounter,
     pushable::{PushMod, Pushable},
     relevance::item::RelItem,
@@ -20,7 +21,7 @@ impl<'a> TagProducer<'a> {
         Self { query }
     }
 
-    fn find_to<P>(&self, out: &mut P)
+    fn find_to<P>(&self, out: &mut P, deadline: Deadline)
     where
         P: Pushable<Item = RelItem<&'static Sentence>>,
     {
@@ -31,29 +32,35 @@ impl<'a> TagProducer<'a> {
             .filter(|i| i.is_jlpt() || i.is_sentence_tag())
             .find(|i| i.is_producer())
             .unwrap();
-        self.push_tag(tag, out);
+        self.push_tag(tag, out, &deadline);
     }
 
-    pub fn push_tag<P>(&self, tag: &Tag, out: &mut P)
+    pub fn push_tag<P>(&self, tag: &Tag, out: &mut P, deadline: &Deadline)
     where
         P: Pushable<Item = RelItem<&'static Sentence>>,
     {
         let s_res = resources::get().sentences();
 
         match tag {
-            Tag::SentenceTag(sentence_tag) => self.push_iter(s_res.by_tag(sentence_tag), out),
-            Tag::Jlpt(jlpt) => self.push_iter(s_res.by_jlpt(*jlpt), out),
+            Tag::SentenceTag(sentence_tag) => {
+                self.push_iter(s_res.by_tag(sentence_tag), out, deadline)
+            }
+            Tag::Jlpt(jlpt) => self.push_iter(s_res.by_jlpt(*jlpt), out, deadline),
             _ => (),
         }
     }
 
-    fn push_iter<P, I>(&self, iter: I, out: &mut P)
+    fn push_iter<P, I>(&self, iter: I, out: &mut P, deadline: &Deadline)
     where
         P: Pushable<Item = RelItem<&'static Sentence>>,
         I: Iterator<Item = &'static Sentence>,
     {
         let mut c = 0;
-        for w in iter {
+        for (pos, w) in iter.enumerate() {
+            if deadline.expired_at(pos) {
+                break;
+            }
+
             let item = RelItem::new(w, c as f32);
             if out.push(item) {
                 c += 1;
@@ -75,12 +82,13 @@ impl<'a> Producer for TagProducer<'a> {
             <Self::Target as Searchable>::ResAdd,
         >,
     ) {
-        self.find_to(out);
+        let deadline = out.deadline();
+        self.find_to(out, deadline);
     }
 
     fn estimate_to(&self, out: &mut FilteredMaxCounter<<Self::Target as Searchable>::Item>) {
         let mut m = PushMod::new(out, |i: RelItem<&Sentence>| i.item);
-        self.find_to(&mut m);
+        self.find_to(&mut m, Deadline::NONE);
     }
 
     fn should_run(&self, _already_found: usize) -> bool {
@@ -93,4 +101,8 @@ impl<'a> Producer for TagProducer<'a> {
                 .filter(|i| i.is_jlpt() || i.is_sentence_tag())
                 .any(|i| i.is_producer())
     }
+
+    fn is_independent(&self) -> bool {
+        true
+    }
 }
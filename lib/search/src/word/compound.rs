@@ -0,0 +1,304 @@
+use crate::engine::words::native::regex::find_words;
+use std::{cmp::Reverse, collections::HashMap};
+use types::jotoba::words::Word;
+
+/// Max amount of characters a compound passed to `split` is allowed to have
+pub const MAX_SPLIT_LEN: usize = 32;
+
+/// Max amount of characters a single segment of a split compound can have
+const MAX_SEGMENT_LEN: usize = 8;
+
+/// Position of a term within a compound
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    Prefix,
+    Infix,
+    Suffix,
+}
+
+/// Options for a compound lookup
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Only return common words
+    pub common_only: bool,
+    /// Only look up compounds with the term at the given position
+    pub position: Option<Position>,
+    pub offset: usize,
+    pub limit: usize,
+}
+
+/// Compounds containing a term, grouped by the terms position
+#[derive(Debug, Default)]
+pub struct Compounds {
+    pub prefix: Group,
+    pub infix: Group,
+    pub suffix: Group,
+}
+
+impl Compounds {
+    /// Returns the group of compounds with the term at `pos`
+    #[inline]
+    pub fn get(&self, pos: Position) -> &Group {
+        match pos {
+            Position::Prefix => &self.prefix,
+            Position::Infix => &self.infix,
+            Position::Suffix => &self.suffix,
+        }
+    }
+
+    #[inline]
+    fn get_mut(&mut self, pos: Position) -> &mut Group {
+        match pos {
+            Position::Prefix => &mut self.prefix,
+            Position::Infix => &mut self.infix,
+            Position::Suffix => &mut self.suffix,
+        }
+    }
+}
+
+/// A page of compounds sharing the same position of the term
+#[derive(Debug, Default)]
+pub struct Group {
+    pub words: Vec<&'static Word>,
+    /// Amount of compounds in the group, ignoring pagination
+    pub total: usize,
+}
+
+/// A single segment of a split compound
+#[derive(Debug, Clone)]
+pub struct Segment {
+    pub text: String,
+    /// Dictionary word matching `text`. `None` if no word could be found
+    pub word: Option<&'static Word>,
+}
+
+/// Finds all compounds containing `term` in one of their readings. The words get grouped by the
+/// position of `term` and each group gets sorted by commonness and JLPT level
+pub fn find(term: &str, options: &Options) -> Compounds {
+    let mut compounds = Compounds::default();
+
+    let chars: Vec<char> = term.chars().collect();
+    if chars.is_empty() {
+        return compounds;
+    }
+
+    let index = indexes::get().word().regex();
+    let word_retrieve = resources::get().words();
+
+    let mut words: Vec<(Position, &'static Word)> = find_words(index, &chars)
+        .into_iter()
+        .filter_map(|seq| word_retrieve.by_sequence(seq))
+        .filter(|word| !options.common_only || word.is_common())
+        .filter(|word| !word.has_reading(term))
+        .filter_map(|word| {
+            let pos = word
+                .reading_iter(true)
+                .find_map(|r| position_in(&r.reading, term))?;
+            Some((pos, word))
+        })
+        .filter(|(pos, _)| options.position.map(|p| p == *pos).unwrap_or(true))
+        .collect();
+
+    words.sort_by_key(|(_, word)| word_order(word));
+
+    for (pos, word) in words {
+        let group = compounds.get_mut(pos);
+        if group.total >= options.offset && group.words.len() < options.limit {
+            group.words.push(word);
+        }
+        group.total += 1;
+    }
+
+    compounds
+}
+
+/// Splits a compound into the best matching sequence of dictionary words. Returns `None` if
+/// the compound doesn't contain any known word
+pub fn split(compound: &str) -> Option<Vec<Segment>> {
+    let chars: Vec<char> = compound.chars().collect();
+    if chars.len() < 2 || chars.len() > MAX_SPLIT_LEN {
+        return None;
+    }
+
+    let index = indexes::get().word().regex();
+    let word_retrieve = resources::get().words();
+
+    // Segments are looked up from short to long, so texts no word contains can be remembered
+    // to skip all longer segments starting with them
+    let mut no_words: Vec<String> = vec![];
+    let mut found: HashMap<String, Option<(&'static Word, bool)>> = HashMap::new();
+
+    let segments = best_split(&chars, |text| {
+        if no_words.iter().any(|i| text.starts_with(i.as_str())) {
+            return None;
+        }
+        if let Some(word) = found.get(text) {
+            return *word;
+        }
+
+        let chars: Vec<char> = text.chars().collect();
+        let words = find_words(index, &chars);
+        if words.is_empty() {
+            no_words.push(text.to_string());
+            return None;
+        }
+
+        let word = words
+            .into_iter()
+            .filter_map(|seq| word_retrieve.by_sequence(seq))
+            .filter(|word| word.has_reading(text))
+            .min_by_key(|word| word_order(word))
+            .map(|word| (word, word.is_common()));
+        found.insert(text.to_string(), word);
+        word
+    });
+
+    if segments.iter().all(|(_, _, word)| word.is_none()) {
+        return None;
+    }
+
+    let segments = segments
+        .into_iter()
+        .map(|(start, end, word)| Segment {
+            text: chars[start..end].iter().collect(),
+            word,
+        })
+        .collect();
+
+    Some(segments)
+}
+
+/// Order of compounds and split candidates. Common words come first, followed by words of
+/// an easier JLPT level and shorter words
+#[inline]
+fn word_order(word: &Word) -> (bool, Reverse<u8>, usize, u32) {
+    (
+        !word.is_common(),
+        Reverse(word.get_jlpt_lvl().unwrap_or(0)),
+        word.get_reading().reading.chars().count(),
+        word.sequence,
+    )
+}
+
+/// Returns the position of `term` within `reading`. `None` if `reading` doesn't contain `term`
+/// or is equal to it
+fn position_in(reading: &str, term: &str) -> Option<Position> {
+    if reading == term || !reading.contains(term) {
+        return None;
+    }
+
+    let pos = if reading.starts_with(term) {
+        Position::Prefix
+    } else if reading.ends_with(term) {
+        Position::Suffix
+    } else {
+        Position::Infix
+    };
+
+    Some(pos)
+}
+
+/// Cost of a split: (unknown characters, segments, uncommon words)
+type SplitCost = (usize, usize, usize);
+
+/// Splits `chars` into segments `(start, end, word)` using `lookup` to find a word (and whether
+/// it is common) for a given text. Prefers the split with the least unknown characters, then
+/// the least segments and then the least uncommon words. The whole text is never used as a
+/// single segment
+fn best_split<W, F>(chars: &[char], mut lookup: F) -> Vec<(usize, usize, Option<W>)>
+where
+    W: Copy,
+    F: FnMut(&str) -> Option<(W, bool)>,
+{
+    let len = chars.len();
+
+    // Best split for each position, along with the segment ending there
+    let mut best: Vec<Option<(SplitCost, (usize, Option<W>))>> = vec![None; len + 1];
+    best[0] = Some(((0, 0, 0), (0, None)));
+
+    for end in 1..=len {
+        let mut candidates = Vec::new();
+
+        for start in end.saturating_sub(MAX_SEGMENT_LEN)..end {
+            let ((unknown, segments, uncommon), _) = match best[start] {
+                Some(b) => b,
+                None => continue,
+            };
+
+            if start + 1 == end {
+                // Single unknown character as fallback
+                candidates.push(((unknown + 1, segments + 1, uncommon), (start, None)));
+            }
+
+            if start == 0 && end == len {
+                continue;
+            }
+
+            let text: String = chars[start..end].iter().collect();
+            if let Some((word, common)) = lookup(&text) {
+                let cost = (unknown, segments + 1, uncommon + (!common) as usize);
+                candidates.push((cost, (start, Some(word))));
+            }
+        }
+
+        best[end] = candidates.into_iter().min_by_key(|(cost, _)| *cost);
+    }
+
+    let mut segments = Vec::new();
+    let mut end = len;
+    while end > 0 {
+        let (_, (start, word)) = best[end].unwrap();
+        segments.push((start, end, word));
+        end = start;
+    }
+    segments.reverse();
+    segments
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("電話番号", "電話", Some(Position::Prefix); "prefix")]
+    #[test_case("携帯電話", "電話", Some(Position::Suffix); "suffix")]
+    #[test_case("国際電話料金", "電話", Some(Position::Infix); "infix")]
+    #[test_case("電話", "電話", None; "equal")]
+    #[test_case("番号", "電話", None; "not contained")]
+    fn test_position_in(reading: &str, term: &str, expected: Option<Position>) {
+        assert_eq!(position_in(reading, term), expected);
+    }
+
+    #[test_case("国際電話料金", &["国際", "電話", "料金"]; "simple")]
+    #[test_case("電話番号", &["電話", "番号"]; "whole word excluded")]
+    #[test_case("国際電話x", &["国際", "電話", "x"]; "unknown char")]
+    #[test_case("携帯電話", &["携帯", "電話"]; "prefer fewer segments")]
+    #[test_case("外国人", &["外国", "人"]; "prefer common")]
+    fn test_best_split(text: &str, expected: &[&str]) {
+        let dict: HashMap<&str, bool> = [
+            ("国際", true),
+            ("電話", true),
+            ("料金", true),
+            ("番号", true),
+            ("電話番号", true),
+            ("携帯", true),
+            ("携", false),
+            ("帯", false),
+            ("外国", true),
+            ("外", true),
+            ("国人", false),
+            ("人", true),
+        ]
+        .into_iter()
+        .collect();
+
+        let chars: Vec<char> = text.chars().collect();
+        let split = best_split(&chars, |t| dict.get(t).map(|c| ((), *c)));
+        let segments: Vec<String> = split
+            .iter()
+            .map(|(s, e, _)| chars[*s..*e].iter().collect())
+            .collect();
+
+        assert_eq!(segments, expected);
+    }
+}
@@ -20,6 +20,10 @@ impl WordFilter {
         #[inline]
         fn inner(wf: &WordFilter, word: &Word) -> Option<()> {
             wf.by_misc_tags(word)?;
+            wf.by_field_tags(word)?;
+            wf.by_dialect_tags(word)?;
+            wf.by_gairaigo_tags(word)?;
+            wf.by_irregular_reading(word)?;
             wf.by_language(word)?;
             wf.by_pos_tags(word)?;
             wf.by_jlpt(word)?;
@@ -62,6 +66,55 @@ impl WordFilter {
             .then(|| ())
     }
 
+    #[inline]
+    fn by_field_tags(&self, w: &Word) -> Option<()> {
+        self.query
+            .get_field_tags()
+            .all(|ft| w.has_field(ft))
+            .then(|| ())
+    }
+
+    #[inline]
+    fn by_dialect_tags(&self, w: &Word) -> Option<()> {
+        self.query
+            .get_dialect_tags()
+            .all(|dt| w.has_dialect(dt))
+            .then(|| ())
+    }
+
+    fn by_gairaigo_tags(&self, w: &Word) -> Option<()> {
+        let fully_derived = self.query.get_fully_derived();
+        let mut langs = self.query.get_gairaigo_tags().collect::<Vec<_>>();
+        if langs.is_empty() {
+            if fully_derived.is_none() {
+                return Some(());
+            }
+            langs.push(None);
+        }
+
+        langs
+            .into_iter()
+            .all(|lang| {
+                w.gairaigo().any(|g| {
+                    lang.map_or(true, |l| g.language == l)
+                        && fully_derived.map_or(true, |d| g.fully_derived == d)
+                })
+            })
+            .then(|| ())
+    }
+
+    #[inline]
+    fn by_irregular_reading(&self, w: &Word) -> Option<()> {
+        if !self.query.has_irregular_reading_tag() {
+            return Some(());
+        }
+
+        resources::get()
+            .words()
+            .has_irregular_reading(w.sequence)
+            .then(|| ())
+    }
+
     fn by_quot_marks(&self, w: &Word) -> Option<()> {
         if self.query.must_contain.is_empty() {
             return Some(());
@@ -1,3 +1,4 @@
+pub mod compound;
 pub mod filter;
 pub mod kanji;
 pub mod order;
@@ -5,6 +6,7 @@ pub mod producer;
 pub mod result;
 
 use crate::{
+    cache,
     executor::{out_builder::OutputBuilder, producer::Producer, searchable::Searchable},
     query::Query,
 };
@@ -12,6 +14,7 @@ use types::jotoba::words::{adjust_language, Word};
 
 use producer::{
     foreign::{romaji::RomajiProducer, ForeignProducer},
+    gairaigo::GairaigoProducer,
     japanese::NativeProducer,
     k_reading::KReadingProducer,
     regex::RegexProducer,
@@ -35,10 +38,13 @@ impl<'a> Search<'a> {
             Box::new(TagProducer::new(query)),
             Box::new(SeqProducer::new(query)),
             Box::new(RegexProducer::new(query)),
+            Box::new(GairaigoProducer::new(query)),
             Box::new(RomajiProducer::new(query)),
             Box::new(SReaderProducer::new(query)),
-            Box::new(NativeProducer::new(query)),
+            // Foreign and native queries exclude each other. Running the foreign producer first
+            // allows it to run along with the sentence reader producer
             Box::new(ForeignProducer::new(query)),
+            Box::new(NativeProducer::new(query)),
         ];
 
         let filter = WordFilter::new(query.clone());
@@ -63,12 +69,22 @@ impl<'a> Searchable for Search<'a> {
         self.query
     }
 
+    #[inline]
+    fn cache(&self) -> Option<&'static cache::WordCache> {
+        Some(&cache::WORDS)
+    }
+
     fn mod_output(&self, out: &mut OutputBuilder<Self::Item, Self::ResAdd>) {
         if out.output_add.raw_query.is_empty() {
             out.output_add.raw_query = self.query.raw_query.clone();
         }
     }
 
+    #[inline]
+    fn merge_res_add(&self, into: &mut Self::ResAdd, from: Self::ResAdd) {
+        into.merge(from);
+    }
+
     #[inline]
     fn to_output_item(&self, item: Self::Item) -> Self::OutItem {
         let mut item = item.to_owned();
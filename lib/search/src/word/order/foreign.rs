@@ -1,5 +1,5 @@
 use crate::engine::words::foreign::output::WordOutput;
-use engine::relevance::data::SortData;
+use engine::relevance::{data::SortData, explain::Explain};
 use indexes::relevance::RelevanceIndex;
 use spin::Mutex;
 use std::collections::HashMap;
@@ -51,7 +51,12 @@ impl ForeignOrder {
         Some(res as usize)
     }
 
-    pub fn score(&self, item: SortData<WordOutput, Vector, Vector>, user_lang: Language) -> usize {
+    pub fn score(
+        &self,
+        item: SortData<WordOutput, Vector, Vector>,
+        user_lang: Language,
+        explain: &mut Explain,
+    ) -> usize {
         let relevance = item.vec_similarity();
 
         /*
@@ -90,11 +95,21 @@ impl ForeignOrder {
                 self.gloss_relevance(&query_str, word.sequence, sense, sg_id)
                     .map(|i| (i as f32 * multilpier) as usize)
             })
-            .max()
-            .unwrap_or_else(|| {
-                super::foreign_search_fall_back(word, relevance, &query_str, query_lang, user_lang)
-                    * text_score
-            });
+            .max();
+
+        let gloss_relevance = match gloss_relevance {
+            Some(gloss_relevance) => {
+                explain.set("gloss relevance", gloss_relevance as f32);
+                gloss_relevance
+            }
+            None => {
+                let fall_back = super::foreign_search_fall_back(
+                    word, relevance, &query_str, query_lang, user_lang, explain,
+                );
+                explain.mul("text score", text_score as f32);
+                fall_back * text_score
+            }
+        };
 
         //println!("gloss relevance: {gloss_relevance}");
         //println!("text_score relevance: {text_score}");
@@ -2,7 +2,7 @@ pub mod foreign;
 pub mod native;
 
 use crate::{query::regex::RegexSQuery, SearchMode};
-use engine::relevance::data::SortData;
+use engine::relevance::{data::SortData, explain::Explain};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use types::jotoba::{
@@ -17,8 +17,14 @@ pub(crate) static REMOVE_PARENTHESES: Lazy<Regex> =
     Lazy::new(|| regex::Regex::new("\\(.*\\)").unwrap());
 
 /// Order for regex-search results
-pub fn regex_order(word: &Word, found_in: &str, _query: &RegexSQuery) -> usize {
+pub fn regex_order(
+    word: &Word,
+    found_in: &str,
+    _query: &RegexSQuery,
+    explain: &mut Explain,
+) -> usize {
     let mut score: usize = 100;
+    explain.set("base", score as f32);
 
     if !word
         .reading
@@ -27,18 +33,24 @@ pub fn regex_order(word: &Word, found_in: &str, _query: &RegexSQuery) -> usize {
         .any(|i| i.reading == found_in)
     {
         score += 20;
+        explain.add("main reading", 20.0);
     }
 
     if word.is_common() {
         score += 30;
+        explain.add("common", 30.0);
     }
 
     if let Some(jlpt) = word.get_jlpt_lvl() {
-        score += 10 + (jlpt * 2) as usize;
+        let jlpt_score = 10 + (jlpt * 2) as usize;
+        score += jlpt_score;
+        explain.add("JLPT level", jlpt_score as f32);
     }
 
     // Show shorter words more on top
-    score = score.saturating_sub(real_string_len(&word.get_reading().reading) * 3);
+    let len_penalty = real_string_len(&word.get_reading().reading) * 3;
+    score = score.saturating_sub(len_penalty);
+    explain.sub("reading length", len_penalty as f32);
 
     score
 }
@@ -49,20 +61,25 @@ pub fn foreign_search_fall_back(
     query_str: &str,
     query_lang: Language,
     user_lang: Language,
+    explain: &mut Explain,
 ) -> usize {
     let mut score: usize = (relevance * 20f32) as usize;
+    explain.set("text relevance", score as f32);
 
     if word.is_common() {
         score += 10;
+        explain.add("common", 10.0);
     }
 
     if let Some(jlpt) = word.get_jlpt_lvl() {
         score += (jlpt * 2) as usize;
+        explain.add("JLPT level", (jlpt * 2) as f32);
     }
 
     // Result found within users specified language
     if query_lang == user_lang {
         score += 12;
+        explain.add("user language", 12.0);
     }
 
     let found = match find_reading(word, query_str, user_lang, query_lang) {
@@ -79,35 +96,46 @@ pub fn foreign_search_fall_back(
         (_, true) => 3,
     };
 
-    score += (calc_likeliness(word, &found) / divisor) as usize;
+    let likeliness = (calc_likeliness(word, &found) / divisor) as usize;
+    score += likeliness;
+    explain.add("gloss position", likeliness as f32);
 
     if found.in_parentheses {
         score = score.saturating_sub(10);
+        explain.sub("in parentheses", 10.0);
     } else {
         score += 30;
+        explain.add("outside parentheses", 30.0);
     }
 
     score
 }
 
-pub(super) fn kanji_reading_search(item: SortData<&'static Word, Vector, Vector>) -> usize {
+pub(crate) fn kanji_reading_search(
+    item: SortData<&'static Word, Vector, Vector>,
+    explain: &mut Explain,
+) -> usize {
     let word = item.item();
     let mut score: usize = 0;
 
     if word.is_common() {
         score += 100;
+        explain.add("common", 100.0);
     }
 
     if let Some(jlpt) = word.get_jlpt_lvl() {
         score += jlpt as usize * 10;
+        explain.add("JLPT level", (jlpt as usize * 10) as f32);
     }
 
     if score == 0 {
         // Show shorter words on top if they aren't important
         let reading_len = word.reading.get_reading().reading.chars().count();
         score = 100usize.saturating_sub(reading_len * 2);
+        explain.set("reading length", score as f32);
     } else {
         score += 100;
+        explain.add("important", 100.0);
     }
 
     score
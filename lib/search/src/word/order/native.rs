@@ -1,4 +1,4 @@
-use engine::relevance::{data::SortData, RelevanceEngine};
+use engine::relevance::{data::SortData, explain::Explain, RelevanceEngine};
 use japanese::JapaneseExt;
 use ngindex2::{item::IndexItem, termset::TermSet};
 use types::jotoba::words::Word;
@@ -41,16 +41,27 @@ impl RelevanceEngine for NativeOrder {
     fn score<'item, 'query>(
         &self,
         item: &SortData<'item, 'query, Self::OutItem, Self::IndexItem, Self::Query>,
+    ) -> f32 {
+        self.score_explained(item, &mut Explain::disabled())
+    }
+
+    fn score_explained<'item, 'query>(
+        &self,
+        item: &SortData<'item, 'query, Self::OutItem, Self::IndexItem, Self::Query>,
+        explain: &mut Explain,
     ) -> f32 {
         let mut score = item.index_item().dice(item.query());
+        explain.set("dice similarity", score);
 
         if let Some(ref o_ts) = self.orig_query_ts {
             if self.w_index.unwrap_or(0) == 0 {
                 let new = item.index_item().dice(o_ts);
                 if new > score {
                     score = new;
+                    explain.set("original query dice similarity", score);
                 } else {
                     score *= 0.7;
+                    explain.mul("original query less similar", 0.7);
                 }
             }
         }
@@ -64,16 +75,19 @@ impl RelevanceEngine for NativeOrder {
 
         if kana == self._orig_query || reading == self._orig_query {
             score = (score * 10.0).min(1.0);
+            explain.set("exact match", score);
         }
 
         if word.jlpt_lvl.is_none() {
             score *= 0.99;
+            explain.mul("no JLPT level", 0.99);
         }
 
         // Is common
         if !word.is_common() {
             //score += 3.0;
             score *= 0.99;
+            explain.mul("not common", 0.99);
         }
 
         if !reading.starts_with(&query_str)
@@ -104,6 +118,7 @@ impl RelevanceEngine for NativeOrder {
         {
             //score += 60.0;
             score *= 0.8;
+            explain.mul("alternative reading", 0.8);
         }
 
         score
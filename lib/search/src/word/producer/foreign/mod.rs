@@ -33,6 +33,8 @@ impl<'a> Producer for ForeignProducer<'a> {
             <Self::Target as Searchable>::ResAdd,
         >,
     ) {
+        let deadline = out.deadline();
+
         // convert WordOutput -> Word
         let mut p_mod = PushMod::new(out, |i: RelItem<WordOutput>| i.map_item(|i| i.word));
 
@@ -41,12 +43,14 @@ impl<'a> Producer for ForeignProducer<'a> {
 
         ForeignSearch::new(self.query, q_str, lang)
             .task()
+            .with_deadline(deadline.clone())
             .find_to(&mut p_mod);
 
         // Add english results
         if lang != Language::English && self.query.show_english() {
             ForeignSearch::new(self.query, q_str, Language::English)
                 .task()
+                .with_deadline(deadline)
                 .find_to(&mut p_mod);
         }
     }
@@ -73,4 +77,8 @@ impl<'a> Producer for ForeignProducer<'a> {
     fn should_run(&self, _already_found: usize) -> bool {
         self.query.q_lang == QueryLang::Foreign && !self.query.query_str.is_empty()
     }
+
+    fn is_independent(&self) -> bool {
+        true
+    }
 }
@@ -1,4 +1,7 @@
-use japanese::guessing::could_be_romaji;
+use japanese::{
+    guessing::could_be_romaji,
+    romaji::{self, KanaVariant},
+};
 
 use crate::{
     engine::words::native::Engine,
@@ -6,19 +9,26 @@ use crate::{
     query::{Query, QueryLang},
     word::{producer::japanese::task::NativeSearch, Search},
 };
-use engine::{pushable::FilteredMaxCounter, task::SearchTask};
+use engine::{
+    pushable::{FilteredMaxCounter, PushMod},
+    relevance::item::RelItem,
+    task::SearchTask,
+};
+use types::jotoba::words::Word;
+
+/// Max amount of kana variants to search for. Each variant runs its own search task
+const MAX_VARIANTS: usize = 4;
 
 pub struct RomajiProducer<'a> {
     query: &'a Query,
+    variants: Vec<KanaVariant>,
 }
 
 impl<'a> RomajiProducer<'a> {
     pub fn new(query: &'a Query) -> Self {
-        Self { query }
-    }
-
-    fn hira_query(&self) -> String {
-        japanese::to_hira_fmt(&self.query.query_str)
+        let mut variants = romaji::kana_variants(&query.query_str);
+        variants.truncate(MAX_VARIANTS);
+        Self { query, variants }
     }
 
     fn kk_query(&self) -> String {
@@ -30,10 +40,9 @@ impl<'a> RomajiProducer<'a> {
         NativeSearch::new(self.query, &hira_query_str).task()
     }
 
-    fn hira_task(&self) -> SearchTask<'static, Engine> {
-        let hira_query_str = self.hira_query();
-        NativeSearch::new(self.query, &hira_query_str)
-            .with_custom_original_query(&hira_query_str)
+    fn hira_task(&self, variant: &KanaVariant) -> SearchTask<'static, Engine> {
+        NativeSearch::new(self.query, &variant.kana)
+            .with_custom_original_query(&variant.kana)
             .task()
     }
 }
@@ -48,12 +57,25 @@ impl<'a> Producer for RomajiProducer<'a> {
             <Self::Target as Searchable>::ResAdd,
         >,
     ) {
-        self.hira_task().find_to(out);
-        self.kk_task().find_to(out);
+        let deadline = out.deadline();
+        for variant in &self.variants {
+            // Rank exact-length matches above lengthened ones
+            let weight = variant.weight();
+            let mut out = PushMod::new(out, |mut i: RelItem<&'static Word>| {
+                i.scale("romaji variant", weight);
+                i
+            });
+            self.hira_task(variant)
+                .with_deadline(deadline.clone())
+                .find_to(&mut out);
+        }
+        self.kk_task().with_deadline(deadline).find_to(out);
     }
 
     fn estimate_to(&self, out: &mut FilteredMaxCounter<<Self::Target as Searchable>::Item>) {
-        self.hira_task().estimate_to(out);
+        for variant in &self.variants {
+            self.hira_task(variant).estimate_to(out);
+        }
         self.kk_task().estimate_to(out);
     }
 
@@ -63,4 +85,10 @@ impl<'a> Producer for RomajiProducer<'a> {
             && self.query.q_lang == QueryLang::Foreign
             && could_be_romaji(&self.query.query_str)
     }
+
+    fn is_independent(&self) -> bool {
+        // `should_run` depends on the amount of items found before, eg. by the gairaigo producer
+        // which also runs for foreign queries
+        false
+    }
 }
@@ -28,7 +28,8 @@ impl<'a> ForeignSearch<'a> {
 
         let lang = self.language;
         let orderer = order::foreign::ForeignOrder::new();
-        task.with_custom_order(move |item| orderer.score(item, lang));
+        task.with_explained_order(move |item, explain| orderer.score(item, lang, explain));
+        task.set_explain(self.query.explain);
 
         let filter = WordFilter::new(self.query.clone());
         task.set_result_filter(move |item| !filter.filter_word(item));
@@ -0,0 +1,62 @@
+use crate::{
+    executor::{out_builder::OutputBuilder, producer::Producer, searchable::Searchable},
+    query::Query,
+    word::Search,
+};
+use engine::{pushable::FilteredMaxCounter, pushable::Pushable, relevance::item::RelItem};
+use types::jotoba::words::Word;
+
+/// Relevance of loanwords whose original word matches the query. They're ranked above regular
+/// results as the query explicitly asks for loanwords. Small enough for `f32` to still tell
+/// apart the relevance of neighbouring positions
+const ORIGINAL_MATCH_RELEVANCE: f32 = 1_000_000.0;
+
+/// Producer for loanwords by their original word, eg. "Arbeit #gairaigo" or "Arbeit #from-german"
+pub struct GairaigoProducer<'a> {
+    query: &'a Query,
+}
+
+impl<'a> GairaigoProducer<'a> {
+    pub fn new(query: &'a Query) -> Self {
+        Self { query }
+    }
+
+    fn words(&self) -> Vec<&'static Word> {
+        let words = resources::get().words();
+        words.by_gairaigo_original(&self.query.query_str).collect()
+    }
+}
+
+impl<'a> Producer for GairaigoProducer<'a> {
+    type Target = Search<'a>;
+
+    fn produce(
+        &self,
+        out: &mut OutputBuilder<
+            <Self::Target as Searchable>::Item,
+            <Self::Target as Searchable>::ResAdd,
+        >,
+    ) {
+        let deadline = out.deadline();
+        for (pos, word) in self.words().into_iter().enumerate() {
+            if deadline.expired_at(pos) {
+                break;
+            }
+            out.push(RelItem::new(word, ORIGINAL_MATCH_RELEVANCE - pos as f32));
+        }
+    }
+
+    fn should_run(&self, _already_found: usize) -> bool {
+        self.query.is_gairaigo_search()
+    }
+
+    fn is_independent(&self) -> bool {
+        true
+    }
+
+    fn estimate_to(&self, out: &mut FilteredMaxCounter<<Self::Target as Searchable>::Item>) {
+        for word in self.words() {
+            out.push(word);
+        }
+    }
+}
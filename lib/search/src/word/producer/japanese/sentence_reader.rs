@@ -100,7 +100,10 @@ impl<'a> Producer for SReaderProducer<'a> {
         >,
     ) {
         if let ParseResult::InflectedWord(infl) = &self.parsed {
-            self.infl_task().unwrap().find_to(out);
+            self.infl_task()
+                .unwrap()
+                .with_deadline(out.deadline())
+                .find_to(out);
             out.output_add.inflection = InflectionInformation::from_part(infl);
             return;
         }
@@ -108,11 +111,17 @@ impl<'a> Producer for SReaderProducer<'a> {
         if let ParseResult::Sentence(mut sentence) = self.parsed.clone() {
             set_furigana(&mut sentence);
 
-            self.snt_task_normalized().unwrap().find_to(out);
+            self.snt_task_normalized()
+                .unwrap()
+                .with_deadline(out.deadline())
+                .find_to(out);
 
             let word = self.sentence_word().unwrap();
             if word.get_inflected() != word.get_normalized() {
-                self.snt_task_infl().unwrap().find_to(out);
+                self.snt_task_infl()
+                    .unwrap()
+                    .with_deadline(out.deadline())
+                    .find_to(out);
             }
 
             out.output_add.inflection = InflectionInformation::from_part(word);
@@ -143,6 +152,10 @@ impl<'a> Producer for SReaderProducer<'a> {
         !word_exists(&self.query.query_str)
     }
 
+    fn is_independent(&self) -> bool {
+        true
+    }
+
     fn estimate_to(&self, out: &mut FilteredMaxCounter<<Self::Target as Searchable>::Item>) {
         if let Some(infl) = self.infl_task() {
             infl.estimate_to(out);
@@ -179,11 +192,16 @@ fn word_exists(term: &str) -> bool {
 /// Generates furigana for a sentence
 fn set_furigana(s: &mut Sentence) {
     for part in s.iter_mut() {
-        let p = part.clone();
-        part.set_furigana(|inp| furigana_by_reading(inp, &p))
+        set_part_furigana(part);
     }
 }
 
+/// Generates furigana for a single part of a sentence
+pub(crate) fn set_part_furigana(part: &mut Part) {
+    let p = part.clone();
+    part.set_furigana(|inp| furigana_by_reading(inp, &p))
+}
+
 /// Returns furigana of the given `morpheme` if available
 fn furigana_by_reading(morpheme: &str, part: &sentence_reader::Part) -> Option<String> {
     word_furi(morpheme, part).or_else(|| name_furi(morpheme))
@@ -225,14 +243,14 @@ fn word_furi(morpheme: &str, part: &sentence_reader::Part) -> Option<String> {
     })
 }
 
-struct WordFuriOrder {
+pub(crate) struct WordFuriOrder {
     pos: Option<PosSimple>,
     morph: String,
 }
 
 impl WordFuriOrder {
     #[inline]
-    fn new(pos: Option<PosSimple>, morph: String) -> Self {
+    pub(crate) fn new(pos: Option<PosSimple>, morph: String) -> Self {
         Self { pos, morph }
     }
 }
@@ -54,6 +54,7 @@ impl<'a> NativeSearch<'a> {
             .with_custom_order(NativeOrder::new(original_query))
             .with_result_filter(move |item| !filter.filter_word(*item))
             .with_threshold(self.threshold)
+            .with_explain(self.query.explain)
     }
 
     #[inline]
@@ -1,4 +1,5 @@
 use engine::{
+    deadline::Deadline,
     pushable::FilteredMaxCounter,
     pushable::{PushMod, Pushable},
     relevance::item::RelItem,
@@ -39,7 +40,7 @@ impl<'a> KReadingProducer<'a> {
         Some(format!("{}{}", kanji.literal, reading.reading))
     }
 
-    fn find_to<P>(&self, out: &mut P)
+    fn find_to<P>(&self, out: &mut P, deadline: Deadline)
     where
         P: Pushable<Item = RelItem<&'static Word>>,
     {
@@ -48,8 +49,10 @@ impl<'a> KReadingProducer<'a> {
             None => return,
         };
 
-        let mut search_task = SearchTask::<k_reading::Engine>::new(&engine_query);
-        search_task.with_custom_order(move |item| order::kanji_reading_search(item));
+        let mut search_task =
+            SearchTask::<k_reading::Engine>::new(&engine_query).with_deadline(deadline);
+        search_task.with_explained_order(order::kanji_reading_search);
+        search_task.set_explain(self.query.explain);
         search_task.find_to(out);
     }
 }
@@ -64,16 +67,21 @@ impl<'a> Producer for KReadingProducer<'a> {
             <Self::Target as Searchable>::ResAdd,
         >,
     ) {
-        self.find_to(out);
+        let deadline = out.deadline();
+        self.find_to(out, deadline);
     }
 
     fn should_run(&self, _already_found: usize) -> bool {
         self.query.form.is_kanji_reading()
     }
 
+    fn is_independent(&self) -> bool {
+        true
+    }
+
     fn estimate_to(&self, out: &mut FilteredMaxCounter<<Self::Target as Searchable>::Item>) {
         let mut m = PushMod::new(out, |i: RelItem<&Word>| i.item);
         // TODO: use estimate_to here
-        self.find_to(&mut m);
+        self.find_to(&mut m, Deadline::NONE);
     }
 }
@@ -1,4 +1,5 @@
 pub mod foreign;
+pub mod gairaigo;
 pub mod japanese;
 pub mod k_reading;
 pub mod regex;
@@ -8,9 +8,10 @@ use crate::{
     word::{order::regex_order, Search},
 };
 use engine::{
+    deadline::Deadline,
     pushable::FilteredMaxCounter,
     pushable::{PushMod, Pushable},
-    relevance::item::RelItem,
+    relevance::{explain::Explain, item::RelItem},
 };
 
 pub struct RegexProducer<'a> {
@@ -27,13 +28,18 @@ impl<'a> RegexProducer<'a> {
         out: &mut P,
     ) -> Option<()> {
         let regex_query = self.query.as_regex_query()?;
-        search(&regex_query, |_, _| 0, out);
+        search(&regex_query, |_, _, _| 0, false, Deadline::NONE, out);
         Some(())
     }
 
-    fn find_to<P: Pushable<Item = RelItem<&'static Word>>>(&self, out: &mut P) -> Option<()> {
+    fn find_to<P: Pushable<Item = RelItem<&'static Word>>>(
+        &self,
+        out: &mut P,
+        deadline: Deadline,
+    ) -> Option<()> {
         let regex_query = self.query.as_regex_query()?;
-        search(&regex_query, |w, r| regex_order(w, r, &regex_query), out);
+        let sort = |w, r, e: &mut Explain| regex_order(w, r, &regex_query, e);
+        search(&regex_query, sort, self.query.explain, deadline, out);
         Some(())
     }
 }
@@ -48,22 +54,32 @@ impl<'a> Producer for RegexProducer<'a> {
             <Self::Target as Searchable>::ResAdd,
         >,
     ) {
-        self.find_to(out);
+        let deadline = out.deadline();
+        self.find_to(out, deadline);
     }
 
     fn should_run(&self, _already_found: usize) -> bool {
         self.query.as_regex_query().is_some()
     }
 
+    fn is_independent(&self) -> bool {
+        true
+    }
+
     fn estimate_to(&self, out: &mut FilteredMaxCounter<<Self::Target as Searchable>::Item>) {
         let mut mid = PushMod::new(out, |i: RelItem<&'static Word>| i.item);
         self.find_to_unsorted(&mut mid);
     }
 }
 
-pub fn search<'a, F, P>(query: &'a RegexSQuery, sort: F, out: &mut P)
-where
-    F: Fn(&'a Word, &'a str) -> usize,
+pub fn search<'a, F, P>(
+    query: &'a RegexSQuery,
+    sort: F,
+    explain: bool,
+    deadline: Deadline,
+    out: &mut P,
+) where
+    F: Fn(&'a Word, &'a str, &mut Explain) -> usize,
     P: Pushable<Item = RelItem<&'static Word>>,
 {
     let word_resources = resources::get().words();
@@ -71,15 +87,20 @@ where
     let index = indexes::get().word().regex();
     let possible_results = regex::find_words(index, &query.get_chars());
 
-    for seq_id in possible_results.into_iter().sorted() {
+    for (pos, seq_id) in possible_results.into_iter().sorted().enumerate() {
+        if deadline.expired_at(pos) {
+            break;
+        }
+
         let word = word_resources.by_sequence(seq_id).unwrap();
 
         let item_iter = word
             .reading_iter(true)
             .filter_map(|i| query.matches(&i.reading).then(|| (word, &i.reading)))
             .map(|(word, reading)| {
-                let order = sort(word, reading) as f32;
-                RelItem::new(word, order)
+                let mut explain = Explain::new(explain);
+                let order = sort(word, reading, &mut explain) as f32;
+                RelItem::new(word, order).with_explanation(explain.finish(order))
             });
 
         for i in item_iter {
@@ -42,6 +42,10 @@ impl<'a> Producer for SeqProducer<'a> {
         self.query.form.is_sequence()
     }
 
+    fn is_independent(&self) -> bool {
+        true
+    }
+
     fn estimate_to(&self, out: &mut FilteredMaxCounter<<Self::Target as Searchable>::Item>) {
         if let Some(word) = self.word() {
             out.push(word);
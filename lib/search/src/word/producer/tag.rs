@@ -6,6 +6,7 @@ use crate::{
     word::Search,
 };
 use engine::{
+    deadline::Deadline,
     pushable::FilteredMaxCounter,
     pushable::{PushMod, Pushable},
     relevance::item::RelItem,
@@ -24,43 +25,53 @@ impl<'a> TagProducer<'a> {
         self.query
             .tags
             .iter()
-            .filter(|i| i.is_producer() && !i.is_sentence_tag())
+            .filter(|i| i.is_producer() && !i.is_sentence_tag() && !i.is_name_type())
             // Use tag with fewest items that it'll produce to reduce the amount of items that have to be filtered
             .map(|i| (self.tag_len(i).unwrap_or(usize::MAX), i))
             .min_by_key(|i| i.0)
             .map(|i| i.1)
     }
 
-    fn find_to<P>(&self, out: &mut P)
+    fn find_to<P>(&self, out: &mut P, deadline: Deadline)
     where
         P: Pushable<Item = RelItem<&'static Word>>,
     {
         // Find first producer tag. All other tags are treated as filter
         let producer_tag = self.get_producer_tag().unwrap();
-        self.find_words(out, producer_tag);
+        self.find_words(out, producer_tag, &deadline);
     }
 
-    fn find_words<P>(&self, out: &mut P, tag: &Tag)
+    fn find_words<P>(&self, out: &mut P, tag: &Tag, deadline: &Deadline)
     where
         P: Pushable<Item = RelItem<&'static Word>>,
     {
         let words = resources::get().words();
         match tag {
-            Tag::PartOfSpeech(pos) => self.push_iter(words.by_pos_simple(*pos), out),
-            Tag::Misc(m) => self.push_iter(words.by_misc(*m), out),
-            Tag::Jlpt(jlpt) => self.push_iter(words.by_jlpt(*jlpt), out),
-            Tag::IrregularIruEru => self.push_iter(words.irregular_ichidan(), out),
+            Tag::PartOfSpeech(pos) => self.push_iter(words.by_pos_simple(*pos), out, deadline),
+            Tag::Misc(m) => self.push_iter(words.by_misc(*m), out, deadline),
+            Tag::Field(f) => self.push_iter(words.by_field(*f), out, deadline),
+            Tag::Dialect(d) => self.push_iter(words.by_dialect(*d), out, deadline),
+            Tag::Gairaigo(lang) => self.push_iter(words.by_foreign_lang(*lang), out, deadline),
+            // Filtered by derivation afterwards
+            Tag::FullyDerived(_) => self.push_iter(words.by_foreign_lang(None), out, deadline),
+            Tag::Jlpt(jlpt) => self.push_iter(words.by_jlpt(*jlpt), out, deadline),
+            Tag::IrregularIruEru => self.push_iter(words.irregular_ichidan(), out, deadline),
+            Tag::IrregularReading => self.push_iter(words.irregular_readings(), out, deadline),
             _ => (),
         }
     }
 
-    fn push_iter<P, I>(&self, iter: I, out: &mut P)
+    fn push_iter<P, I>(&self, iter: I, out: &mut P, deadline: &Deadline)
     where
         P: Pushable<Item = RelItem<&'static Word>>,
         I: Iterator<Item = &'static Word> + DoubleEndedIterator,
     {
         let mut c = 0;
-        for w in iter.rev() {
+        for (pos, w) in iter.rev().enumerate() {
+            if deadline.expired_at(pos) {
+                break;
+            }
+
             let item = RelItem::new(w, (1000 - c) as f32);
             if out.push(item) {
                 c += 1;
@@ -78,8 +89,13 @@ impl<'a> TagProducer<'a> {
         match tag {
             Tag::PartOfSpeech(p) => w_retr.pos_simple_len(p),
             Tag::Misc(m) => w_retr.misc_len(m),
+            Tag::Field(f) => w_retr.field_len(f),
+            Tag::Dialect(d) => w_retr.dialect_len(d),
+            Tag::Gairaigo(lang) => w_retr.foreign_lang_len(*lang),
+            Tag::FullyDerived(_) => w_retr.foreign_lang_len(None),
             Tag::Jlpt(j) => w_retr.jlpt_len(*j),
             Tag::IrregularIruEru => Some(w_retr.irregular_ichidan_len()),
+            Tag::IrregularReading => Some(w_retr.irregular_readings_len()),
             _ => None,
         }
     }
@@ -95,7 +111,8 @@ impl<'a> Producer for TagProducer<'a> {
             <Self::Target as Searchable>::ResAdd,
         >,
     ) {
-        self.find_to(out);
+        let deadline = out.deadline();
+        self.find_to(out, deadline);
     }
 
     fn should_run(&self, _already_found: usize) -> bool {
@@ -103,8 +120,12 @@ impl<'a> Producer for TagProducer<'a> {
         self.query.query_str.is_empty() && self.get_producer_tag().is_some()
     }
 
+    fn is_independent(&self) -> bool {
+        true
+    }
+
     fn estimate_to(&self, out: &mut FilteredMaxCounter<<Self::Target as Searchable>::Item>) {
         let mut mid = PushMod::new(out, |i: RelItem<&Word>| i.item);
-        self.find_to(&mut mid);
+        self.find_to(&mut mid, Deadline::NONE);
     }
 }
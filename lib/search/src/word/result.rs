@@ -38,6 +38,19 @@ impl AddResData {
     pub fn sentence_index(&self) -> usize {
         self.sentence.as_ref().map(|i| i.index).unwrap_or(0)
     }
+
+    /// Takes over all data set in `other`
+    pub fn merge(&mut self, other: AddResData) {
+        if other.sentence.is_some() {
+            self.sentence = other.sentence;
+        }
+        if other.inflection.is_some() {
+            self.inflection = other.inflection;
+        }
+        if !other.raw_query.is_empty() {
+            self.raw_query = other.raw_query;
+        }
+    }
 }
 
 impl InflectionInformation {
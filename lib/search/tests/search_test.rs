@@ -5,6 +5,7 @@ use search::{
     word::{kanji::load_word_kanji_info, result::AddResData},
     SearchExecutor,
 };
+use std::sync::Arc;
 use test_case::test_case;
 use types::jotoba::{
     languages::Language,
@@ -12,7 +13,7 @@ use types::jotoba::{
     words::{inflection::Inflection, part_of_speech::PosSimple, Word},
 };
 
-fn search(query: &Query) -> SearchResult<Word, AddResData> {
+fn search(query: &Query) -> Arc<SearchResult<Word, AddResData>> {
     let search = search::word::Search::new(query);
     SearchExecutor::new(search).run()
 }
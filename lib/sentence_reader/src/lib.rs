@@ -14,6 +14,9 @@ pub use igo_unidic;
 pub use output::Sentence;
 pub use sentence::part::{self, Part};
 
+/// Characters which end a sentence
+const SENTENCE_TERMINATORS: &[char] = &['。', '！', '？', '!', '?', '\n'];
+
 pub static JA_NL_PARSER: Lazy<OnceCell<igo_unidic::Parser>> = Lazy::new(|| OnceCell::new());
 
 pub fn load_parser<P: AsRef<Path>>(path: P) {
@@ -29,18 +32,31 @@ pub fn is_loaded() -> bool {
     JA_NL_PARSER.get().is_some()
 }
 
+/// Returns the loaded tokenizer or `None` if it isn't loaded yet
+#[inline]
+pub fn parser() -> Option<&'static igo_unidic::Parser> {
+    JA_NL_PARSER.get()
+}
+
+/// Splits a (longer) text into its sentences. The terminating characters are kept at the end of
+/// each sentence, empty sentences are skipped
+pub fn split_sentences(text: &str) -> impl Iterator<Item = &str> {
+    text.split_inclusive(SENTENCE_TERMINATORS)
+        .map(|i| i.trim())
+        .filter(|i| !i.is_empty())
+}
+
 /// Parser for sentence
 pub struct Parser<'input> {
     sentence_analyzer: SentenceAnalyzer<'input>,
 }
 
 impl<'input> Parser<'input> {
-    /// Creates a new InputTextParser
+    /// Creates a new InputTextParser. Panics if the tokenizer isn't loaded yet
     pub fn new(original: &'input str) -> Self {
-        let sentence_analyzer = SentenceAnalyzer::new(
-            analyzer::get_grammar_analyzer(),
-            JA_NL_PARSER.get().unwrap().parse(original),
-        );
+        let parser = parser().expect("Tokenizer not loaded");
+        let sentence_analyzer =
+            SentenceAnalyzer::new(analyzer::get_grammar_analyzer(), parser.parse(original));
 
         Self { sentence_analyzer }
     }
@@ -61,4 +77,10 @@ impl<'input> Parser<'input> {
 
         ParseResult::Sentence(Sentence::new(sent_parse))
     }
+
+    /// Returns all parts of the input without classifying it as a single (inflected) word or
+    /// sentence. Parts are returned in the order they appear in the input
+    pub fn parts(&self) -> Vec<Part> {
+        self.sentence_analyzer.analyze::<Part>()
+    }
 }
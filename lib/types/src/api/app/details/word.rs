@@ -2,7 +2,7 @@ use serde::Serialize;
 
 use crate::{
     api::{app::search::responses::kanji::Kanji, app::search::responses::words::Word},
-    jotoba::words::inflection::Inflections,
+    jotoba::words::{inflection::Inflections, reading_breakdown::ReadingBreakdown},
 };
 
 #[derive(Serialize)]
@@ -12,8 +12,14 @@ pub struct Details {
     kanji: Vec<Kanji>,
     #[serde(skip_serializing_if = "Option::is_none")]
     conjugations: Option<Inflections>,
+    /// Per kanji breakdown of the words reading
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reading_breakdown: Option<ReadingBreakdown>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     collocations: Vec<Word>,
+    /// Words referenced by or referencing the word
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related: Vec<Word>,
     has_sentence: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     transitivity_pair: Option<TransitivityPair>,
@@ -32,7 +38,9 @@ impl Details {
         word: Word,
         kanji: Vec<Kanji>,
         conjugations: Option<Inflections>,
+        reading_breakdown: Option<ReadingBreakdown>,
         collocations: Vec<Word>,
+        related: Vec<Word>,
         has_sentence: bool,
         transitivity_pair: Option<TransitivityPair>,
     ) -> Self {
@@ -40,7 +48,9 @@ impl Details {
             word,
             kanji,
             conjugations,
+            reading_breakdown,
             collocations,
+            related,
             has_sentence,
             transitivity_pair,
         }
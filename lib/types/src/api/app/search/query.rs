@@ -20,6 +20,10 @@ pub struct SearchPayload {
     /// Overwrite
     #[serde(default, deserialize_with = "deserialize_lang_option")]
     pub lang_overwrite: Option<Language>,
+
+    /// Add a breakdown of the ranking to each word result
+    #[serde(default)]
+    pub explain: bool,
 }
 
 /// APP settings
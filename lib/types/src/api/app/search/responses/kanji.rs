@@ -50,6 +50,22 @@ pub struct Kanji {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub on_compounds: Vec<CompoundWord>,
     pub radical: DetailedRadical,
+    /// Usage of the kanjis readings, sorted by usage
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub reading_usage: Vec<ReadingUsage>,
+}
+
+/// Usage statistics of a kanji reading
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReadingUsage {
+    pub reading: String,
+    /// Amount of words using the reading
+    pub count: u32,
+    /// Share of words using the reading in percent
+    pub percentage: f32,
+    /// Most relevant words using the reading
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<CompoundWord>,
 }
 
 impl Kanji {
@@ -62,6 +78,11 @@ impl Kanji {
     pub fn set_on_compounds(&mut self, on_compounds: Vec<CompoundWord>) {
         self.on_compounds = on_compounds;
     }
+
+    /// Set the usage statistics of the kanji's readings
+    pub fn set_reading_usage(&mut self, reading_usage: Vec<ReadingUsage>) {
+        self.reading_usage = reading_usage;
+    }
 }
 
 /// A word used in kanji compounds
@@ -120,6 +141,7 @@ impl From<crate::jotoba::kanji::Kanji> for Kanji {
             radical: k.radical,
             kun_compounds: vec![],
             on_compounds: vec![],
+            reading_usage: vec![],
         }
     }
 }
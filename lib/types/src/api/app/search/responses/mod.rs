@@ -13,6 +13,9 @@ pub struct Response<T: Serialize> {
     inner: Page<T>,
     #[serde(skip_serializing_if = "Option::is_none")]
     search_help: Option<SearchHelp>,
+    /// Whether the search hit its deadline and only the results found until then are returned
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    truncated: bool,
 }
 
 impl<T: Serialize> Response<T> {
@@ -20,6 +23,7 @@ impl<T: Serialize> Response<T> {
         Self {
             inner,
             search_help: None,
+            truncated: false,
         }
     }
 
@@ -27,6 +31,7 @@ impl<T: Serialize> Response<T> {
         Self {
             inner,
             search_help: Some(search_help),
+            truncated: false,
         }
     }
 
@@ -37,6 +42,7 @@ impl<T: Serialize> Response<T> {
         Self {
             search_help: help_fn(&inner),
             inner,
+            truncated: false,
         }
     }
 
@@ -44,4 +50,10 @@ impl<T: Serialize> Response<T> {
         self.search_help = Some(search_help);
         self
     }
+
+    /// Marks the response as incomplete
+    pub fn set_truncated(&mut self, truncated: bool) -> &mut Self {
+        self.truncated = truncated;
+        self
+    }
 }
@@ -2,9 +2,14 @@ use serde::{Deserialize, Serialize};
 
 use crate::jotoba::{
     languages::Language,
+    search::explain::Explanation,
     words::{
-        dialect::Dialect, field::Field, misc::Misc, part_of_speech::PartOfSpeech, pitch::Pitch,
-        sense::Gairaigo,
+        dialect::Dialect,
+        field::Field,
+        misc::Misc,
+        part_of_speech::PartOfSpeech,
+        pitch::Pitch,
+        sense::{Gairaigo, SenseRef},
     },
 };
 
@@ -30,6 +35,9 @@ pub struct Word {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub intransive_verion: Option<u32>,
     pub sentences_available: u16,
+    /// Breakdown of the ranking. Only set if the search was asked to explain its ranking
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<Explanation>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -46,6 +54,10 @@ pub struct Sense {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub antonym: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub xref_target: Option<SenseRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub antonym_target: Option<SenseRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub information: Option<String>,
     pub part_of_speech: Vec<PartOfSpeech>,
     pub language: Language,
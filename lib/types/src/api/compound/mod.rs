@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+
+/// Compound endpoint request
+#[derive(Deserialize)]
+pub struct Request {
+    /// The kanji or word to find compounds for
+    pub term: String,
+
+    /// Only return common words
+    #[serde(default)]
+    pub common_only: bool,
+
+    /// Only return compounds with the term at the given position
+    #[serde(default)]
+    pub position: Option<Position>,
+
+    /// Result page, starting at 1
+    #[serde(default = "default_page")]
+    pub page: usize,
+
+    /// Amount of compounds per position and page
+    #[serde(default = "default_page_size")]
+    pub page_size: usize,
+}
+
+#[inline]
+fn default_page() -> usize {
+    1
+}
+
+#[inline]
+fn default_page_size() -> usize {
+    20
+}
+
+/// Position of the term within a compound
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Position {
+    Prefix,
+    Infix,
+    Suffix,
+}
+
+/// Compound endpoint response
+#[derive(Serialize)]
+pub struct Response {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prefix: Option<Group>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    infix: Option<Group>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suffix: Option<Group>,
+}
+
+impl Response {
+    #[inline]
+    pub fn new(prefix: Option<Group>, infix: Option<Group>, suffix: Option<Group>) -> Self {
+        Self {
+            prefix,
+            infix,
+            suffix,
+        }
+    }
+}
+
+/// Compounds sharing the same position of the term
+#[derive(Serialize)]
+pub struct Group {
+    /// Amount of compounds in the group across all pages
+    total: usize,
+    words: Vec<CompoundWord>,
+}
+
+impl Group {
+    #[inline]
+    pub fn new(total: usize, words: Vec<CompoundWord>) -> Self {
+        Self { total, words }
+    }
+}
+
+/// A word found as compound or as part of a split compound
+#[derive(Serialize)]
+pub struct CompoundWord {
+    sequence: u32,
+    reading: String,
+    kana: String,
+    common: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    jlpt_lvl: Option<u8>,
+}
+
+impl CompoundWord {
+    #[inline]
+    pub fn new(
+        sequence: u32,
+        reading: String,
+        kana: String,
+        common: bool,
+        jlpt_lvl: Option<u8>,
+    ) -> Self {
+        Self {
+            sequence,
+            reading,
+            kana,
+            common,
+            jlpt_lvl,
+        }
+    }
+}
+
+/// Compound split endpoint request
+#[derive(Deserialize)]
+pub struct SplitRequest {
+    /// The compound to split into dictionary words
+    pub compound: String,
+}
+
+/// Compound split endpoint response
+#[derive(Serialize)]
+pub struct SplitResponse {
+    segments: Vec<Segment>,
+}
+
+impl SplitResponse {
+    #[inline]
+    pub fn new(segments: Vec<Segment>) -> Self {
+        Self { segments }
+    }
+}
+
+/// A single part of a split compound
+#[derive(Serialize)]
+pub struct Segment {
+    text: String,
+    /// The dictionary word for `text`. Omitted if no word could be found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    word: Option<CompoundWord>,
+}
+
+impl Segment {
+    #[inline]
+    pub fn new(text: String, word: Option<CompoundWord>) -> Self {
+        Self { text, word }
+    }
+}
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+/// Furigana endpoint request
+#[derive(Deserialize)]
+pub struct Request {
+    /// The text to annotate
+    pub text: String,
+    /// Output format of the annotated text
+    #[serde(default)]
+    pub format: Format,
+    /// Don't add furigana to kanji of this JLPT level or easier
+    #[serde(default)]
+    pub known_jlpt: Option<u8>,
+    /// Don't add furigana to kanji of this school grade or lower
+    #[serde(default)]
+    pub known_grade: Option<u8>,
+}
+
+/// Output format of annotated text
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// Jotobas furigana encoding: `[漢字|かん|じ]`
+    #[default]
+    Jotoba,
+    Html,
+    Anki,
+    Aozora,
+    /// List of segments
+    Json,
+}
+
+/// Furigana endpoint response
+#[derive(Serialize)]
+pub struct Response {
+    /// The annotated text. Set for all formats except json
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    /// Segments of the text. Only set for the json format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    segments: Option<Vec<Segment>>,
+}
+
+impl Response {
+    /// Creates a new response holding an annotated text
+    #[inline]
+    pub fn with_text(text: String) -> Self {
+        Self {
+            text: Some(text),
+            segments: None,
+        }
+    }
+
+    /// Creates a new response holding segments
+    #[inline]
+    pub fn with_segments(segments: Vec<Segment>) -> Self {
+        Self {
+            text: None,
+            segments: Some(segments),
+        }
+    }
+}
+
+/// A segment of an annotated text
+#[derive(Serialize)]
+pub struct Segment {
+    text: String,
+    /// Reading of `text`. None if `text` doesn't need furigana
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reading: Option<String>,
+}
+
+impl Segment {
+    #[inline]
+    pub fn new(text: String, reading: Option<String>) -> Self {
+        Self { text, reading }
+    }
+}
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+
+/// Confusables endpoint request. Either `kanji` or `jlpt` has to be set
+#[derive(Deserialize)]
+pub struct Request {
+    /// Kanji to return the confusable sets for
+    #[serde(default)]
+    pub kanji: Option<String>,
+
+    /// Return confusable sets for kanji of the given JLPT level
+    #[serde(default)]
+    pub jlpt: Option<u8>,
+
+    /// Max amount of sets to return
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+#[inline]
+fn default_limit() -> usize {
+    20
+}
+
+/// Confusables endpoint response
+#[derive(Serialize)]
+pub struct Response {
+    sets: Vec<ConfusableSet>,
+}
+
+impl Response {
+    #[inline]
+    pub fn new(sets: Vec<ConfusableSet>) -> Self {
+        Self { sets }
+    }
+}
+
+/// A kanji along with all kanji it can be confused with
+#[derive(Serialize)]
+pub struct ConfusableSet {
+    kanji: Entry,
+    /// Most similar kanji first
+    confusables: Vec<Entry>,
+}
+
+impl ConfusableSet {
+    #[inline]
+    pub fn new(kanji: Entry, confusables: Vec<Entry>) -> Self {
+        Self { kanji, confusables }
+    }
+}
+
+/// A single kanji of a confusable set
+#[derive(Serialize)]
+pub struct Entry {
+    literal: char,
+    stroke_count: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    jlpt: Option<u8>,
+    meanings: Vec<String>,
+    onyomi: Vec<String>,
+    kunyomi: Vec<String>,
+    /// Visual similarity to the kanji of the set, between 0 and 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    similarity: Option<f32>,
+}
+
+impl Entry {
+    #[inline]
+    pub fn new(
+        literal: char,
+        stroke_count: u8,
+        jlpt: Option<u8>,
+        meanings: Vec<String>,
+        onyomi: Vec<String>,
+        kunyomi: Vec<String>,
+        similarity: Option<f32>,
+    ) -> Self {
+        Self {
+            literal,
+            stroke_count,
+            jlpt,
+            meanings,
+            onyomi,
+            kunyomi,
+            similarity,
+        }
+    }
+}
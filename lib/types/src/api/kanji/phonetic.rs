@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+/// Phonetic series endpoint request
+#[derive(Deserialize)]
+pub struct Request {
+    /// A phonetic component or a kanji containing one
+    pub literal: char,
+}
+
+/// Phonetic series endpoint response
+#[derive(Serialize)]
+pub struct Response {
+    series: Vec<Series>,
+}
+
+impl Response {
+    #[inline]
+    pub fn new(series: Vec<Series>) -> Self {
+        Self { series }
+    }
+}
+
+/// Kanji sharing a phonetic component
+#[derive(Serialize)]
+pub struct Series {
+    component: char,
+    /// The onyomi most members share
+    reading: String,
+    /// Amount of members having `reading` as onyomi
+    regular: usize,
+    /// Share of members having `reading` as onyomi, between 0 and 1
+    regularity: f32,
+    members: Vec<Member>,
+}
+
+impl Series {
+    #[inline]
+    pub fn new(
+        component: char,
+        reading: String,
+        regular: usize,
+        regularity: f32,
+        members: Vec<Member>,
+    ) -> Self {
+        Self {
+            component,
+            reading,
+            regular,
+            regularity,
+            members,
+        }
+    }
+}
+
+/// A single kanji of a phonetic series
+#[derive(Serialize)]
+pub struct Member {
+    literal: char,
+    onyomi: Vec<String>,
+    /// Whether the kanji has the series reading
+    regular: bool,
+}
+
+impl Member {
+    #[inline]
+    pub fn new(literal: char, onyomi: Vec<String>, regular: bool) -> Self {
+        Self {
+            literal,
+            onyomi,
+            regular,
+        }
+    }
+}
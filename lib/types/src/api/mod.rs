@@ -1,7 +1,11 @@
 pub mod app;
 pub mod completions;
+pub mod compound;
+pub mod furigana;
 pub mod image;
 pub mod kanji;
 pub mod news;
 pub mod radical;
+pub mod reader;
+pub mod romaji;
 pub mod search;
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+
+/// Difficulty endpoint request
+#[derive(Deserialize)]
+pub struct Request {
+    /// The text to analyze
+    pub text: String,
+
+    /// Max amount of words to return for pre-learning
+    #[serde(default = "default_top_n")]
+    pub top_n: usize,
+}
+
+/// Default amount of words to pre-learn
+#[inline]
+fn default_top_n() -> usize {
+    20
+}
+
+/// Difficulty endpoint response
+#[derive(Serialize)]
+pub struct Response {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    estimated_jlpt: Option<u8>,
+    /// Amount of analyzed tokens
+    token_count: usize,
+    /// Share of tokens for each JLPT level
+    jlpt_levels: Vec<JlptShare>,
+    kanji: Vec<Kanji>,
+    /// Tokens which couldn't be assigned to a word
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    unknown: Vec<String>,
+    /// Neither common nor JLPT words
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    rare: Vec<Vocabulary>,
+    /// Words to learn before reading the text
+    vocabulary: Vec<Vocabulary>,
+}
+
+impl Response {
+    #[inline]
+    pub fn new(
+        estimated_jlpt: Option<u8>,
+        token_count: usize,
+        jlpt_levels: Vec<JlptShare>,
+        kanji: Vec<Kanji>,
+        unknown: Vec<String>,
+        rare: Vec<Vocabulary>,
+        vocabulary: Vec<Vocabulary>,
+    ) -> Self {
+        Self {
+            estimated_jlpt,
+            token_count,
+            jlpt_levels,
+            kanji,
+            unknown,
+            rare,
+            vocabulary,
+        }
+    }
+}
+
+/// Share of tokens for a single JLPT level
+#[derive(Serialize)]
+pub struct JlptShare {
+    /// JLPT level. `None` for tokens without level
+    #[serde(skip_serializing_if = "Option::is_none")]
+    level: Option<u8>,
+    count: usize,
+    percent: f32,
+}
+
+impl JlptShare {
+    #[inline]
+    pub fn new(level: Option<u8>, count: usize, percent: f32) -> Self {
+        Self {
+            level,
+            count,
+            percent,
+        }
+    }
+}
+
+/// A kanji occurring in the text
+#[derive(Serialize)]
+pub struct Kanji {
+    literal: char,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    grade: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    jlpt: Option<u8>,
+    /// Occurrences in the text
+    count: usize,
+}
+
+impl Kanji {
+    #[inline]
+    pub fn new(literal: char, grade: Option<u8>, jlpt: Option<u8>, count: usize) -> Self {
+        Self {
+            literal,
+            grade,
+            jlpt,
+            count,
+        }
+    }
+}
+
+/// A word occurring in the text
+#[derive(Serialize)]
+pub struct Vocabulary {
+    sequence: u32,
+    reading: String,
+    kana: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    jlpt_lvl: Option<u8>,
+    /// Occurrences in the text
+    count: usize,
+}
+
+impl Vocabulary {
+    #[inline]
+    pub fn new(
+        sequence: u32,
+        reading: String,
+        kana: String,
+        jlpt_lvl: Option<u8>,
+        count: usize,
+    ) -> Self {
+        Self {
+            sequence,
+            reading,
+            kana,
+            jlpt_lvl,
+            count,
+        }
+    }
+}
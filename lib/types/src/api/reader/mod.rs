@@ -0,0 +1,83 @@
+pub mod difficulty;
+
+use crate::jotoba::words::inflection::Inflection;
+use serde::{Deserialize, Serialize};
+
+/// Reader endpoint request
+#[derive(Deserialize)]
+pub struct Request {
+    /// The text to read
+    pub text: String,
+}
+
+/// Reader endpoint response
+#[derive(Serialize)]
+pub struct Response {
+    sentences: Vec<Sentence>,
+}
+
+impl Response {
+    #[inline]
+    pub fn new(sentences: Vec<Sentence>) -> Self {
+        Self { sentences }
+    }
+}
+
+/// A single sentence of the read text
+#[derive(Serialize)]
+pub struct Sentence {
+    tokens: Vec<Token>,
+}
+
+impl Sentence {
+    #[inline]
+    pub fn new(tokens: Vec<Token>) -> Self {
+        Self { tokens }
+    }
+}
+
+/// A single token (word) within a sentence
+#[derive(Serialize)]
+pub struct Token {
+    /// The token as it appears in the text
+    inflected: String,
+    /// Dictionary form of the token
+    normalized: String,
+    /// Furigana of the inflected token. None if can't be
+    /// calculated or the token is written in kana only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    furigana: Option<String>,
+    /// Part of Speech
+    #[serde(skip_serializing_if = "Option::is_none")]
+    word_class: Option<&'static str>,
+    /// Sequence IDs of the words matching the token, best match first
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    sequences: Vec<u32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    inflections: Vec<Inflection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    jlpt_lvl: Option<u8>,
+}
+
+impl Token {
+    #[inline]
+    pub fn new(
+        inflected: String,
+        normalized: String,
+        furigana: Option<String>,
+        word_class: Option<&'static str>,
+        sequences: Vec<u32>,
+        inflections: Vec<Inflection>,
+        jlpt_lvl: Option<u8>,
+    ) -> Self {
+        Self {
+            inflected,
+            normalized,
+            furigana,
+            word_class,
+            sequences,
+            inflections,
+            jlpt_lvl,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// Romaji endpoint request
+#[derive(Deserialize)]
+pub struct Request {
+    /// The text to romanize
+    pub text: String,
+    #[serde(default)]
+    pub system: System,
+    #[serde(default)]
+    pub long_vowels: LongVowels,
+}
+
+/// Romanization system
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum System {
+    #[default]
+    Hepburn,
+    Kunrei,
+    Nihon,
+}
+
+/// Notation of long vowels
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LongVowels {
+    #[default]
+    Macron,
+    Circumflex,
+    Spelled,
+}
+
+/// Romaji endpoint response
+#[derive(Serialize)]
+pub struct Response {
+    romaji: String,
+}
+
+impl Response {
+    #[inline]
+    pub fn new(romaji: String) -> Self {
+        Self { romaji }
+    }
+}
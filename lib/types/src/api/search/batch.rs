@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+use super::{kanji, name, sentence, word};
+use crate::jotoba::{languages::Language, search::SearchTarget};
+
+/// Batch search request
+#[derive(Deserialize)]
+pub struct Request {
+    pub items: Vec<Item>,
+
+    /// Language of all items which don't set one
+    #[serde(default)]
+    pub language: Language,
+
+    #[serde(default)]
+    pub no_english: bool,
+
+    /// Add a (Hepburn) romanization to word and name results
+    #[serde(default)]
+    pub romaji: bool,
+}
+
+/// A single lookup of a batch request. Either `query` or `sequence` has to be set
+#[derive(Deserialize)]
+pub struct Item {
+    #[serde(default)]
+    pub query: Option<String>,
+
+    /// Sequence ID of a word, name or sentence to look up. Kanji don't have one
+    #[serde(default)]
+    pub sequence: Option<u32>,
+
+    #[serde(default)]
+    pub target: SearchTarget,
+
+    #[serde(default)]
+    pub language: Option<Language>,
+}
+
+/// Batch search response
+#[derive(Serialize)]
+pub struct Response {
+    /// Results in the same order as the requested items
+    results: Vec<ItemResult>,
+}
+
+impl Response {
+    #[inline]
+    pub fn new(results: Vec<ItemResult>) -> Self {
+        Self { results }
+    }
+}
+
+/// Result of a single item of a batch request
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemResult {
+    Words(word::Response),
+    Kanji(kanji::Response),
+    Names(name::Response),
+    Sentences(sentence::Response),
+    Error(ItemError),
+}
+
+/// Error of a single item. Has the same format as the error of a failed request
+#[derive(Serialize)]
+pub struct ItemError {
+    code: u16,
+    error: String,
+    message: String,
+}
+
+impl ItemError {
+    #[inline]
+    pub fn new(code: u16, error: String, message: String) -> Self {
+        Self {
+            code,
+            error,
+            message,
+        }
+    }
+}
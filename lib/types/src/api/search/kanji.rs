@@ -33,6 +33,63 @@ pub struct Kanji {
     radical: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     stroke_frames: Option<String>,
+    /// Usage of the kanjis readings, sorted by usage
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    reading_usage: Vec<ReadingUsage>,
+}
+
+/// Usage statistics of a kanji reading
+#[derive(Serialize, Deserialize)]
+pub struct ReadingUsage {
+    reading: String,
+    /// Amount of words using the reading
+    count: u32,
+    /// Share of words using the reading in percent
+    percentage: f32,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    examples: Vec<ReadingExample>,
+}
+
+/// A word using a kanji reading
+#[derive(Serialize, Deserialize)]
+pub struct ReadingExample {
+    word: String,
+    kana: String,
+}
+
+impl Kanji {
+    /// Sets the usage statistics of the kanjis readings
+    #[inline]
+    pub fn set_reading_usage(&mut self, reading_usage: Vec<ReadingUsage>) {
+        self.reading_usage = reading_usage;
+    }
+}
+
+impl ReadingUsage {
+    #[inline]
+    pub fn new(
+        reading: String,
+        count: u32,
+        percentage: f32,
+        examples: Vec<ReadingExample>,
+    ) -> Self {
+        Self {
+            reading,
+            count,
+            percentage,
+            examples,
+        }
+    }
+}
+
+impl From<&crate::jotoba::words::Word> for ReadingExample {
+    #[inline]
+    fn from(word: &crate::jotoba::words::Word) -> Self {
+        Self {
+            word: word.get_reading().reading.clone(),
+            kana: word.reading.kana.reading.clone(),
+        }
+    }
 }
 
 impl From<&crate::jotoba::kanji::Kanji> for Kanji {
@@ -58,6 +115,7 @@ impl From<&crate::jotoba::kanji::Kanji> for Kanji {
             parts: kanji.parts.iter().map(|i| i.to_string()).collect(),
             radical: kanji.radical.literal.to_string(),
             stroke_frames: frames,
+            reading_usage: vec![],
         }
     }
 }
@@ -1,3 +1,4 @@
+pub mod batch;
 pub mod kanji;
 pub mod name;
 pub mod sentence;
@@ -18,4 +19,12 @@ pub struct SearchRequest {
 
     #[serde(default)]
     pub no_english: bool,
+
+    /// Add a (Hepburn) romanization to word and name results
+    #[serde(default)]
+    pub romaji: bool,
+
+    /// Add a breakdown of the ranking to each word result
+    #[serde(default)]
+    pub explain: bool,
 }
@@ -5,6 +5,12 @@ use crate::jotoba::names::name_type::NameType;
 #[derive(Serialize, Deserialize)]
 pub struct Response {
     names: Vec<Name>,
+    /// Katakana transcriptions of the query if it is a name in latin script
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    transcriptions: Vec<String>,
+    /// Whether the search hit its deadline and only the results found until then are returned
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    truncated: bool,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -15,6 +21,8 @@ pub struct Name {
     pub transcription: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name_type: Option<Vec<NameType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub romaji: Option<String>,
 }
 
 impl From<&crate::jotoba::names::Name> for Name {
@@ -25,14 +33,39 @@ impl From<&crate::jotoba::names::Name> for Name {
             kanji: name.kanji.clone(),
             transcription: name.transcription.clone(),
             name_type: name.name_type.clone(),
+            romaji: None,
         }
     }
 }
 
+impl Response {
+    /// Returns a mutable reference to all names of the response
+    #[inline]
+    pub fn names_mut(&mut self) -> &mut Vec<Name> {
+        &mut self.names
+    }
+
+    /// Sets the katakana transcriptions of the response
+    #[inline]
+    pub fn set_transcriptions(&mut self, transcriptions: Vec<String>) {
+        self.transcriptions = transcriptions;
+    }
+
+    /// Marks the response as incomplete
+    #[inline]
+    pub fn set_truncated(&mut self, truncated: bool) {
+        self.truncated = truncated;
+    }
+}
+
 impl From<Vec<&crate::jotoba::names::Name>> for Response {
     #[inline]
     fn from(name: Vec<&crate::jotoba::names::Name>) -> Self {
         let names: Vec<Name> = name.into_iter().map(Name::from).collect();
-        Self { names }
+        Self {
+            names,
+            transcriptions: vec![],
+            truncated: false,
+        }
     }
 }
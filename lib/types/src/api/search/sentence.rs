@@ -5,6 +5,17 @@ use crate::jotoba::languages::Language;
 #[derive(Serialize, Deserialize)]
 pub struct Response {
     sentences: Vec<Sentence>,
+    /// Whether the search hit its deadline and only the results found until then are returned
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    truncated: bool,
+}
+
+impl Response {
+    /// Marks the response as incomplete
+    #[inline]
+    pub fn set_truncated(&mut self, truncated: bool) {
+        self.truncated = truncated;
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -20,6 +31,9 @@ pub struct Sentence {
 impl From<Vec<Sentence>> for Response {
     #[inline]
     fn from(sentences: Vec<Sentence>) -> Self {
-        Self { sentences }
+        Self {
+            sentences,
+            truncated: false,
+        }
     }
 }
@@ -2,9 +2,10 @@ use crate::{
     api::search::kanji::Kanji,
     jotoba::{
         languages::Language,
+        search::explain::Explanation,
         words::{
             dialect::Dialect, field::Field, misc::Misc, part_of_speech::PartOfSpeech,
-            pitch::PitchPart,
+            pitch::PitchPart, sense::SenseRef,
         },
     },
 };
@@ -16,11 +17,24 @@ use serde::{Deserialize, Serialize};
 pub struct Response {
     kanji: Vec<Kanji>,
     words: Vec<Word>,
+    /// Whether the search hit its deadline and only the results found until then are returned
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    truncated: bool,
 }
 
 impl Response {
     pub fn new(words: Vec<Word>, kanji: Vec<Kanji>) -> Self {
-        Self { kanji, words }
+        Self {
+            kanji,
+            words,
+            truncated: false,
+        }
+    }
+
+    /// Marks the response as incomplete
+    #[inline]
+    pub fn set_truncated(&mut self, truncated: bool) {
+        self.truncated = truncated;
     }
 }
 
@@ -36,6 +50,30 @@ pub struct Word {
     audio: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pitch: Option<Vec<PitchPart>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    romaji: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    explanation: Option<Explanation>,
+}
+
+impl Word {
+    /// Returns the kana reading of the word
+    #[inline]
+    pub fn kana(&self) -> &str {
+        &self.reading.kana
+    }
+
+    /// Sets the romanized reading of the word
+    #[inline]
+    pub fn set_romaji(&mut self, romaji: String) {
+        self.romaji = Some(romaji);
+    }
+
+    /// Sets the breakdown of the words ranking
+    #[inline]
+    pub fn set_explanation(&mut self, explanation: Explanation) {
+        self.explanation = Some(explanation);
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -64,6 +102,10 @@ pub struct Sense {
     misc: Option<Misc>,
     #[serde(skip_serializing_if = "Option::is_none")]
     xref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    xref_target: Option<SenseRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    antonym_target: Option<SenseRef>,
 }
 
 impl From<&crate::jotoba::words::sense::Sense> for Sense {
@@ -86,6 +128,8 @@ impl From<&crate::jotoba::words::sense::Sense> for Sense {
             antonym: sense.antonym.as_ref().cloned(),
             misc: sense.misc,
             xref: sense.xref.as_ref().cloned(),
+            xref_target: sense.xref_target,
+            antonym_target: sense.antonym_target,
         }
     }
 }
@@ -113,6 +157,8 @@ impl From<&crate::jotoba::words::Word> for Word {
             alt_readings: None,
             audio: word.audio_file().as_ref().map(|i| format!("/audio/{}", i)),
             pitch,
+            romaji: None,
+            explanation: None,
         }
     }
 }
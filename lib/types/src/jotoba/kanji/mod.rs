@@ -1,5 +1,7 @@
+pub mod phonetic;
 pub mod radical;
 pub mod reading;
+pub mod similarity;
 
 use std::{char, path::Path};
 
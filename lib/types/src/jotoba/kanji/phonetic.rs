@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Min amount of kanji which have to share the reading of a component in order to consider
+/// the component as phonetic
+pub const MIN_SHARED_READING: usize = 3;
+
+/// Min share of members which have to share the series reading
+pub const MIN_REGULARITY: f32 = 0.5;
+
+/// Kanji sharing a phonetic component, eg. 青, 清, 晴, 精 and 請 with the reading セイ
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhoneticSeries {
+    /// The shared phonetic component
+    pub component: char,
+    /// The onyomi most members share
+    pub reading: String,
+    /// All kanji with onyomi containing the component. The component itself comes first if it is
+    /// a member, followed by all regular and all irregular members
+    pub members: Vec<char>,
+    /// Amount of members having `reading` as onyomi
+    pub regular: usize,
+}
+
+impl PhoneticSeries {
+    /// Returns the share of members having the series reading, between 0 and 1
+    #[inline]
+    pub fn regularity(&self) -> f32 {
+        if self.members.is_empty() {
+            return 0.0;
+        }
+        self.regular as f32 / self.members.len() as f32
+    }
+
+    /// Returns `true` if `literal` is a member of the series
+    #[inline]
+    pub fn has_member(&self, literal: char) -> bool {
+        self.members.contains(&literal)
+    }
+}
+
+/// Derives all phonetic series out of `kanji`, given as `(literal, onyomi, components)`. A
+/// component is considered phonetic if enough kanji containing it share an onyomi
+pub fn derive_series<'a, I>(kanji: I) -> Vec<PhoneticSeries>
+where
+    I: IntoIterator<Item = (char, &'a [String], Vec<char>)>,
+{
+    let mut onyomi: HashMap<char, &[String]> = HashMap::new();
+    let mut by_component: HashMap<char, Vec<char>> = HashMap::new();
+
+    for (literal, on, components) in kanji {
+        if on.is_empty() {
+            continue;
+        }
+        onyomi.insert(literal, on);
+
+        for component in components.into_iter().filter(|c| *c != literal) {
+            let members = by_component.entry(component).or_default();
+            if !members.contains(&literal) {
+                members.push(literal);
+            }
+        }
+    }
+
+    let mut series: Vec<_> = by_component
+        .into_iter()
+        .filter_map(|(component, mut members)| {
+            if onyomi.contains_key(&component) {
+                members.push(component);
+            }
+
+            if members.len() < MIN_SHARED_READING {
+                return None;
+            }
+
+            build_series(component, members, &onyomi)
+        })
+        .collect();
+
+    series.sort_by_key(|i| i.component);
+    series
+}
+
+fn build_series(
+    component: char,
+    mut members: Vec<char>,
+    onyomi: &HashMap<char, &[String]>,
+) -> Option<PhoneticSeries> {
+    let has_reading = |literal: &char, reading: &str| {
+        onyomi
+            .get(literal)
+            .map_or(false, |on| on.iter().any(|i| i == reading))
+    };
+
+    let mut reading_count: HashMap<&str, usize> = HashMap::new();
+    for on in members.iter().filter_map(|i| onyomi.get(i)) {
+        for reading in on.iter() {
+            *reading_count.entry(reading.as_str()).or_default() += 1;
+        }
+    }
+
+    // Most shared reading. Ties are resolved alphabetically to stay deterministic
+    let (reading, regular) = reading_count
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))?;
+
+    let regularity = regular as f32 / members.len() as f32;
+    if regular < MIN_SHARED_READING || regularity < MIN_REGULARITY {
+        return None;
+    }
+
+    members.sort_by_key(|i| (*i != component, !has_reading(i, reading), *i));
+
+    Some(PhoneticSeries {
+        component,
+        reading: reading.to_string(),
+        members,
+        regular,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn on(readings: &[&str]) -> Vec<String> {
+        readings.iter().map(|i| i.to_string()).collect()
+    }
+
+    #[test]
+    fn test_derive_series() {
+        let sei = on(&["セイ"]);
+        let sei_jou = on(&["セイ", "ジョウ"]);
+        let sui = on(&["スイ"]);
+        let katsu = on(&["カツ"]);
+        let chuu = on(&["チュウ"]);
+        let kanji: Vec<(char, &[String], Vec<char>)> = vec![
+            ('青', &sei_jou, vec!['龶', '月']),
+            ('清', &sei, vec!['氵', '青']),
+            ('晴', &sei, vec!['日', '青']),
+            ('精', &sei, vec!['米', '青']),
+            ('請', &sei, vec!['言', '青']),
+            ('水', &sui, vec!['水']),
+            ('活', &katsu, vec!['氵', '舌']),
+            ('沖', &chuu, vec!['氵', '中']),
+        ];
+
+        let series = derive_series(kanji);
+        assert_eq!(series.len(), 1);
+
+        let series = &series[0];
+        assert_eq!(series.component, '青');
+        assert_eq!(series.reading, "セイ");
+        assert_eq!(series.members, vec!['青', '晴', '清', '精', '請']);
+        assert_eq!(series.regular, 5);
+        assert_eq!(series.regularity(), 1.0);
+    }
+
+    #[test]
+    fn test_irregular_members() {
+        let kou = on(&["コウ"]);
+        let ku = on(&["ク", "コウ"]);
+        let kuu = on(&["クウ"]);
+        let kanji: Vec<(char, &[String], Vec<char>)> = vec![
+            ('工', &ku, vec![]),
+            ('江', &kou, vec!['氵', '工']),
+            ('功', &kou, vec!['工', '力']),
+            ('空', &kuu, vec!['穴', '工']),
+        ];
+
+        let series = derive_series(kanji);
+        assert_eq!(series.len(), 1);
+        assert_eq!(series[0].members, vec!['工', '功', '江', '空']);
+        assert_eq!(series[0].regular, 3);
+        assert!(!series[0].has_member('水'));
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashSet;
+
+/// Min score two kanji need to have in order to be considered visually similar
+pub const MIN_SIMILARITY: f32 = 0.5;
+
+/// Min score two kanji need to have in order to be considered confusable
+pub const MIN_CONFUSABLE: f32 = 0.55;
+
+/// Stroke difference at which strokes stop adding to the similarity
+pub const MAX_STROKE_DIFF: u8 = 6;
+
+// Weights of the single similarity criteria
+const DIRECT_WEIGHT: f32 = 0.45;
+const LAYOUT_WEIGHT: f32 = 0.2;
+const NESTED_WEIGHT: f32 = 0.2;
+const STROKE_WEIGHT: f32 = 0.15;
+
+/// Visual shape of a kanji derived from its IDS decomposition
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Shape {
+    pub strokes: u8,
+    /// IDS operator describing how the direct components are arranged, eg. ⿰ or ⿱.
+    /// `None` if the kanji has no IDS decomposition
+    pub layout: Option<char>,
+    /// Direct components in the order of the decomposition. The position of a component
+    /// reflects the layout, eg. left and right for ⿰
+    pub components: Vec<char>,
+    /// All components of the decomposition tree, including nested ones
+    pub nested: HashSet<char>,
+}
+
+impl Shape {
+    #[inline]
+    pub fn new(
+        strokes: u8,
+        layout: Option<char>,
+        components: Vec<char>,
+        nested: HashSet<char>,
+    ) -> Self {
+        Self {
+            strokes,
+            layout,
+            components,
+            nested,
+        }
+    }
+
+    /// Returns the direct components without duplicates, eg. 木 only once for 林
+    #[inline]
+    pub fn distinct_components(&self) -> HashSet<char> {
+        self.components.iter().copied().collect()
+    }
+}
+
+/// Returns `true` if `c` is an IDS operator, eg. ⿰ (left to right) or ⿴ (surround)
+#[inline]
+pub fn is_layout_operator(c: char) -> bool {
+    ('\u{2FF0}'..='\u{2FFB}').contains(&c)
+}
+
+/// Returns the visual similarity of two kanji between 0 and 1, based on shared components, the
+/// position of the components within the layout and the difference in strokes
+pub fn score(a: &Shape, b: &Shape) -> f32 {
+    DIRECT_WEIGHT * direct_overlap(a, b)
+        + LAYOUT_WEIGHT * layout_overlap(a, b)
+        + NESTED_WEIGHT * nested_overlap(a, b)
+        + STROKE_WEIGHT * stroke_similarity(a, b)
+}
+
+/// Share of distinct direct components both kanji have
+fn direct_overlap(a: &Shape, b: &Shape) -> f32 {
+    let a = a.distinct_components();
+    let b = b.distinct_components();
+    let max = a.len().max(b.len());
+    if max == 0 {
+        return 0.0;
+    }
+
+    a.intersection(&b).count() as f32 / max as f32
+}
+
+/// Share of components at the same position within equally structured kanji. Kanji with
+/// different IDS operators (eg. ⿰ and ⿱) never share a position
+fn layout_overlap(a: &Shape, b: &Shape) -> f32 {
+    if a.components.len() != b.components.len() || a.components.is_empty() {
+        return 0.0;
+    }
+
+    if let (Some(a), Some(b)) = (a.layout, b.layout) {
+        if a != b {
+            return 0.0;
+        }
+    }
+
+    let same = a
+        .components
+        .iter()
+        .zip(b.components.iter())
+        .filter(|(a, b)| a == b)
+        .count();
+    same as f32 / a.components.len() as f32
+}
+
+/// Jaccard index of all components within the decomposition trees
+fn nested_overlap(a: &Shape, b: &Shape) -> f32 {
+    let union = a.nested.union(&b.nested).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.nested.intersection(&b.nested).count() as f32 / union as f32
+}
+
+#[inline]
+fn stroke_similarity(a: &Shape, b: &Shape) -> f32 {
+    let diff = a.strokes.abs_diff(b.strokes).min(MAX_STROKE_DIFF);
+    1.0 - diff as f32 / MAX_STROKE_DIFF as f32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn shape(strokes: u8, components: &[char], nested: &[char]) -> Shape {
+        shape_with(strokes, Some('⿰'), components, nested)
+    }
+
+    fn shape_with(
+        strokes: u8,
+        layout: Option<char>,
+        components: &[char],
+        nested: &[char],
+    ) -> Shape {
+        let nested = components.iter().chain(nested.iter()).copied().collect();
+        Shape::new(strokes, layout, components.to_vec(), nested)
+    }
+
+    #[test]
+    fn test_identical() {
+        let a = shape(11, &['氵', '青'], &['龶', '月']);
+        assert!((score(&a, &a) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_order() {
+        let sei = shape(11, &['氵', '青'], &['龶', '月']);
+        let jou = shape(11, &['忄', '青'], &['龶', '月']);
+        let sei2 = shape(12, &['日', '青'], &['龶', '月']);
+        let katsu = shape(9, &['氵', '舌'], &['千', '口']);
+        let sou = shape(12, &['艹', '早'], &['日', '十']);
+
+        let similar = score(&sei, &jou);
+        assert!(similar >= MIN_CONFUSABLE);
+        assert!(similar > score(&sei, &sei2));
+        assert!(score(&sei, &sei2) > score(&sei, &katsu));
+        assert!(score(&sei, &sei2) >= MIN_CONFUSABLE);
+        assert!(score(&sei, &katsu) < MIN_SIMILARITY);
+        assert!(score(&sei, &sou) < MIN_SIMILARITY);
+    }
+
+    #[test]
+    fn test_layout() {
+        // 旦 and 旧 both consist of 日 and 一 but arranged differently
+        let tan = shape_with(5, Some('⿱'), &['日', '一'], &[]);
+        let tan2 = shape_with(5, Some('⿱'), &['日', '一'], &[]);
+        let side = shape_with(5, Some('⿰'), &['日', '一'], &[]);
+        let unknown = shape_with(5, None, &['日', '一'], &[]);
+
+        assert!(score(&tan, &tan2) > score(&tan, &side));
+        assert!((score(&tan, &unknown) - score(&tan, &tan2)).abs() < f32::EPSILON);
+        assert!(is_layout_operator('⿴') && !is_layout_operator('口'));
+    }
+
+    #[test]
+    fn test_duplicate_components() {
+        // 林 consists of 木 twice which must not count as two shared components with 杯
+        let hayashi = shape(8, &['木', '木'], &[]);
+        let hai = shape(8, &['木', '不'], &[]);
+        assert!((direct_overlap(&hayashi, &hai) - 0.5).abs() < f32::EPSILON);
+        assert!((direct_overlap(&hayashi, &hayashi) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_no_components() {
+        let a = shape(1, &[], &[]);
+        let b = shape(2, &[], &[]);
+        assert!(score(&a, &b) < MIN_SIMILARITY);
+    }
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+/// Itemised breakdown of how the score of a search result was computed
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Explanation {
+    /// Name of the producer which emitted the item
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub producer: Option<String>,
+    /// Relevance computed by the index (eg. vector similarity) before any ranking was applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relevance: Option<f32>,
+    /// All ranking contributions in the order they were applied
+    pub factors: Vec<Factor>,
+    /// The final score of the item
+    pub score: f32,
+}
+
+impl Explanation {
+    /// Creates a new explanation without any factors
+    #[inline]
+    pub fn new(score: f32) -> Self {
+        Self {
+            score,
+            ..Default::default()
+        }
+    }
+}
+
+/// A single contribution to the score of an item
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Factor {
+    /// Short description of the factor, eg. "common"
+    pub name: String,
+    pub op: FactorOp,
+    pub value: f32,
+}
+
+impl Factor {
+    #[inline]
+    pub fn new<S: ToString>(name: S, op: FactorOp, value: f32) -> Self {
+        Self {
+            name: name.to_string(),
+            op,
+            value,
+        }
+    }
+}
+
+/// How a factor got applied to the score
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FactorOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    /// The score was overwritten with the value
+    Set,
+}
@@ -1,3 +1,4 @@
+pub mod explain;
 pub mod guess;
 pub mod help;
 pub mod query_type;
@@ -3,7 +3,7 @@ use localization::{language::Language, traits::Translatable, TranslationDict};
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Hash, Default)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
 pub enum SearchTarget {
     #[serde(rename = "1")]
     Kanji,
@@ -32,6 +32,17 @@ impl SearchTarget {
         dict.gettext(self.get_id(), language)
     }
 
+    /// Returns the lowercase name of the search target, eg. `"words"`
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchTarget::Kanji => "kanji",
+            SearchTarget::Sentences => "sentences",
+            SearchTarget::Names => "names",
+            SearchTarget::Words => "words",
+        }
+    }
+
     #[inline]
     pub fn get_type_id(&self) -> u8 {
         match self {
@@ -4,9 +4,12 @@ use std::fmt::Display;
 use localization::{language::Language, traits::Translatable, TranslationDict};
 
 use serde::{Deserialize, Serialize};
-use strum_macros::EnumString;
+use strum::IntoEnumIterator;
+use strum_macros::{AsRefStr, EnumIter, EnumString};
 
-#[derive(Debug, PartialEq, Clone, Copy, EnumString, Serialize, Deserialize, Hash)]
+#[derive(
+    Debug, PartialEq, Clone, Copy, AsRefStr, EnumString, EnumIter, Serialize, Deserialize, Hash,
+)]
 #[repr(u8)]
 pub enum Dialect {
     #[strum(serialize = "bra")]
@@ -35,6 +38,13 @@ pub enum Dialect {
     Tsugaru,
 }
 
+impl Dialect {
+    #[inline]
+    pub fn iter() -> impl Iterator<Item = Dialect> {
+        <Dialect as IntoEnumIterator>::iter()
+    }
+}
+
 impl Display for Dialect {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
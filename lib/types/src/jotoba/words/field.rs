@@ -2,9 +2,12 @@
 use localization::{language::Language, traits::Translatable, TranslationDict};
 
 use serde::{Deserialize, Serialize};
-use strum_macros::{AsRefStr, EnumString};
+use strum::IntoEnumIterator;
+use strum_macros::{AsRefStr, EnumIter, EnumString};
 
-#[derive(Debug, PartialEq, Clone, Copy, AsRefStr, EnumString, Serialize, Deserialize, Hash)]
+#[derive(
+    Debug, PartialEq, Clone, Copy, AsRefStr, EnumString, EnumIter, Serialize, Deserialize, Hash,
+)]
 #[repr(u8)]
 pub enum Field {
     #[strum(serialize = "agric")]
@@ -161,6 +164,13 @@ pub enum Field {
     Zoology,
 }
 
+impl Field {
+    #[inline]
+    pub fn iter() -> impl Iterator<Item = Field> {
+        <Field as IntoEnumIterator>::iter()
+    }
+}
+
 #[cfg(feature = "jotoba_intern")]
 impl Translatable for Field {
     fn get_id(&self) -> &'static str {
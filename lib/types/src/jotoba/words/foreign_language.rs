@@ -2,9 +2,12 @@
 use localization::traits::Translatable;
 
 use serde::{Deserialize, Serialize};
-use strum_macros::{AsRefStr, EnumString};
+use strum::IntoEnumIterator;
+use strum_macros::{AsRefStr, EnumIter, EnumString};
 
-#[derive(Debug, PartialEq, Clone, Copy, AsRefStr, EnumString, Serialize, Deserialize, Hash)]
+#[derive(
+    Debug, PartialEq, Clone, Copy, AsRefStr, EnumString, EnumIter, Serialize, Deserialize, Hash,
+)]
 #[repr(u8)]
 pub enum ForeignLanguage {
     #[strum(serialize = "eng")]
@@ -123,6 +126,13 @@ pub enum ForeignLanguage {
     French,
 }
 
+impl ForeignLanguage {
+    #[inline]
+    pub fn iter() -> impl Iterator<Item = ForeignLanguage> {
+        <ForeignLanguage as IntoEnumIterator>::iter()
+    }
+}
+
 #[cfg(feature = "jotoba_intern")]
 impl Translatable for ForeignLanguage {
     fn get_id(&self) -> &'static str {
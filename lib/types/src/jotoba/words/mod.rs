@@ -10,6 +10,7 @@ pub mod part_of_speech;
 pub mod pitch;
 pub mod priority;
 pub mod reading;
+pub mod reading_breakdown;
 pub mod sense;
 
 use std::num::{NonZeroU32, NonZeroU8};
@@ -17,18 +18,27 @@ use std::num::{NonZeroU32, NonZeroU8};
 pub use dict::Dict;
 
 use self::{
+    dialect::Dialect,
+    field::Field,
     inflection::Inflections,
     misc::Misc,
     part_of_speech::{PartOfSpeech, PosSimple},
     pitch::{raw_data::PitchValues, Pitch},
     reading::{Reading, ReadingIter},
-    sense::{Sense, SenseGlossIter},
+    sense::{Gairaigo, Sense, SenseGlossIter},
 };
 use super::languages::Language;
 use bitflags::BitFlag;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "jotoba_intern")]
+use self::{
+    information::Information,
+    reading_breakdown::{IrregularReading, ReadingBreakdown},
+};
+#[cfg(feature = "jotoba_intern")]
+use super::kanji::Kanji;
 #[cfg(feature = "jotoba_intern")]
 use japanese::furigana::{self, SentencePartRef};
 
@@ -168,6 +178,24 @@ impl Word {
             .any(|i| i == *misc)
     }
 
+    /// Returns `true` if the word has at least one sense of the given `field`
+    #[inline]
+    pub fn has_field(&self, field: &Field) -> bool {
+        self.senses.iter().any(|i| i.field == Some(*field))
+    }
+
+    /// Returns `true` if the word has at least one sense of the given `dialect`
+    #[inline]
+    pub fn has_dialect(&self, dialect: &Dialect) -> bool {
+        self.senses.iter().any(|i| i.dialect == Some(*dialect))
+    }
+
+    /// Returns an iterator over the loanword information of all senses
+    #[inline]
+    pub fn gairaigo(&self) -> impl Iterator<Item = &Gairaigo> {
+        self.senses.iter().filter_map(|i| i.gairaigo.as_ref())
+    }
+
     /// Returns `true` if word has at least one of the provided part of speech
     #[inline]
     pub fn has_pos(&self, pos_filter: &[PosSimple]) -> bool {
@@ -296,6 +324,42 @@ impl Word {
         Some(furigana::parse::from_str(furi).collect::<Vec<_>>())
     }
 
+    /// Returns a per kanji breakdown of the words reading. `None` if the word has no furigana or
+    /// isn't written with kanji
+    pub fn reading_breakdown<'a, F>(&self, get_kanji: F) -> Option<ReadingBreakdown>
+    where
+        F: Fn(char) -> Option<&'a Kanji>,
+    {
+        let kanji = reading_breakdown::align(&self.get_furigana()?, &get_kanji);
+        if kanji.is_empty() {
+            return None;
+        }
+
+        let info = self
+            .reading
+            .kanji
+            .as_ref()
+            .and_then(|i| i.reading_info.as_ref());
+        let has_info = |info_type| info.map_or(false, |i| i.contains(&info_type));
+        let has_readings = |lit| {
+            get_kanji(lit).map_or(false, |k: &Kanji| {
+                !k.onyomi.is_empty() || !k.kunyomi.is_empty() || !k.nanori.is_empty()
+            })
+        };
+
+        let irregular = if has_info(Information::Ateji) {
+            Some(IrregularReading::Ateji)
+        } else if has_info(Information::Gikun)
+            || reading_breakdown::has_unaligned(&kanji, has_readings)
+        {
+            Some(IrregularReading::Jukujikun)
+        } else {
+            None
+        };
+
+        Some(ReadingBreakdown { kanji, irregular })
+    }
+
     /// Get alternative readings in a beautified, print-ready format
     #[inline]
     pub fn alt_readings_beautified(&self) -> String {
@@ -0,0 +1,499 @@
+use serde::{Deserialize, Serialize};
+
+use crate::jotoba::kanji::reading::ReadingType;
+#[cfg(feature = "jotoba_intern")]
+use crate::jotoba::kanji::Kanji;
+#[cfg(feature = "jotoba_intern")]
+use japanese::{furigana::SentencePartRef, JapaneseExt};
+#[cfg(feature = "jotoba_intern")]
+use localization::traits::Translatable;
+
+/// Kind of reading a kanji has within a word
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReadingKind {
+    Onyomi,
+    Kunyomi,
+    Nanori,
+    /// The reading couldn't be found in the kanjis readings
+    Irregular,
+}
+
+/// Kind of a words reading which can't be aligned with the readings of its kanji
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IrregularReading {
+    /// Reading of a kanji compound as a whole, eg. 今日 (きょう)
+    Jukujikun,
+    /// Kanji used for their sound only, eg. 寿司 (すし)
+    Ateji,
+}
+
+/// The reading of a single kanji within a word. Kanji blocks whose reading can't be split up, like
+/// 今日 (きょう), are represented as a single irregular `KanjiReading`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KanjiReading {
+    pub kanji: String,
+    /// The reading as it is used within the word
+    pub kana: String,
+    pub kind: ReadingKind,
+    /// The dictionary reading of the kanji if it differs from `kana` due to sound changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    /// The first consonant got voiced, eg. ひと → びと
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub rendaku: bool,
+    /// The last mora turned into a small っ, eg. がく → がっ
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub gemination: bool,
+}
+
+/// Per kanji breakdown of a words reading
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadingBreakdown {
+    pub kanji: Vec<KanjiReading>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub irregular: Option<IrregularReading>,
+}
+
+impl From<ReadingType> for ReadingKind {
+    #[inline]
+    fn from(r_type: ReadingType) -> Self {
+        match r_type {
+            ReadingType::Onyomi => ReadingKind::Onyomi,
+            ReadingType::Kunyomi => ReadingKind::Kunyomi,
+        }
+    }
+}
+
+#[cfg(feature = "jotoba_intern")]
+impl Translatable for ReadingKind {
+    fn get_id(&self) -> &'static str {
+        match self {
+            ReadingKind::Onyomi => "On",
+            ReadingKind::Kunyomi => "Kun",
+            ReadingKind::Nanori => "Nanori",
+            ReadingKind::Irregular => "Irregular",
+        }
+    }
+}
+
+#[cfg(feature = "jotoba_intern")]
+impl Translatable for IrregularReading {
+    fn get_id(&self) -> &'static str {
+        match self {
+            IrregularReading::Jukujikun => "jukujikun",
+            IrregularReading::Ateji => "ateji",
+        }
+    }
+}
+
+impl KanjiReading {
+    #[cfg(feature = "jotoba_intern")]
+    #[inline]
+    fn irregular(kanji: String, kana: &str) -> Self {
+        Self {
+            kanji,
+            kana: kana.to_string(),
+            kind: ReadingKind::Irregular,
+            base: None,
+            rendaku: false,
+            gemination: false,
+        }
+    }
+
+    /// Returns `true` if the reading couldn't be aligned with the kanjis readings
+    #[inline]
+    pub fn is_irregular(&self) -> bool {
+        self.kind == ReadingKind::Irregular
+    }
+}
+
+impl ReadingBreakdown {
+    /// Returns `true` if at least one kanji reading couldn't be aligned
+    #[inline]
+    pub fn has_irregular(&self) -> bool {
+        self.kanji.iter().any(|i| i.is_irregular())
+    }
+}
+
+/// A possible reading of a kanji within a word
+#[cfg(feature = "jotoba_intern")]
+struct Candidate {
+    kana: String,
+    kind: ReadingKind,
+    base: String,
+    rendaku: bool,
+    gemination: bool,
+}
+
+#[cfg(feature = "jotoba_intern")]
+impl Candidate {
+    #[inline]
+    fn cost(&self) -> u8 {
+        self.rendaku as u8 + self.gemination as u8
+    }
+
+    fn into_reading(self, kanji: char) -> KanjiReading {
+        let changed = self.rendaku || self.gemination;
+        KanjiReading {
+            kanji: kanji.to_string(),
+            kana: self.kana,
+            kind: self.kind,
+            base: changed.then_some(self.base),
+            rendaku: self.rendaku,
+            gemination: self.gemination,
+        }
+    }
+}
+
+/// Aligns the furigana `parts` of a word with the readings of their kanji, which are looked up
+/// using `get_kanji`
+#[cfg(feature = "jotoba_intern")]
+pub fn align<'a, F>(parts: &[SentencePartRef], get_kanji: F) -> Vec<KanjiReading>
+where
+    F: Fn(char) -> Option<&'a Kanji>,
+{
+    align_with(parts, |lit| {
+        let kanji = match get_kanji(lit) {
+            Some(k) => k,
+            None => return vec![],
+        };
+
+        let readings = kanji
+            .onyomi
+            .iter()
+            .chain(kanji.kunyomi.iter())
+            .filter_map(|r| kanji.find_reading(r))
+            .map(|r| (r.get_raw().to_string(), r.get_type().into()));
+        let nanori = kanji
+            .nanori
+            .iter()
+            .map(|r| (r.clone(), ReadingKind::Nanori));
+        readings.chain(nanori).collect()
+    })
+}
+
+/// Returns `true` if a reading of `aligned` couldn't be aligned although readings of all kanji
+/// are known. Kanji without known readings, eg. ones missing in the kanji data, never align and
+/// don't make a reading irregular
+#[cfg(feature = "jotoba_intern")]
+pub fn has_unaligned<F>(aligned: &[KanjiReading], has_readings: F) -> bool
+where
+    F: Fn(char) -> bool,
+{
+    let all_known = aligned
+        .iter()
+        .flat_map(|i| i.kanji.chars())
+        .all(|c| c == '々' || has_readings(c));
+    all_known && aligned.iter().any(|i| i.is_irregular())
+}
+
+/// Aligns the furigana `parts` of a word with kanji readings returned by `readings`
+#[cfg(feature = "jotoba_intern")]
+fn align_with<F>(parts: &[SentencePartRef], readings: F) -> Vec<KanjiReading>
+where
+    F: Fn(char) -> Vec<(String, ReadingKind)>,
+{
+    let mut out = vec![];
+    let mut last_kanji: Option<char> = None;
+
+    for (pos, part) in parts.iter().enumerate() {
+        let kanji = match part.kanji {
+            Some(k) => k,
+            None => continue,
+        };
+
+        // Resolve the iteration mark 々 to the kanji it repeats
+        let literals: Vec<(char, char)> = kanji
+            .chars()
+            .map(|c| {
+                let lookup = if c == '々' {
+                    last_kanji.unwrap_or(c)
+                } else {
+                    c
+                };
+                last_kanji = Some(lookup);
+                (c, lookup)
+            })
+            .collect();
+
+        let ctx = SplitCtx {
+            readings: &readings,
+            word_start: pos == 0,
+            word_end: pos + 1 == parts.len(),
+        };
+
+        let kana = part.kana.to_hiragana();
+        match ctx.split(&literals, &kana, 0) {
+            Some(split) => out.extend(split),
+            None => out.push(KanjiReading::irregular(kanji.to_string(), part.kana)),
+        }
+    }
+
+    out
+}
+
+#[cfg(feature = "jotoba_intern")]
+struct SplitCtx<'r, F> {
+    readings: &'r F,
+    /// Whether the kanji block is at the beginning of the word
+    word_start: bool,
+    /// Whether the kanji block is at the end of the word
+    word_end: bool,
+}
+
+#[cfg(feature = "jotoba_intern")]
+impl<'r, F> SplitCtx<'r, F>
+where
+    F: Fn(char) -> Vec<(String, ReadingKind)>,
+{
+    /// Splits `kana` into readings of `literals`, preferring readings with fewer sound changes
+    fn split(
+        &self,
+        literals: &[(char, char)],
+        kana: &str,
+        pos: usize,
+    ) -> Option<Vec<KanjiReading>> {
+        let ((lit, lookup), rest) = match literals.split_first() {
+            Some(s) => s,
+            None => return kana.is_empty().then(Vec::new),
+        };
+
+        let allow_rendaku = pos > 0 || !self.word_start;
+        let allow_gemination = !rest.is_empty() || !self.word_end;
+
+        let mut candidates = self.candidates(*lookup, allow_rendaku, allow_gemination);
+        candidates.sort_by_key(|i| i.cost());
+
+        for candidate in candidates {
+            let rest_kana = match kana.strip_prefix(candidate.kana.as_str()) {
+                Some(r) => r,
+                None => continue,
+            };
+
+            if let Some(mut split) = self.split(rest, rest_kana, pos + 1) {
+                split.insert(0, candidate.into_reading(*lit));
+                return Some(split);
+            }
+        }
+
+        None
+    }
+
+    /// Returns all readings of `lit` including their variants with sound changes applied
+    fn candidates(&self, lit: char, rendaku: bool, gemination: bool) -> Vec<Candidate> {
+        let mut out = vec![];
+
+        for (reading, kind) in (self.readings)(lit) {
+            for base in base_readings(&reading) {
+                for voiced in voiced_variants(&base, rendaku) {
+                    let voiced_changed = voiced != base;
+                    for geminated in geminated_variants(&voiced, gemination) {
+                        let gemination = geminated != voiced;
+                        out.push(Candidate {
+                            kana: geminated,
+                            kind,
+                            base: base.clone(),
+                            rendaku: voiced_changed,
+                            gemination,
+                        });
+                    }
+                }
+            }
+        }
+
+        out
+    }
+}
+
+/// Returns the kana forms of a dictionary reading. For kun readings with okurigana (eg. た.べる)
+/// this is the stem and the full reading
+#[cfg(feature = "jotoba_intern")]
+fn base_readings(reading: &str) -> Vec<String> {
+    let reading = reading.replace('-', "").to_hiragana();
+    match reading.split_once('.') {
+        Some((stem, okurigana)) => vec![stem.to_string(), format!("{stem}{okurigana}")],
+        None => vec![reading],
+    }
+}
+
+/// Returns `reading` and, if `allowed`, all forms of it with a voiced first consonant
+#[cfg(feature = "jotoba_intern")]
+fn voiced_variants(reading: &str, allowed: bool) -> Vec<String> {
+    let mut out = vec![reading.to_string()];
+    if !allowed {
+        return out;
+    }
+
+    let mut chars = reading.chars();
+    let first = match chars.next() {
+        Some(f) => f,
+        None => return out,
+    };
+    let rest = chars.as_str();
+
+    out.extend(voiced(first).iter().map(|v| format!("{v}{rest}")));
+    out
+}
+
+/// Returns `reading` and, if `allowed`, the form of it with its last mora geminated, eg. がく → がっ
+/// or きる → きっ
+#[cfg(feature = "jotoba_intern")]
+fn geminated_variants(reading: &str, allowed: bool) -> Vec<String> {
+    let mut out = vec![reading.to_string()];
+    if !allowed || reading.chars().count() < 2 {
+        return out;
+    }
+
+    let mut chars = reading.chars();
+    if chars.next_back().map_or(false, can_geminate) {
+        out.push(format!("{}っ", chars.as_str()));
+    }
+
+    out
+}
+
+/// Returns `true` if a mora ending with `c` can be replaced by a small っ
+#[cfg(feature = "jotoba_intern")]
+fn can_geminate(c: char) -> bool {
+    !matches!(
+        c,
+        'ん' | 'っ' | 'ー' | 'ぁ' | 'ぃ' | 'ぅ' | 'ぇ' | 'ぉ' | 'ゃ' | 'ゅ' | 'ょ'
+    )
+}
+
+/// Returns the voiced (and half voiced) forms of a kana
+#[cfg(feature = "jotoba_intern")]
+fn voiced(c: char) -> &'static [char] {
+    match c {
+        'か' => &['が'],
+        'き' => &['ぎ'],
+        'く' => &['ぐ'],
+        'け' => &['げ'],
+        'こ' => &['ご'],
+        'さ' => &['ざ'],
+        'し' => &['じ'],
+        'す' => &['ず'],
+        'せ' => &['ぜ'],
+        'そ' => &['ぞ'],
+        'た' => &['だ'],
+        'ち' => &['ぢ', 'じ'],
+        'つ' => &['づ', 'ず'],
+        'て' => &['で'],
+        'と' => &['ど'],
+        'は' => &['ば', 'ぱ'],
+        'ひ' => &['び', 'ぴ'],
+        'ふ' => &['ぶ', 'ぷ'],
+        'へ' => &['べ', 'ぺ'],
+        'ほ' => &['ぼ', 'ぽ'],
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "jotoba_intern")]
+mod test {
+    use super::*;
+    use japanese::furigana::parse;
+    use test_case::test_case;
+
+    fn readings(lit: char) -> Vec<(String, ReadingKind)> {
+        let (on, kun): (&[&str], &[&str]) = match lit {
+            '学' => (&["ガク"], &["まな.ぶ"]),
+            '校' => (&["コウ", "キョウ"], &[]),
+            '人' => (&["ジン", "ニン"], &["ひと", "-り", "-と"]),
+            '食' => (&["ショク", "ジキ"], &["く.う", "た.べる"]),
+            '物' => (&["ブツ", "モツ"], &["もの"]),
+            '一' => (&["イチ", "イツ"], &["ひと", "ひと.つ"]),
+            '発' => (&["ハツ", "ホツ"], &["た.つ"]),
+            '表' => (&["ヒョウ"], &["おもて", "あらわ.す"]),
+            '今' => (&["コン", "キン"], &["いま"]),
+            '日' => (&["ニチ", "ジツ"], &["ひ", "-び", "-か"]),
+            '時' => (&["ジ"], &["とき"]),
+            '切' => (&["セツ", "サイ"], &["き.る"]),
+            '手' => (&["シュ"], &["て"]),
+            _ => (&[], &[]),
+        };
+
+        let on = on.iter().map(|i| (i.to_string(), ReadingKind::Onyomi));
+        let kun = kun.iter().map(|i| (i.to_string(), ReadingKind::Kunyomi));
+        on.chain(kun).collect()
+    }
+
+    fn breakdown(furigana: &str) -> Vec<(String, String, ReadingKind)> {
+        let parts = parse::from_str(furigana).collect::<Vec<_>>();
+        align_with(&parts, readings)
+            .into_iter()
+            .map(|i| (i.kanji, i.kana, i.kind))
+            .collect()
+    }
+
+    #[test_case("[学校|がっ|こう]", &[("学", "がっ"), ("校", "こう")]; "Gemination")]
+    #[test_case("[食|た]べ[物|もの]", &[("食", "た"), ("物", "もの")]; "Okurigana")]
+    #[test_case("[人々|ひと|びと]", &[("人", "ひと"), ("々", "びと")]; "Iteration mark")]
+    #[test_case("[発表|はっ|ぴょう]", &[("発", "はっ"), ("表", "ぴょう")]; "Gemination and handakuten")]
+    #[test_case("[一人|ひと|り]", &[("一", "ひと"), ("人", "り")]; "Kun suffix")]
+    #[test_case("[時々|とき|どき]", &[("時", "とき"), ("々", "どき")]; "Rendaku")]
+    #[test_case("[切手|きっ|て]", &[("切", "きっ"), ("手", "て")]; "Kun gemination")]
+    fn test_align(furigana: &str, expected: &[(&str, &str)]) {
+        let parsed = breakdown(furigana);
+        let parsed = parsed
+            .iter()
+            .map(|i| (i.0.as_str(), i.1.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(parsed, expected);
+        assert!(parsed.iter().all(|i| !i.1.is_empty()));
+    }
+
+    #[test]
+    fn test_kinds() {
+        let parsed = breakdown("[学校|がっ|こう]");
+        assert_eq!(parsed[0].2, ReadingKind::Onyomi);
+        let parsed = breakdown("[食|た]べ[物|もの]");
+        assert_eq!(parsed[0].2, ReadingKind::Kunyomi);
+    }
+
+    #[test]
+    fn test_sound_changes() {
+        let parts = parse::from_str("[学校|がっ|こう]").collect::<Vec<_>>();
+        let aligned = align_with(&parts, readings);
+        assert!(aligned[0].gemination);
+        assert_eq!(aligned[0].base.as_deref(), Some("がく"));
+        assert!(!aligned[1].gemination && aligned[1].base.is_none());
+    }
+
+    #[test]
+    fn test_jukujikun() {
+        let parsed = breakdown("[今日|きょう]");
+        assert_eq!(
+            parsed,
+            vec![(
+                "今日".to_string(),
+                "きょう".to_string(),
+                ReadingKind::Irregular
+            )]
+        );
+    }
+
+    #[test]
+    fn test_unaligned() {
+        let has_readings = |lit| !readings(lit).is_empty();
+        let aligned = |furigana| {
+            let parts = parse::from_str(furigana).collect::<Vec<_>>();
+            align_with(&parts, readings)
+        };
+
+        assert!(has_unaligned(&aligned("[今日|きょう]"), has_readings));
+        assert!(!has_unaligned(&aligned("[学校|がっ|こう]"), has_readings));
+        // 鬱 has no known readings so it can't be told whether the reading is irregular
+        assert!(!has_unaligned(&aligned("[鬱|うつ]"), has_readings));
+        assert!(!has_unaligned(&aligned("[鬱日|うつ|び]"), has_readings));
+    }
+
+    #[test]
+    fn test_no_rendaku_at_start() {
+        let parsed = breakdown("[時|どき]");
+        assert_eq!(parsed[0].2, ReadingKind::Irregular);
+    }
+}
@@ -30,6 +30,72 @@ pub struct Sense {
     pub language: Language,
     pub example_sentence: Option<u32>,
     pub gairaigo: Option<Gairaigo>,
+    /// `xref` resolved to the referenced word. Set after loading the resources
+    #[serde(skip)]
+    pub xref_target: Option<SenseRef>,
+    /// `antonym` resolved to the referenced word. Set after loading the resources
+    #[serde(skip)]
+    pub antonym_target: Option<SenseRef>,
+}
+
+/// A reference to a word or one of its senses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize, Hash)]
+pub struct SenseRef {
+    pub sequence: u32,
+    /// The referenced sense or `None` if the reference applies to the whole word
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sense_id: Option<u8>,
+}
+
+impl SenseRef {
+    #[inline]
+    pub fn new(sequence: u32, sense_id: Option<u8>) -> Self {
+        Self { sequence, sense_id }
+    }
+}
+
+/// An unresolved `xref` or `antonym` in the format of `kanji・kana・sense-number`
+/// where only the first element is required, eg. `見る・みる・2`, `見る・2` or `みる`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawRef<'a> {
+    /// Kanji or kana reading of the referenced word
+    pub text: &'a str,
+    /// Kana reading if `text` is a kanji reading
+    pub kana: Option<&'a str>,
+    /// 1-based position of the referenced sense
+    pub sense_nr: Option<u8>,
+}
+
+impl<'a> RawRef<'a> {
+    /// Parses a raw reference. Returns `None` if `s` has an invalid format
+    pub fn parse(s: &'a str) -> Option<Self> {
+        let mut parts = s.split('・').map(|i| i.trim()).collect::<Vec<_>>();
+
+        let sense_nr = match parts.last()?.parse::<u8>() {
+            Ok(nr) => {
+                parts.pop();
+                Some(nr)
+            }
+            Err(_) => None,
+        };
+
+        match parts.as_slice() {
+            [text] if !text.is_empty() => Some(Self::new(text, None, sense_nr)),
+            [text, kana] if !text.is_empty() && !kana.is_empty() => {
+                Some(Self::new(text, Some(kana), sense_nr))
+            }
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn new(text: &'a str, kana: Option<&'a str>, sense_nr: Option<u8>) -> Self {
+        Self {
+            text,
+            kana,
+            sense_nr,
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize, Hash)]
@@ -39,6 +105,24 @@ pub struct Gairaigo {
     pub original: String,
 }
 
+impl Gairaigo {
+    /// Returns the original word in the normalized form used for lookups
+    #[inline]
+    pub fn normalized_original(&self) -> String {
+        normalize_original(&self.original)
+    }
+}
+
+/// Normalizes an original (foreign) word of a loanword so it can be compared regardless of
+/// casing, spacing and punctuation. Eg. "Arbeit" and "arbeit" or "ice cream" and "ice-cream"
+pub fn normalize_original(original: &str) -> String {
+    original
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
 impl Eq for Sense {}
 
 /// A gloss value represents one word in the
@@ -294,4 +378,27 @@ mod test {
             assert_eq!(*gloss, gloss_res);
         }
     }
+
+    #[test]
+    fn test_parse_raw_ref() {
+        let parse = |s| RawRef::parse(s).map(|i| (i.text, i.kana, i.sense_nr));
+        assert_eq!(
+            parse("見る・みる・2"),
+            Some(("見る", Some("みる"), Some(2)))
+        );
+        assert_eq!(parse("見る・みる"), Some(("見る", Some("みる"), None)));
+        assert_eq!(parse("見る・2"), Some(("見る", None, Some(2))));
+        assert_eq!(parse("みる"), Some(("みる", None, None)));
+        assert_eq!(parse("1"), None);
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("a・b・c・1"), None);
+    }
+
+    #[test]
+    fn test_normalize_original() {
+        assert_eq!(normalize_original("Arbeit"), "arbeit");
+        assert_eq!(normalize_original("ice cream"), "icecream");
+        assert_eq!(normalize_original("ice-cream"), "icecream");
+        assert_eq!(normalize_original("Café"), "café");
+    }
 }